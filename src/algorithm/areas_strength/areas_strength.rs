@@ -1,11 +1,10 @@
-use sal_sync::services::entity::error::str_err::StrErr;
 use crate::{
     algorithm::{
-        context::{context::Context, context_access::{ContextReadRef, ContextWrite}, ctx_result::CtxResult},
-        entities::{serde_parser::IFromJson, strength::{ComputedFrameData, ComputedFrameDataArray}, DataArray}, initial::initial_ctx::InitialCtx,
+        context::{context::Context, context_access::ContextWrite, ctx_result::CtxResult, ctx_store::CtxType},
+        initial::initial_ctx::InitialCtx,
+        pipeline::{cache::{Memo, StableHasher}, step::Step},
     },
-    infrostructure::{api::client::api_client::ApiClient, query::restart_eval::RestartEvalQuery},
-    kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult}, ship_model::model_link::ModelLink
+    kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, types::eval_result::EvalResult}, ship_model::model_link::ModelLink
 };
 
 use super::areas_strength_ctx::AreasStrengthCtx;
@@ -17,54 +16,62 @@ use super::areas_strength_ctx::AreasStrengthCtx;
 pub struct AreasStrength {
     dbg: DbgId,
     model: ModelLink,
-    ctx: Box<dyn Eval<(), EvalResult> + Send>,
 }
 //
 //
 impl AreasStrength {
     ///
     /// Fetches all initiall data
-    /// - 'api_client' - access to the database
-    pub fn new(parent: impl Into<String>, model: ModelLink, ctx: impl Eval<(), EvalResult> + Send + 'static) -> Self {
+    /// - 'model' - access to the ship model
+    pub fn new(parent: impl Into<String>, model: ModelLink) -> Self {
         let dbg = DbgId::with_parent(&DbgId(parent.into()), "AreasStrength");
         Self {
             dbg,
             model,
-            ctx: Box::new(ctx),
         }
     }
     //
     //
 }
-impl Eval<(), EvalResult> for AreasStrength {
-    fn eval(&mut self, _: ()) -> futures::future::BoxFuture<'_, EvalResult> {
+impl Eval<Context, EvalResult> for AreasStrength {
+    fn eval(&mut self, ctx: Context) -> futures::future::BoxFuture<'_, EvalResult> {
         Box::pin(async move {
-            match self.ctx.eval(()).await {
-                CtxResult::Ok(ctx) => {
-                    match self.model.areas().await {
-                        Ok(areas) => {
-                            let result = AreasStrengthCtx { areas };
-                            ctx.write(result)
-                        },
-                        Err(err) => {
-                            return CtxResult::Err(StrErr(format!(
-                                "{}.eval | Read context error: {:?}",
-                                self.dbg, err
-                            )));
-                        },
-                    }
-                },
-                CtxResult::Err(err) => CtxResult::Err(StrErr(format!(
-                    "{}.eval | Read context error: {:?}",
-                    self.dbg, err
-                ))),
-                CtxResult::None => CtxResult::None,
-            }
+            CtxResult::try_async(async {
+                let areas = self.model.areas(ctx.api_replies().clone()).await?;
+                ctx.write(AreasStrengthCtx { areas }).into()
+            })
+            .await
+            .map_err(|err| err.context(format!("{}.eval | Areas error", self.dbg)))
         })
     }
 }
 //
 //
+impl Step for AreasStrength {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    ///
+    /// The ship data revision is read by the [Cached](crate::algorithm::pipeline::cache::Cached) wrapper
+    fn reads(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<InitialCtx>()]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<AreasStrengthCtx>()]
+    }
+}
+//
+//
+impl Memo for AreasStrength {
+    type Output = AreasStrengthCtx;
+    ///
+    /// Areas split by frames depend only on the ship id and revision
+    fn hash_inputs(&self, _: &Context, _: &mut StableHasher) -> Result<(), EvalError> {
+        Ok(())
+    }
+}
+//
+//
 impl std::fmt::Debug for AreasStrength {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AreasStrength")
@@ -1,15 +1,24 @@
-use crate::algorithm::entities::{area::HAreaStrength, strength::VerticalArea};
+use serde::{Deserialize, Serialize};
+use crate::algorithm::{
+    context::ctx_store::StepCtx,
+    entities::{area::HAreaStrength, strength::VerticalArea},
+};
 
 ///
 /// Общая структура для ввода данных. Содержит все данные
 /// для расчетов.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AreasStrengthCtx {
     /// разбиение на шпации - фреймы
     pub areas: (Vec<VerticalArea>, Vec<HAreaStrength>),
 }
 //
 //
+impl StepCtx for AreasStrengthCtx {
+    const STEP: &'static str = "AreasStrength";
+}
+//
+//
 // impl Default for AreasStrengthCtx {
 //     ///
 //     /// Struct constructor
@@ -1,19 +1,33 @@
-use crate::
-    algorithm::{
-        areas_strength::areas_strength_ctx::AreasStrengthCtx, initial::initial_ctx::InitialCtx
-    }
-;
-use super::testing_ctx::TestingCtx;
+use std::collections::BTreeMap;
+use sal_sync::services::entity::error::str_err::StrErr;
+use crate::{
+    algorithm::{initial::initial_ctx::InitialCtx, pipeline::trace::Trace},
+    infrostructure::api::client::{api_replies::ApiReplies, query_stats::QueryStats},
+    kernel::sync::cancel_token::CancelToken,
+};
+use super::{ctx_store::{CtxStore, CtxType}, testing_ctx::TestingCtx};
 ///
 /// # Calculation context
 /// - Provides read/write access to initial
-/// - R/W access to the isoleted data of each step of computations
+/// - R/W access to the isoleted data of each step of computations,
+///   stored by type, see [StepCtx](super::ctx_store::StepCtx)
 #[derive(Debug, Clone)]
 pub struct Context {
     /// where store [initial data](design\docs\algorithm\part01\initial_data.md)
-    pub(super) initial: InitialCtx,
-    /// TODO: DOC
-    pub(super) areas_strength: Option<AreasStrengthCtx>,
+    /// and results of all steps of computations
+    pub(super) store: CtxStore,
+    ///
+    /// Cancellation of the evaluation, checked by the steps
+    cancel: CancelToken,
+    ///
+    /// Counters of the database queries made by the step
+    stats: QueryStats,
+    ///
+    /// Raw database replies, recorded or replayed
+    replies: ApiReplies,
+    ///
+    /// Trace of the evaluated pipeline
+    trace: Trace,
     ///
     /// Uset for testing only
     #[allow(dead_code)]
@@ -26,10 +40,76 @@ impl Context {
     /// New instance [Context]
     /// - 'initial' - [InitialCtx] instance, where store initial data
     pub fn new(initial: InitialCtx) -> Self {
+        let mut store = CtxStore::new();
+        store.insert(initial);
         Self {
-            initial,
-            areas_strength: None,
+            store,
+            cancel: CancelToken::new(),
+            stats: QueryStats::new(),
+            replies: ApiReplies::new(),
+            trace: Trace::default(),
             testing: None,
         }
     }
+    ///
+    /// Returns the context evaluated until the `cancel` token is cancelled
+    pub fn with_cancel(self, cancel: CancelToken) -> Self {
+        Self { cancel, ..self }
+    }
+    ///
+    /// Returns the cancellation token of the evaluation
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel
+    }
+    ///
+    /// Returns the context, which database queries are counted in the `stats`
+    pub fn with_query_stats(self, stats: QueryStats) -> Self {
+        Self { stats, ..self }
+    }
+    ///
+    /// Returns the counters of the database queries
+    pub fn query_stats(&self) -> &QueryStats {
+        &self.stats
+    }
+    ///
+    /// Returns the context, which database replies are recorded or replayed by the `replies`
+    pub fn with_api_replies(self, replies: ApiReplies) -> Self {
+        Self { replies, ..self }
+    }
+    ///
+    /// Returns the raw database replies
+    pub fn api_replies(&self) -> &ApiReplies {
+        &self.replies
+    }
+    ///
+    /// Returns the context with the `trace` of the evaluation attached
+    pub fn with_trace(self, trace: Trace) -> Self {
+        Self { trace, ..self }
+    }
+    ///
+    /// Returns the trace of the evaluated pipeline
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+    ///
+    /// Returns true if the value of the type `key` is stored
+    pub fn contains(&self, key: &CtxType) -> bool {
+        self.store.contains_type(key)
+    }
+    ///
+    /// Returns all stored contexts serialized, keyed by the short type name
+    pub fn to_json(&self) -> Result<BTreeMap<String, serde_json::Value>, StrErr> {
+        self.store.to_json()
+    }
+    ///
+    /// Returns the stored [results](super::ctx_store::StepCtx::RESULT) serialized, keyed by the short type name
+    pub fn results_json(&self) -> Result<BTreeMap<String, serde_json::Value>, StrErr> {
+        self.store.results_json()
+    }
+    ///
+    /// Copies the values of the types `keys` from `other`
+    /// - Used to collect the results of the steps evaluated on the copies of the context
+    pub fn merge(&mut self, other: &Context, keys: &[CtxType]) {
+        self.store.merge(&other.store, keys);
+    }
 }
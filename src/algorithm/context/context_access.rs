@@ -1,48 +1,37 @@
-use sal_sync::services::entity::error::str_err::StrErr;
-
-use super::{context::Context, ctx_result::CtxResult};
-use crate::algorithm::{areas_strength::areas_strength_ctx::AreasStrengthCtx, 
-        initial::initial_ctx::InitialCtx};
+use crate::kernel::error::eval_error::EvalError;
+use super::{context::Context, ctx_result::CtxResult, ctx_store::StepCtx};
 ///
 /// Provides restricted write access to the [Context] members
 pub trait ContextWrite<T> {
-    fn write(self, value: T) -> CtxResult<Context, StrErr>;
+    fn write(self, value: T) -> CtxResult<Context, EvalError>;
 }
 ///
 /// Provides simple read access to the [Context] members
+/// - Error if the member has not been produced yet
 pub trait ContextReadRef<T> {
-    fn read(&self) -> &T;
+    fn read(&self) -> Result<&T, EvalError>;
 }
 ///
 /// Provides simple read access to the [Context] members
+/// - Error if the member has not been produced yet
 pub trait ContextRead<T> {
-    fn read(&self) -> T;
+    fn read(&self) -> Result<T, EvalError>;
 }
 //
 //
-impl ContextWrite<InitialCtx> for Context {
-    fn write(mut self, value: InitialCtx) -> CtxResult<Self, StrErr> {
-        self.initial = value;
+impl<T: StepCtx> ContextWrite<T> for Context {
+    fn write(mut self, value: T) -> CtxResult<Self, EvalError> {
+        self.store.insert(value);
         CtxResult::Ok(self)
     }
 }
-impl ContextReadRef<InitialCtx> for Context {
-    fn read(&self) -> &InitialCtx {
-        &self.initial
+impl<T: StepCtx> ContextReadRef<T> for Context {
+    fn read(&self) -> Result<&T, EvalError> {
+        self.store.get::<T>()
     }
 }
-//
-//
-//
-//
-impl ContextWrite<AreasStrengthCtx> for Context {
-    fn write(mut self, value: AreasStrengthCtx) -> CtxResult<Self, StrErr> {
-        self.areas_strength = Some(value);
-        CtxResult::Ok(self)
-    }
-}
-impl ContextRead<AreasStrengthCtx> for Context {
-    fn read(&self) -> AreasStrengthCtx {
-        self.areas_strength.clone().unwrap()
+impl<T: StepCtx + Clone> ContextRead<T> for Context {
+    fn read(&self) -> Result<T, EvalError> {
+        self.store.get::<T>().cloned()
     }
 }
@@ -0,0 +1,140 @@
+use std::{collections::{BTreeMap, HashMap}, fs, path::Path};
+use sal_sync::services::entity::error::str_err::StrErr;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use crate::{
+    algorithm::{
+        areas_strength::areas_strength_ctx::AreasStrengthCtx,
+        initial::initial_ctx::InitialCtx,
+        loading::loading_ctx::{BulkheadsCtx, CargoesCtx, CompartmentsCtx, ContainersCtx, LoadingCtx, VoyageCtx},
+        parameters::parameters_ctx::ParametersCtx,
+        stability::stability_ctx::StabilityCtx,
+        strength::strength_ctx::StrengthCtx,
+    },
+    infrostructure::api::client::api_replies::ApiReplies,
+};
+use super::{context::Context, ctx_store::{CtxStore, CtxType, StepCtx}};
+///
+/// Restores the serialized value of the context type into the store
+type Restore = fn(Value, &mut CtxStore) -> Result<(), String>;
+///
+/// # Context types, which can be restored from the [CtxBundle]
+/// - Default instance contains all context types of the algorithm
+#[derive(Debug, Clone)]
+pub struct CtxRegistry {
+    types: HashMap<&'static str, Restore>,
+}
+//
+//
+impl CtxRegistry {
+    ///
+    /// New empty instance
+    pub fn new() -> Self {
+        Self { types: HashMap::new() }
+    }
+    ///
+    /// Returns the registry with the context type `T` added
+    pub fn with<T: StepCtx + DeserializeOwned>(mut self) -> Self {
+        self.types.insert(CtxType::of::<T>().short_name(), restore::<T>);
+        self
+    }
+}
+//
+//
+impl Default for CtxRegistry {
+    fn default() -> Self {
+        Self::new()
+            .with::<InitialCtx>()
+            .with::<AreasStrengthCtx>()
+            .with::<CargoesCtx>()
+            .with::<ContainersCtx>()
+            .with::<CompartmentsCtx>()
+            .with::<BulkheadsCtx>()
+            .with::<VoyageCtx>()
+            .with::<LoadingCtx>()
+            .with::<StabilityCtx>()
+            .with::<StrengthCtx>()
+            .with::<ParametersCtx>()
+    }
+}
+///
+/// Deserializes the value of type `T` and inserts it into the store
+fn restore<T: StepCtx + DeserializeOwned>(value: Value, store: &mut CtxStore) -> Result<(), String> {
+    store.insert(serde_json::from_value::<T>(value).map_err(|err| err.to_string())?);
+    Ok(())
+}
+///
+/// # Snapshot of the calculation to reproduce it without the database
+/// - 'contexts' - all stored contexts, keyed by the short type name
+/// - 'replies' - raw database replies by the sql query
+/// - Saved as YAML if the file extension is `yaml` or `yml`, otherwise as JSON
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CtxBundle {
+    pub contexts: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub replies: BTreeMap<String, String>,
+}
+//
+//
+impl CtxBundle {
+    ///
+    /// Returns the snapshot of the `ctx` and its recorded database replies
+    pub fn new(ctx: &Context) -> Result<Self, StrErr> {
+        Ok(Self {
+            contexts: ctx.to_json()?,
+            replies: ctx.api_replies().replies(),
+        })
+    }
+    ///
+    /// Returns the context restored from the snapshot,
+    /// database queries are answered by the recorded replies
+    /// - 'registry' - context types to be restored, unknown types are the error
+    pub fn context(&self, registry: &CtxRegistry) -> Result<Context, StrErr> {
+        let initial_name = CtxType::of::<InitialCtx>().short_name();
+        let initial: InitialCtx = match self.contexts.get(initial_name) {
+            Some(initial) => serde_json::from_value(initial.clone())
+                .map_err(|err| StrErr(format!("CtxBundle.context | '{}' error: {}", initial_name, err)))?,
+            None => return Err(StrErr(format!("CtxBundle.context | '{}' is missing", initial_name))),
+        };
+        let mut ctx = Context::new(initial).with_api_replies(ApiReplies::replay(self.replies.clone()));
+        for (name, value) in &self.contexts {
+            if name == initial_name {
+                continue;
+            }
+            match registry.types.get(name.as_str()) {
+                Some(restore) => restore(value.clone(), &mut ctx.store)
+                    .map_err(|err| StrErr(format!("CtxBundle.context | '{}' error: {}", name, err)))?,
+                None => return Err(StrErr(format!("CtxBundle.context | Unknown context type '{}'", name))),
+            }
+        }
+        Ok(ctx)
+    }
+    ///
+    /// Returns true if the file should be YAML
+    fn is_yaml(path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "yaml" || ext == "yml")
+    }
+    ///
+    /// Writes the snapshot into the file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), StrErr> {
+        let path = path.as_ref();
+        let text = match Self::is_yaml(path) {
+            true => serde_yaml::to_string(self).map_err(|err| err.to_string()),
+            false => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        };
+        text.and_then(|text| fs::write(path, text).map_err(|err| err.to_string()))
+            .map_err(|err| StrErr(format!("CtxBundle.save | Write '{}' error: {}", path.display(), err)))
+    }
+    ///
+    /// Reads the snapshot from the file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StrErr> {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| match Self::is_yaml(path) {
+                true => serde_yaml::from_str(&text).map_err(|err| err.to_string()),
+                false => serde_json::from_str(&text).map_err(|err| err.to_string()),
+            })
+            .map_err(|err| StrErr(format!("CtxBundle.load | Read '{}' error: {}", path.display(), err)))
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sal_sync::services::entity::error::str_err::StrErr;
+use super::context::Context;
+///
+/// Changed value of the context
+/// - 'path' - path to the value, `<context type>/<field>/<index>...`
+/// - 'before' - previous value, None if the value is added
+/// - 'after' - new value, None if the value is removed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CtxDiff {
+    pub path: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+//
+//
+impl CtxDiff {
+    ///
+    /// Returns the changed results between `prev` and `next` contexts
+    pub fn between(prev: &Context, next: &Context) -> Result<Vec<CtxDiff>, StrErr> {
+        let prev = prev.results_json()?;
+        let next = next.results_json()?;
+        let mut result = vec![];
+        for (name, before) in &prev {
+            match next.get(name) {
+                Some(after) => diff(name, before, after, &mut result),
+                None => result.push(CtxDiff { path: name.to_owned(), before: Some(before.clone()), after: None }),
+            }
+        }
+        for (name, after) in &next {
+            if !prev.contains_key(name) {
+                result.push(CtxDiff { path: name.to_owned(), before: None, after: Some(after.clone()) });
+            }
+        }
+        Ok(result)
+    }
+}
+///
+/// Collects the changed leaf values of `before` and `after` into `result`
+fn diff(path: &str, before: &Value, after: &Value, result: &mut Vec<CtxDiff>) {
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, value) in before {
+                let path = format!("{path}/{key}");
+                match after.get(key) {
+                    Some(other) => diff(&path, value, other, result),
+                    None => result.push(CtxDiff { path, before: Some(value.clone()), after: None }),
+                }
+            }
+            for (key, value) in after {
+                if !before.contains_key(key) {
+                    result.push(CtxDiff { path: format!("{path}/{key}"), before: None, after: Some(value.clone()) });
+                }
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            for index in 0..before.len().max(after.len()) {
+                let path = format!("{path}/{index}");
+                match (before.get(index), after.get(index)) {
+                    (Some(value), Some(other)) => diff(&path, value, other, result),
+                    (value, other) => result.push(CtxDiff { path, before: value.cloned(), after: other.cloned() }),
+                }
+            }
+        }
+        (before, after) => if before != after {
+            result.push(CtxDiff { path: path.to_owned(), before: Some(before.clone()), after: Some(after.clone()) });
+        }
+    }
+}
@@ -1,12 +1,14 @@
+use std::future::Future;
 ///
 /// Enum for structurizing types of result's
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum CtxResult<T, E> {
     /// positive type of result
     Ok(T),
     /// result type with error
     Err(E),
     /// empty result
+    #[default]
     None,
 }
 //
@@ -27,11 +29,106 @@ impl<T, E> CtxResult<T, E> {
             CtxResult::None => panic!("called `Result::unwrap()` on an `None` value"),
         }
     }
+    ///
+    /// Returns true if the result is `Ok`
+    pub fn is_ok(&self) -> bool {
+        matches!(self, CtxResult::Ok(_))
+    }
+    ///
+    /// Returns true if the result is `Err`
+    pub fn is_err(&self) -> bool {
+        matches!(self, CtxResult::Err(_))
+    }
+    ///
+    /// Returns true if the result is `None`
+    pub fn is_none(&self) -> bool {
+        matches!(self, CtxResult::None)
+    }
+    ///
+    /// Maps the `Ok` value by the `op`, `Err` and `None` are kept
+    pub fn map<U>(self, op: impl FnOnce(T) -> U) -> CtxResult<U, E> {
+        match self {
+            CtxResult::Ok(value) => CtxResult::Ok(op(value)),
+            CtxResult::Err(err) => CtxResult::Err(err),
+            CtxResult::None => CtxResult::None,
+        }
+    }
+    ///
+    /// Maps the `Err` value by the `op`, `Ok` and `None` are kept
+    pub fn map_err<F>(self, op: impl FnOnce(E) -> F) -> CtxResult<T, F> {
+        match self {
+            CtxResult::Ok(value) => CtxResult::Ok(value),
+            CtxResult::Err(err) => CtxResult::Err(op(err)),
+            CtxResult::None => CtxResult::None,
+        }
+    }
+    ///
+    /// Calls the `op` with the `Ok` value, `Err` and `None` are returned as is
+    pub fn and_then<U>(self, op: impl FnOnce(T) -> CtxResult<U, E>) -> CtxResult<U, E> {
+        match self {
+            CtxResult::Ok(value) => op(value),
+            CtxResult::Err(err) => CtxResult::Err(err),
+            CtxResult::None => CtxResult::None,
+        }
+    }
+    ///
+    /// Converts into the [Result], `None` is replaced with the `err`
+    pub fn ok_or(self, err: E) -> Result<T, E> {
+        self.ok_or_else(|| err)
+    }
+    ///
+    /// Converts into the [Result], `None` is replaced with the error returned by the `op`
+    pub fn ok_or_else(self, op: impl FnOnce() -> E) -> Result<T, E> {
+        match self {
+            CtxResult::Ok(value) => Ok(value),
+            CtxResult::Err(err) => Err(err),
+            CtxResult::None => Err(op()),
+        }
+    }
+    ///
+    /// Returns the `Ok` value, `Err` is discarded
+    pub fn ok(self) -> Option<T> {
+        match self {
+            CtxResult::Ok(value) => Some(value),
+            _ => None,
+        }
+    }
+    ///
+    /// Returns the result of the `body`, where the `?` operator can be used
+    /// - `Ok(Some(value))` is `Ok`, `Ok(None)` is `None`, `Err` is `Err`
+    ///
+    /// ```ignore
+    /// Box::pin(async move {
+    ///     CtxResult::try_async(async {
+    ///         let initial: InitialCtx = ContextRead::read(&ctx)?;
+    ///         let value = AnotherCtx::new(&initial)?;
+    ///         ctx.write(value).into()
+    ///     }).await
+    /// })
+    /// ```
+    pub async fn try_async(body: impl Future<Output = Result<Option<T>, E>>) -> Self {
+        body.await.into()
+    }
 }
 //
 //
-impl<T, E> Default for CtxResult<T, E> {
-    fn default() -> Self {
-        Self::None
+impl<T, E> From<Result<Option<T>, E>> for CtxResult<T, E> {
+    fn from(result: Result<Option<T>, E>) -> Self {
+        match result {
+            Ok(Some(value)) => CtxResult::Ok(value),
+            Ok(None) => CtxResult::None,
+            Err(err) => CtxResult::Err(err),
+        }
+    }
+}
+//
+//
+impl<T, E> From<CtxResult<T, E>> for Result<Option<T>, E> {
+    fn from(result: CtxResult<T, E>) -> Self {
+        match result {
+            CtxResult::Ok(value) => Ok(Some(value)),
+            CtxResult::Err(err) => Err(err),
+            CtxResult::None => Ok(None),
+        }
     }
 }
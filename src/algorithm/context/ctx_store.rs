@@ -0,0 +1,152 @@
+use std::{any::{type_name, Any, TypeId}, collections::{BTreeMap, HashMap}, sync::Arc};
+use sal_sync::services::entity::error::str_err::StrErr;
+use serde::Serialize;
+use crate::kernel::error::eval_error::EvalError;
+///
+/// Data produced by a calculation step and stored in the [Context](super::context::Context)
+pub trait StepCtx: Any + std::fmt::Debug + Serialize + Send + Sync {
+    ///
+    /// Name of the step, which produces this context
+    const STEP: &'static str;
+    ///
+    /// True if the context is the result of the calculation, returned to the client,
+    /// the input and intermediate data are not returned
+    const RESULT: bool = false;
+}
+///
+/// Key of the [StepCtx] type in the [CtxStore]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CtxType {
+    id: TypeId,
+    name: &'static str,
+}
+//
+//
+impl CtxType {
+    ///
+    /// Returns the key of type `T`
+    pub fn of<T: StepCtx>() -> Self {
+        Self { id: TypeId::of::<T>(), name: type_name::<T>() }
+    }
+    ///
+    /// Returns the name of the type without the module path
+    pub fn short_name(&self) -> &'static str {
+        short_name(self.name)
+    }
+}
+//
+//
+impl std::fmt::Display for CtxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+///
+/// # Type-keyed storage of the step contexts
+/// - Any [StepCtx] can be inserted, one value per type
+/// - Values are shared between clones of the storage
+#[derive(Clone, Default)]
+pub struct CtxStore {
+    values: HashMap<TypeId, Entry>,
+}
+///
+/// Stored value with its type name and serializer
+#[derive(Clone)]
+struct Entry {
+    name: &'static str,
+    result: bool,
+    value: Arc<dyn Any + Send + Sync>,
+    to_json: fn(&(dyn Any + Send + Sync)) -> Result<serde_json::Value, String>,
+}
+///
+/// Serializes the stored value of type `T`
+fn to_json<T: StepCtx>(value: &(dyn Any + Send + Sync)) -> Result<serde_json::Value, String> {
+    match value.downcast_ref::<T>() {
+        Some(value) => serde_json::to_value(value).map_err(|err| err.to_string()),
+        None => Err(format!("type mismatch, expected '{}'", type_name::<T>())),
+    }
+}
+///
+/// Returns the name of the type without the module path
+fn short_name(name: &'static str) -> &'static str {
+    name.rsplit("::").next().unwrap_or(name)
+}
+//
+//
+impl CtxStore {
+    ///
+    /// Returns new empty instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///
+    /// Inserts the value, replaces the previous value of the same type
+    pub fn insert<T: StepCtx>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Entry {
+            name: type_name::<T>(),
+            result: T::RESULT,
+            value: Arc::new(value),
+            to_json: to_json::<T>,
+        });
+    }
+    ///
+    /// Returns reference to the value of type `T`
+    /// - Error if the step producing `T` has not been evaluated
+    pub fn get<T: StepCtx>(&self) -> Result<&T, EvalError> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|entry| entry.value.downcast_ref::<T>())
+            .ok_or_else(|| EvalError::internal(format!(
+                "CtxStore.get | step '{}' has not produced '{}'",
+                T::STEP,
+                type_name::<T>(),
+            )))
+    }
+    ///
+    /// Returns true if the value of the type `key` is stored
+    pub fn contains_type(&self, key: &CtxType) -> bool {
+        self.values.contains_key(&key.id)
+    }
+    ///
+    /// Copies the values of the types `keys` from `other`,
+    /// types missing in `other` are skipped
+    pub fn merge(&mut self, other: &CtxStore, keys: &[CtxType]) {
+        for key in keys {
+            if let Some(value) = other.values.get(&key.id) {
+                self.values.insert(key.id, value.clone());
+            }
+        }
+    }
+    ///
+    /// Returns all stored values serialized, keyed by the short type name
+    pub fn to_json(&self) -> Result<BTreeMap<String, serde_json::Value>, StrErr> {
+        self.json(false)
+    }
+    ///
+    /// Returns the stored [results](StepCtx::RESULT) serialized, keyed by the short type name
+    pub fn results_json(&self) -> Result<BTreeMap<String, serde_json::Value>, StrErr> {
+        self.json(true)
+    }
+    ///
+    /// Returns the stored values serialized, only the results if `results_only`
+    fn json(&self, results_only: bool) -> Result<BTreeMap<String, serde_json::Value>, StrErr> {
+        self.values
+            .values()
+            .filter(|entry| entry.result || !results_only)
+            .map(|entry| {
+                (entry.to_json)(entry.value.as_ref())
+                    .map(|value| (short_name(entry.name).to_owned(), value))
+                    .map_err(|err| StrErr(format!("CtxStore.to_json | '{}' error: {}", entry.name, err)))
+            })
+            .collect()
+    }
+}
+//
+//
+impl std::fmt::Debug for CtxStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set()
+            .entries(self.values.values().map(|entry| entry.name))
+            .finish()
+    }
+}
@@ -13,7 +13,10 @@
 //! ```
 pub mod context_access;
 pub mod context;
+pub mod ctx_bundle;
+pub mod ctx_diff;
 pub mod ctx_result;
+pub mod ctx_store;
 ///
 /// TODO: To be moved to the better place
 pub mod testing_ctx;
@@ -1,8 +1,10 @@
 //! Площадь горизонтальной поверхности для расчета прочности
 
+use serde::{Deserialize, Serialize};
+
 use crate::{algorithm::entities::math::Bound, kernel::error::error::Error};
 /// Площадь горизонтальной поверхности для расчета прочности
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HAreaStrength {
     /// Значение площади, м^2
     value: f64,  
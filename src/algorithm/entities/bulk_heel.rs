@@ -0,0 +1,102 @@
+//! Крен от смещения навалочного груза
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{
+        load::IBulk,
+        math::{first_root, Curve, ICurve},
+    },
+    kernel::error::error::Error,
+};
+
+/// Результат проверки угла крена от смещения груза
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkHeelCheck {
+    /// Угол крена, градус. None - плечо остойчивости
+    /// не уравновешивает кренящее плечо
+    pub angle: Option<f64>,
+    /// Допустимый угол крена, градус
+    pub limit: f64,
+}
+//
+impl BulkHeelCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.angle.is_some_and(|angle| angle <= self.limit)
+    }
+}
+/// Кренящее плечо от смещения навалочного груза.
+/// Плечо уменьшается с креном линейно до 0.8λ0 при 40°
+/// по аналогии с Международным зерновым кодексом
+pub struct BulkHeel {
+    /// Кренящее плечо на прямом положении λ0, м
+    lever: f64,
+}
+//
+impl BulkHeel {
+    /// Допустимый угол крена от смещения груза, градус
+    const ANGLE_LIMIT: f64 = 12.;
+    /// Расчетная амплитуда бортовой качки, градус,
+    /// если амплитуда не задана в параметрах рейса
+    pub const ROLL: f64 = 30.;
+    /// Основной конструктор
+    /// * displacement - Водоизмещение, т
+    /// * moment - Кренящий момент от смещения навалочных грузов, т·м, см. [BulkHeel::moment]
+    pub fn new(displacement: f64, moment: f64) -> Result<Self, Error> {
+        if displacement <= 0. {
+            return Err(Error::FromString(format!(
+                "BulkHeel new error: displacement {displacement} <= 0."
+            )));
+        }
+        Ok(Self {
+            lever: moment / displacement,
+        })
+    }
+    /// Кренящий момент от смещения навалочных грузов при качке, т·м
+    /// * bulks - Навалочные грузы
+    /// * roll - Амплитуда бортовой качки, градус
+    pub fn moment(bulks: &[Rc<dyn IBulk>], roll: f64) -> f64 {
+        bulks.iter().map(|v| v.heel_moment(roll)).sum()
+    }
+    /// Кренящее плечо на прямом положении λ0, м
+    pub fn lever(&self) -> f64 {
+        self.lever
+    }
+    /// Кренящее плечо при угле крена, м
+    /// * angle - Угол крена, градус
+    pub fn lever_at(&self, angle: f64) -> f64 {
+        self.lever * (1. - 0.005 * angle)
+    }
+    /// Кривая кренящего плеча: (угол крена, градус; плечо, м)
+    /// * angles - Углы крена, градус
+    pub fn curve(&self, angles: &[f64]) -> Vec<(f64, f64)> {
+        angles
+            .iter()
+            .map(|angle| (*angle, self.lever_at(*angle)))
+            .collect()
+    }
+    /// Угол крена от смещения груза, градус.
+    /// None - плечо остойчивости не уравновешивает кренящее плечо
+    /// * gz - Диаграмма статической остойчивости: (угол крена, градус; плечо, м)
+    pub fn angle(&self, gz: &[(f64, f64)]) -> Result<Option<f64>, Error> {
+        let max_angle = gz
+            .iter()
+            .map(|(angle, _)| *angle)
+            .fold(f64::MIN, f64::max);
+        let gz = Curve::new_linear(gz)
+            .map_err(|e| Error::FromString(format!("BulkHeel angle gz error: {e}")))?;
+        let delta = |angle: f64| -> Result<f64, Error> { Ok(gz.value(angle)? - self.lever_at(angle)) };
+        first_root(delta, 0., max_angle, 0.1)
+    }
+    /// Проверка угла крена: не более 12° и не более
+    /// угла входа кромки палубы в воду
+    /// * gz - Диаграмма статической остойчивости: (угол крена, градус; плечо, м)
+    /// * entry_angle - Угол входа кромки верхней палубы в воду, градус
+    pub fn check(&self, gz: &[(f64, f64)], entry_angle: Option<f64>) -> Result<BulkHeelCheck, Error> {
+        Ok(BulkHeelCheck {
+            angle: self.angle(gz)?,
+            limit: entry_angle.map_or(Self::ANGLE_LIMIT, |v| v.min(Self::ANGLE_LIMIT)),
+        })
+    }
+}
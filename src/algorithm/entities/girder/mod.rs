@@ -0,0 +1,12 @@
+//! Общая прочность корпуса: эпюры нагрузок и проверка
+//! по допускаемым значениям
+mod stress;
+mod torsion;
+mod wave_load;
+
+pub use stress::*;
+pub use torsion::*;
+pub use wave_load::*;
+
+/// Ускорение свободного падения, м/с^2
+pub const G: f64 = 9.81;
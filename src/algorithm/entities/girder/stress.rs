@@ -0,0 +1,127 @@
+//! Нормальные и касательные напряжения в поперечных сечениях корпуса
+use serde::{Deserialize, Serialize};
+use crate::{
+    algorithm::entities::strength::SectionModulusData,
+    kernel::error::error::Error,
+};
+
+use super::G;
+
+/// Категория судостроительной стали
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SteelGrade {
+    /// Сталь нормальной прочности, R_eH = 235 МПа
+    Mild,
+    /// Сталь повышенной прочности, R_eH = 315 МПа
+    Ah32,
+    /// Сталь повышенной прочности, R_eH = 355 МПа
+    Ah36,
+    /// Сталь повышенной прочности, R_eH = 390 МПа
+    Ah40,
+}
+//
+impl SteelGrade {
+    /// Разбор категории стали из строки
+    pub fn from_str(src: &str) -> Result<Self, Error> {
+        Ok(match src.trim().to_uppercase().as_str() {
+            "A" | "B" | "D" | "E" | "MILD" => SteelGrade::Mild,
+            "A32" | "D32" | "E32" | "F32" | "AH32" | "DH32" | "EH32" | "FH32" => SteelGrade::Ah32,
+            "A36" | "D36" | "E36" | "F36" | "AH36" | "DH36" | "EH36" | "FH36" => SteelGrade::Ah36,
+            "A40" | "D40" | "E40" | "F40" | "AH40" | "DH40" | "EH40" | "FH40" => SteelGrade::Ah40,
+            src => return Err(Error::FromString(format!("SteelGrade from_str error: no grade {src}"))),
+        })
+    }
+    /// Коэффициент использования механических свойств стали
+    pub fn k(&self) -> f64 {
+        match self {
+            SteelGrade::Mild => 1.,
+            SteelGrade::Ah32 => 0.78,
+            SteelGrade::Ah36 => 0.72,
+            SteelGrade::Ah40 => 0.68,
+        }
+    }
+    /// Допускаемые нормальные напряжения, МПа
+    pub fn sigma_allow(&self) -> f64 {
+        175. / self.k()
+    }
+    /// Допускаемые касательные напряжения, МПа
+    pub fn tau_allow(&self) -> f64 {
+        110. / self.k()
+    }
+}
+/// Напряжения в поперечном сечении корпуса по шпангоуту
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StressData {
+    /// Индекс шпангоута
+    pub index: i32,
+    /// Нормальные напряжения на уровне палубы, МПа
+    pub sigma_deck: f64,
+    /// Нормальные напряжения на уровне днища, МПа
+    pub sigma_bottom: f64,
+    /// Касательные напряжения, МПа
+    pub tau: f64,
+    /// Допускаемые нормальные напряжения, МПа
+    pub sigma_allow: f64,
+    /// Допускаемые касательные напряжения, МПа
+    pub tau_allow: f64,
+}
+//
+impl StressData {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.sigma_deck.abs() <= self.sigma_allow
+            && self.sigma_bottom.abs() <= self.sigma_allow
+            && self.tau.abs() <= self.tau_allow
+    }
+}
+/// Расчет напряжений в поперечных сечениях корпуса по
+/// изгибающим моментам и перерезывающим силам в шпангоутах
+pub struct Stress {
+    /// Характеристики сечений по шпангоутам
+    sections: Vec<SectionModulusData>,
+}
+//
+impl Stress {
+    /// Основной конструктор
+    /// * sections - Характеристики сечений, по одному на каждый шпангоут разбиения
+    pub fn new(sections: Vec<SectionModulusData>) -> Result<Self, Error> {
+        for v in &sections {
+            if v.w_deck <= 0. || v.w_bottom <= 0. || v.shear_area <= 0. {
+                return Err(Error::FromString(format!(
+                    "Stress new error: nonpositive section properties {v}"
+                )));
+            }
+        }
+        Ok(Self { sections })
+    }
+    /// Таблица напряжений по шпангоутам.
+    /// Изгибающий момент положителен при перегибе: палуба растянута
+    /// * loads - Нагрузки по шпангоутам: (изгибающий момент, т*м, перерезывающая сила, т)
+    pub fn table(&self, loads: &[(f64, f64)]) -> Result<Vec<StressData>, Error> {
+        if loads.len() != self.sections.len() {
+            return Err(Error::FromString(format!(
+                "Stress table error: loads.len() {} != sections.len() {}",
+                loads.len(),
+                self.sections.len()
+            )));
+        }
+        self.sections
+            .iter()
+            .zip(loads.iter())
+            .map(|(section, (bending, shear))| {
+                let grade = SteelGrade::from_str(&section.steel_grade)?;
+                // т*м -> МН*м, т -> МН
+                let bending = bending * G * 1.0e-3;
+                let shear = shear * G * 1.0e-3;
+                Ok(StressData {
+                    index: section.index,
+                    sigma_deck: bending / section.w_deck,
+                    sigma_bottom: -bending / section.w_bottom,
+                    tau: shear / section.shear_area,
+                    sigma_allow: grade.sigma_allow(),
+                    tau_allow: grade.tau_allow(),
+                })
+            })
+            .collect()
+    }
+}
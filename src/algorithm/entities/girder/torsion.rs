@@ -0,0 +1,101 @@
+//! Крутящий момент корпуса от поперечного смещения масс
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{
+        load::ILoadMass,
+        math::{Bounds, Curve, ICurve},
+    },
+    kernel::error::error::Error,
+};
+
+/// Результат проверки крутящего момента в сечении
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TorsionCheck {
+    /// Отстояние сечения от миделя, м
+    pub x: f64,
+    /// Крутящий момент, т*м
+    pub value: f64,
+    /// Допускаемый крутящий момент, т*м
+    pub limit: f64,
+}
+//
+impl TorsionCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.value.abs() <= self.limit
+    }
+}
+/// Крутящий момент корпуса на тихой воде. Считается
+/// от поперечного смещения центров масс грузов по шпациям.
+/// Невязка на носовом конце, уравновешиваемая моментом сил
+/// поддержания, распределяется по длине пропорционально длине шпаций
+pub struct Torsion {
+    /// Разбиение корпуса на шпации
+    frames: Bounds,
+    /// Грузы судна
+    loads: Vec<Rc<dyn ILoadMass>>,
+}
+//
+impl Torsion {
+    /// Основной конструктор
+    /// * frames - Разбиение корпуса на шпации
+    /// * loads - Грузы судна
+    pub fn new(frames: Bounds, loads: Vec<Rc<dyn ILoadMass>>) -> Self {
+        Self { frames, loads }
+    }
+    /// Распределенный крутящий момент по шпациям, т*м
+    pub fn distributed(&self) -> Result<Vec<f64>, Error> {
+        let mut result = Vec::new();
+        for frame in self.frames.iter() {
+            let mut value = 0.;
+            for load in &self.loads {
+                value += load.value(frame)? * load.shift().y();
+            }
+            result.push(value);
+        }
+        let length = self.frames.length();
+        let residual: f64 = result.iter().sum();
+        for (value, frame) in result.iter_mut().zip(self.frames.iter()) {
+            *value -= residual
+                * frame.length().ok_or(Error::FromString(
+                    "Torsion distributed error: frame without length".to_owned(),
+                ))?
+                / length;
+        }
+        Ok(result)
+    }
+    /// Крутящий момент в конце каждой шпации нарастающим итогом
+    /// от кормы. Возвращает вектор (отстояние сечения, момент, т*м)
+    pub fn values(&self) -> Result<Vec<(f64, f64)>, Error> {
+        let mut sum = 0.;
+        let mut result = Vec::new();
+        for (value, frame) in self.distributed()?.into_iter().zip(self.frames.iter()) {
+            sum += value;
+            result.push((
+                frame.end().ok_or(Error::FromString(
+                    "Torsion values error: frame without end".to_owned(),
+                ))?,
+                sum,
+            ));
+        }
+        Ok(result)
+    }
+    /// Проверка крутящего момента по допускаемым значениям
+    /// * limits - Допускаемый момент: (отстояние сечения, момент, т*м)
+    pub fn check(&self, limits: &[(f64, f64)]) -> Result<Vec<TorsionCheck>, Error> {
+        let limits = Curve::new_linear(limits)
+            .map_err(|e| Error::FromString(format!("Torsion check limits error: {e}")))?;
+        self.values()?
+            .into_iter()
+            .map(|(x, value)| {
+                Ok(TorsionCheck {
+                    x,
+                    value,
+                    limit: limits.value(x)?,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,211 @@
+//! Волновые изгибающие моменты и перерезывающие силы
+use serde::{Deserialize, Serialize};
+use crate::{
+    algorithm::entities::{
+        math::{Curve, ICurve},
+        stability::NavigationArea,
+        strength::GirderLimitData,
+    },
+    kernel::error::error::Error,
+};
+
+use super::G;
+
+/// Результат проверки суммарных нагрузок на тихой воде и на волнении в сечении
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GirderCheck {
+    /// Отстояние сечения от миделя, м
+    pub x: f64,
+    /// Изгибающий момент при перегибе, т*м
+    pub bending_hog: f64,
+    /// Изгибающий момент при прогибе, т*м
+    pub bending_sag: f64,
+    /// Положительная перерезывающая сила, т
+    pub shear_pos: f64,
+    /// Отрицательная перерезывающая сила, т
+    pub shear_neg: f64,
+    /// Допускаемые значения в сечении
+    pub limit: GirderLimitData,
+}
+//
+impl GirderCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.bending_hog <= self.limit.bending_max
+            && self.bending_sag >= self.limit.bending_min
+            && self.shear_pos <= self.limit.shear_max
+            && self.shear_neg >= self.limit.shear_min
+    }
+}
+/// Волновые изгибающие моменты и перерезывающие силы
+/// по длине корпуса. Расчет по правилам РС с учетом
+/// коэффициента редукции для района плавания
+pub struct WaveLoad {
+    /// Длина судна, м
+    length: f64,
+    /// Ширина судна, м
+    breadth: f64,
+    /// Коэффициент общей полноты
+    c_b: f64,
+    /// Коэффициент редукции для района плавания
+    reduction: f64,
+}
+//
+impl WaveLoad {
+    /// Основной конструктор
+    /// * length - Длина судна, м
+    /// * breadth - Ширина судна, м
+    /// * c_b - Коэффициент общей полноты
+    /// * reduction - Коэффициент редукции для района плавания
+    pub fn new(length: f64, breadth: f64, c_b: f64, reduction: f64) -> Result<Self, Error> {
+        if length <= 0. || length > 500. {
+            return Err(Error::FromString(format!(
+                "WaveLoad new error: length {length} out of range (0, 500]"
+            )));
+        }
+        if breadth <= 0. {
+            return Err(Error::FromString(format!("WaveLoad new error: breadth {breadth} <= 0.")));
+        }
+        if c_b <= 0. || c_b > 1. {
+            return Err(Error::FromString(format!(
+                "WaveLoad new error: c_b {c_b} out of range (0, 1]"
+            )));
+        }
+        if reduction <= 0. || reduction > 1. {
+            return Err(Error::FromString(format!(
+                "WaveLoad new error: reduction {reduction} out of range (0, 1]"
+            )));
+        }
+        Ok(Self {
+            length,
+            breadth,
+            c_b,
+            reduction,
+        })
+    }
+    /// Конструктор с коэффициентом редукции из таблицы по району плавания
+    /// * length - Длина судна, м
+    /// * breadth - Ширина судна, м
+    /// * c_b - Коэффициент общей полноты
+    /// * area - Район плавания судна
+    /// * reductions - Коэффициенты редукции: (район плавания, коэффициент)
+    pub fn from_area(
+        length: f64,
+        breadth: f64,
+        c_b: f64,
+        area: NavigationArea,
+        reductions: &[(NavigationArea, f64)],
+    ) -> Result<Self, Error> {
+        let reduction = match area {
+            NavigationArea::Unrestricted => 1.,
+            area => reductions
+                .iter()
+                .find(|(a, _)| *a == area)
+                .map(|(_, v)| *v)
+                .ok_or(Error::FromString(format!(
+                    "WaveLoad from_area error: no reduction for {area}"
+                )))?,
+        };
+        Self::new(length, breadth, c_b, reduction)
+    }
+    /// Волновой коэффициент C_w
+    fn c_w(&self) -> f64 {
+        let l = self.length;
+        if l <= 100. {
+            0.0792 * l
+        } else if l <= 300. {
+            10.75 - ((300. - l) / 100.).powf(1.5)
+        } else if l <= 350. {
+            10.75
+        } else {
+            10.75 - ((l - 350.) / 150.).powf(1.5)
+        }
+    }
+    /// Относительное отстояние сечения от кормового перпендикуляра
+    /// * x - Отстояние сечения от миделя, м
+    fn x_rel(&self, x: f64) -> f64 {
+        (x / self.length + 0.5).clamp(0., 1.)
+    }
+    /// Волновой изгибающий момент в сечении: (перегиб, прогиб), т*м
+    /// * x - Отстояние сечения от миделя, м
+    pub fn bending(&self, x: f64) -> Result<(f64, f64), Error> {
+        let k = Curve::new_linear(&[(0., 0.), (0.4, 1.), (0.65, 1.), (1., 0.)])?.value(self.x_rel(x))?;
+        let base = self.reduction * self.c_w() * self.length.powi(2) * self.breadth * 1.0e-3 / G;
+        Ok((
+            k * 190. * base * self.c_b,
+            -k * 110. * base * (self.c_b + 0.7),
+        ))
+    }
+    /// Волновая перерезывающая сила в сечении: (положительная, отрицательная), т
+    /// * x - Отстояние сечения от миделя, м
+    pub fn shear(&self, x: f64) -> Result<(f64, f64), Error> {
+        let x_rel = self.x_rel(x);
+        let c = 190. * self.c_b / (110. * (self.c_b + 0.7));
+        let f_1 = Curve::new_linear(&[
+            (0., 0.),
+            (0.2, 0.92 * c),
+            (0.3, 0.92 * c),
+            (0.4, 0.7),
+            (0.6, 0.7),
+            (0.7, 1.),
+            (0.85, 1.),
+            (1., 0.),
+        ])?
+        .value(x_rel)?;
+        let f_2 = Curve::new_linear(&[
+            (0., 0.),
+            (0.2, 0.92),
+            (0.3, 0.92),
+            (0.4, 0.7),
+            (0.6, 0.7),
+            (0.7, c),
+            (0.85, c),
+            (1., 0.),
+        ])?
+        .value(x_rel)?;
+        let base = self.reduction * 30. * self.c_w() * self.length * self.breadth * (self.c_b + 0.7) * 1.0e-2 / G;
+        Ok((f_1 * base, -f_2 * base))
+    }
+    /// Проверка суммарных нагрузок на тихой воде и на волнении
+    /// * still_water - Нагрузки на тихой воде: (отстояние сечения от миделя, изгибающий момент, т*м, перерезывающая сила, т)
+    /// * area - Район плавания судна
+    /// * limits - Допускаемые значения для всех районов плавания, отсортированные по отстоянию сечения
+    pub fn check(
+        &self,
+        still_water: &[(f64, f64, f64)],
+        area: NavigationArea,
+        limits: &[GirderLimitData],
+    ) -> Result<Vec<GirderCheck>, Error> {
+        let limits: Vec<_> = limits.iter().filter(|v| v.area == area).collect();
+        if limits.is_empty() {
+            return Err(Error::FromString(format!("WaveLoad check error: no limits for {area}")));
+        }
+        let limit = |key: fn(&GirderLimitData) -> f64| -> Result<Curve<f64>, Error> {
+            Curve::new_linear(&limits.iter().map(|v| (v.frame_x, key(v))).collect::<Vec<_>>())
+        };
+        let (bending_max, bending_min) = (limit(|v| v.bending_max)?, limit(|v| v.bending_min)?);
+        let (shear_max, shear_min) = (limit(|v| v.shear_max)?, limit(|v| v.shear_min)?);
+        still_water
+            .iter()
+            .map(|&(x, bending, shear)| {
+                let (wave_hog, wave_sag) = self.bending(x)?;
+                let (wave_pos, wave_neg) = self.shear(x)?;
+                Ok(GirderCheck {
+                    x,
+                    bending_hog: bending + wave_hog,
+                    bending_sag: bending + wave_sag,
+                    shear_pos: shear + wave_pos,
+                    shear_neg: shear + wave_neg,
+                    limit: GirderLimitData {
+                        area,
+                        frame_x: x,
+                        bending_max: bending_max.value(x)?,
+                        bending_min: bending_min.value(x)?,
+                        shear_max: shear_max.value(x)?,
+                        shear_min: shear_min.value(x)?,
+                    },
+                })
+            })
+            .collect()
+    }
+}
@@ -1,12 +1,26 @@
 //! Навалочный смещаемый груз
 
-use crate::Error;
+use crate::kernel::error::error::Error;
 
 /// Навалочный смещаемый груз.
 /// Имеет свойства смещения груза в сторону крена судна.
 pub trait IBulk {
     /// Кренящий момент от смещения сыпучего груза
     fn moment(&self) -> f64;
+    /// Кренящий момент от смещения груза при качке, т·м
+    /// * roll - Амплитуда бортовой качки, градус
+    fn heel_moment(&self, roll: f64) -> f64;
+}
+/// Способ определения смещения навалочного груза
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulkShift {
+    /// Объемный кренящий момент по таблицам зерна
+    Grain,
+    /// Смещение поверхности груза на угол, на который
+    /// амплитуда качки превышает угол естественного откоса, градус
+    Repose(f64),
+    /// Фиксированное поперечное смещение центра тяжести груза, м
+    Fixed(f64),
 }
 /// Навалочный смещаемый груз.
 pub struct Bulk {
@@ -14,22 +28,47 @@ pub struct Bulk {
     s_f: f64,
     /// Объемный кренящий момент
     moment: f64,
+    /// Масса груза, т
+    mass: f64,
+    /// Способ определения смещения груза
+    shift: BulkShift,
 }
 //
 impl Bulk {
+    /// Угол смещения поверхности зерна, для которого
+    /// заданы объемные кренящие моменты, градус
+    const GRAIN_ANGLE: f64 = 15.;
     /// Основной конструктор
     /// * s_f - Удельный погрузочный объем, м³/т.
     /// * moment - Объемный кренящий момент
     pub fn new(
-        s_f: f64,     
+        s_f: f64,
+        moment: f64,
+    ) -> Result<Self, Error> {
+        Self::new_shift(s_f, moment, 0., BulkShift::Grain)
+    }
+    /// Конструктор с заданным способом смещения груза
+    /// * s_f - Удельный погрузочный объем, м³/т.
+    /// * moment - Объемный кренящий момент при смещении поверхности на 15°
+    /// * mass - Масса груза, т
+    /// * shift - Способ определения смещения груза
+    pub fn new_shift(
+        s_f: f64,
         moment: f64,
+        mass: f64,
+        shift: BulkShift,
     ) -> Result<Self, Error> {
         if s_f <= 0. {
             return Err(Error::FromString(format!("Bulk new error: s_f {s_f} <= 0.")));
         }
+        if mass < 0. {
+            return Err(Error::FromString(format!("Bulk new error: mass {mass} < 0.")));
+        }
         Ok(Self {
-            s_f,         
+            s_f,
             moment,
+            mass,
+            shift,
         })
     }
 }
@@ -39,4 +78,16 @@ impl IBulk for Bulk {
     fn moment(&self) -> f64 {
         self.moment / self.s_f
     }
+    /// Кренящий момент от смещения груза при качке, т·м
+    /// * roll - Амплитуда бортовой качки, градус
+    fn heel_moment(&self, roll: f64) -> f64 {
+        match self.shift {
+            BulkShift::Grain => self.moment(),
+            BulkShift::Repose(angle) => {
+                let shift = (roll - angle).max(0.);
+                self.moment() * shift.to_radians().tan() / Self::GRAIN_ANGLE.to_radians().tan()
+            }
+            BulkShift::Fixed(shift_y) => self.mass * shift_y,
+        }
+    }
 }
@@ -0,0 +1,119 @@
+//! Местная нагрузка от груза на палубу и настил второго дна
+use super::{Desk, IDesk, ILoad};
+use crate::{
+    algorithm::entities::{
+        loads::{CargoGeneralCategory, CompartmentData, DeckZoneData, LoadCargo, MatterType},
+        math::Bound,
+    },
+    kernel::error::error::Error,
+};
+use serde::{Deserialize, Serialize};
+
+/// Результат проверки местной нагрузки от груза на зону
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeckLoadCheck {
+    /// Имя груза
+    pub cargo: String,
+    /// Имя зоны палубы или настила второго дна
+    pub zone: String,
+    /// Нагрузка от груза, т/м^2
+    pub load: f64,
+    /// Допускаемая нагрузка, т/м^2
+    pub limit: f64,
+}
+//
+impl DeckLoadCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.load <= self.limit
+    }
+}
+/// Проверка равномерно распределенной нагрузки от грузов
+/// на зоны палуб и настила второго дна. Нагрузка считается
+/// как масса груза, отнесенная к площади его опирания.
+/// Палубные грузы проверяются по зонам палуб, грузы в трюмах
+/// и навалочные грузы в помещениях трюмов - по зонам настила второго дна
+pub struct DeckLoad<'a> {
+    /// Зоны палуб и настила второго дна
+    zones: &'a [DeckZoneData],
+}
+//
+impl<'a> DeckLoad<'a> {
+    /// Основной конструктор
+    /// * zones - Зоны палуб и настила второго дна с допускаемыми нагрузками
+    pub fn new(zones: &'a [DeckZoneData]) -> Self {
+        Self { zones }
+    }
+    /// Проверка грузов. Возвращает результат для каждой пары груз - зона,
+    /// на которую опирается груз
+    /// * cargoes - Грузы без привязки к помещению
+    /// * compartments - Помещения судна, навалочный груз опирается
+    ///   на настил второго дна по всей ширине помещения
+    pub fn check(&self, cargoes: &[LoadCargo], compartments: &[CompartmentData]) -> Result<Vec<DeckLoadCheck>, Error> {
+        let mut result = Vec::new();
+        for cargo in cargoes
+            .iter()
+            .filter(|v| v.general_category == CargoGeneralCategory::Cargo)
+            .filter(|v| v.bound_y1.is_some() && v.bound_y2.is_some())
+        {
+            let desk = Desk::from_cargo(cargo)?;
+            let area = desk.horizontal_area(&Bound::Full, &Bound::Full)?;
+            if area <= 0. {
+                return Err(Error::FromString(format!(
+                    "DeckLoad check error: horizontal area {area} <= 0. for cargo {}",
+                    cargo.name
+                )));
+            }
+            for zone in self
+                .zones
+                .iter()
+                .filter(|v| v.is_tank_top != cargo.is_on_deck)
+            {
+                let zone_x = Bound::new(zone.bound_x1, zone.bound_x2)?;
+                let zone_y = Bound::new(zone.bound_y1, zone.bound_y2)?;
+                if desk.horizontal_area(&zone_x, &zone_y)? <= 0. {
+                    continue;
+                }
+                result.push(DeckLoadCheck {
+                    cargo: cargo.name.clone(),
+                    zone: zone.name.clone(),
+                    load: desk.mass() / area,
+                    limit: zone.max_load,
+                });
+            }
+        }
+        for compartment in compartments.iter().filter(|v| v.matter_type == MatterType::Bulk) {
+            let mass = compartment
+                .mass
+                .or(compartment.density.zip(compartment.volume).map(|(density, volume)| density * volume))
+                .ok_or(Error::FromString(format!(
+                    "DeckLoad check error: no mass for compartment {}",
+                    compartment.name
+                )))?;
+            let bound_x = Bound::new(compartment.bound_x1, compartment.bound_x2)?;
+            let mut zones = Vec::new();
+            for zone in self.zones.iter().filter(|v| v.is_tank_top) {
+                let length = bound_x
+                    .intersect(&Bound::new(zone.bound_x1, zone.bound_x2)?)?
+                    .length()
+                    .unwrap_or(0.);
+                if length > 0. {
+                    zones.push((zone, length * (zone.bound_y2 - zone.bound_y1)));
+                }
+            }
+            let area: f64 = zones.iter().map(|(_, area)| area).sum();
+            if area <= 0. {
+                continue;
+            }
+            for (zone, _) in zones {
+                result.push(DeckLoadCheck {
+                    cargo: compartment.name.clone(),
+                    zone: zone.name.clone(),
+                    load: mass / area,
+                    limit: zone.max_load,
+                });
+            }
+        }
+        Ok(result)
+    }
+}
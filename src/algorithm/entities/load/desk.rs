@@ -1,14 +1,14 @@
 //! Палубный груз
-use crate::{math::*, Error, ILoad};
+use crate::{algorithm::entities::{loads::LoadCargo, math::*}, kernel::error::error::Error};
 
-use crate::load::ILoadMass;
+use super::{ILoad, ILoadMass};
 
 /// Палубный груз, имеет площадь и парусность
 pub trait IDesk: ILoad {
     /// Парусность попадающая в Bound или вся если Bound отсутствует
     fn windage_area(&self, bound_x: &Bound, bound_z: &Bound) -> Result<f64, Error>;
     /// Статический момент площади парусности палубного груза, м^3
-    //   fn windage_moment(&self) -> Result<Moment, Error>;
+    fn windage_moment(&self) -> Result<Moment, Error>;
     /// Площадь горизонтальной поверхности, м^2
     fn horizontal_area(&self, bound_x: &Bound, bound_y: &Bound) -> Result<f64, Error>;
     /// Высота груза, м
@@ -87,6 +87,71 @@ impl Desk {
             is_container,
         }
     }
+    /// Груз, опирающийся на палубу или настил второго дна.
+    /// Площади и центр парусности, если не заданы, считаются по границам груза
+    /// * v - Данные груза
+    pub fn from_cargo(v: &LoadCargo) -> Result<Self, Error> {
+        let mass_shift = if let (Some(mass_shift_x), Some(mass_shift_y), Some(mass_shift_z)) =
+            (v.mass_shift_x, v.mass_shift_y, v.mass_shift_z)
+        {
+            Some(Position::new(mass_shift_x, mass_shift_y, mass_shift_z))
+        } else {
+            None
+        };
+        let bound_x = Bound::new(v.bound_x1, v.bound_x2)?;
+        let bound_y = if let (Some(bound_y1), Some(bound_y2)) = (v.bound_y1, v.bound_y2) {
+            Bound::new(bound_y1, bound_y2)?
+        } else {
+            Bound::Full
+        };
+        let bound_z = if let (Some(bound_z1), Some(bound_z2)) = (v.bound_z1, v.bound_z2) {
+            Bound::new(bound_z1, bound_z2)?
+        } else {
+            Bound::Full
+        };
+        let area = |area: Option<f64>, length: Option<f64>| {
+            area.or(bound_x.length().zip(length).map(|(x, length)| x * length))
+                .ok_or(Error::FromString(format!("Desk from_cargo error: no areas for cargo {}", v.name)))
+        };
+        let vertical_area = area(v.vertical_area, bound_z.length())?;
+        let horizontal_area = area(v.horizontal_area, bound_y.length())?;
+        let mass_shift = match mass_shift {
+            Some(mass_shift) => mass_shift,
+            None => match (bound_x.center(), bound_y.center(), bound_z.center()) {
+                (Some(x), Some(y), Some(z)) => Position::new(x, y, z),
+                _ => return Err(Error::FromString(format!("Desk from_cargo error: no center of mass for cargo {}", v.name))),
+            },
+        };
+        let vertical_shift = if let (
+            Some(vertical_area_shift_x),
+            Some(vertical_area_shift_y),
+            Some(vertical_area_shift_z),
+        ) = (
+            v.vertical_area_shift_x,
+            v.vertical_area_shift_y,
+            v.vertical_area_shift_z,
+        ) {
+            Position::new(
+                vertical_area_shift_x,
+                vertical_area_shift_y,
+                vertical_area_shift_z,
+            )
+        } else {
+            mass_shift
+        };
+        Ok(Self::new(
+            v.mass.ok_or(format!("Desk from_cargo error: no mass for cargo {}", v.name))?,
+            mass_shift,
+            bound_x,
+            bound_y,
+            bound_z,
+            vertical_area,
+            vertical_shift,
+            horizontal_area,
+            v.timber,
+            v.container.unwrap_or(false),
+        ))
+    }
 }
 //
 impl IDesk for Desk {
@@ -96,13 +161,13 @@ impl IDesk for Desk {
             * self.bound_z.part_ratio(bound_z)?
             * self.windage_area)
     }
-    /*    /// Статический момент площади парусности палубного груза, м^3
+    /// Статический момент площади парусности палубного груза, м^3
     fn windage_moment(&self) -> Result<Moment, Error> {
         Ok(Moment::from_pos(
-            self.windage_shift.clone(),
+            self.windage_shift,
             self.windage_area(&Bound::Full, &Bound::Full)?,
         ))
-    }*/
+    }
     /// Площадь горизонтальной поверхности, м^2
     fn horizontal_area(&self, bound_x: &Bound, bound_y: &Bound) -> Result<f64, Error> {
         let part_x = self.bound_x.part_ratio(bound_x)?;
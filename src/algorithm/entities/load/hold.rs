@@ -0,0 +1,202 @@
+//! Трюм со съемными зерновыми перегородками
+use crate::{
+    algorithm::entities::{
+        loads::{Bulkhead, BulkheadPlaceData, CompartmentData, HoldData, LoadCargo},
+        math::{Bound, Curve, ICurve},
+    },
+    kernel::error::error::Error,
+};
+
+/// Съемная перегородка, устанавливаемая в одно из
+/// заданных мест в трюме
+#[derive(Debug, Clone)]
+pub struct MovableBulkhead {
+    /// Данные перегородки
+    bulkhead: Bulkhead,
+    /// Места установки перегородки
+    places: Vec<BulkheadPlaceData>,
+    /// Текущее место установки
+    place: BulkheadPlaceData,
+}
+//
+impl MovableBulkhead {
+    /// Основной конструктор
+    /// * bulkhead - Данные перегородки с ИД и номером места установки
+    /// * places - Места установки съемных перегородок
+    pub fn new(bulkhead: Bulkhead, places: &[BulkheadPlaceData]) -> Result<Self, Error> {
+        let (Some(id), Some(position)) = (bulkhead.id, bulkhead.position) else {
+            return Err(Error::FromString(format!(
+                "MovableBulkhead new error: no id or position for {}",
+                bulkhead.name
+            )));
+        };
+        let places: Vec<_> = places.iter().filter(|v| v.bulkhead_id == id).cloned().collect();
+        let place = Self::find(&places, position)?;
+        Ok(Self {
+            bulkhead,
+            places,
+            place,
+        })
+    }
+    /// Поиск места установки по номеру
+    fn find(places: &[BulkheadPlaceData], position: i32) -> Result<BulkheadPlaceData, Error> {
+        places
+            .iter()
+            .find(|v| v.position == position)
+            .cloned()
+            .ok_or(Error::FromString(format!(
+                "MovableBulkhead error: no place {position}"
+            )))
+    }
+    /// ИД трюма, в котором установлена перегородка
+    pub fn hold_id(&self) -> i32 {
+        self.place.hold_id
+    }
+    /// Отстояние перегородки от миделя, м
+    pub fn x(&self) -> f64 {
+        self.place.x
+    }
+    /// Перестановка перегородки в другое место
+    /// * position - Номер места установки
+    pub fn move_to(&mut self, position: i32) -> Result<(), Error> {
+        self.place = Self::find(&self.places, position)?;
+        self.bulkhead.position = Some(position);
+        Ok(())
+    }
+    /// Перегородка в виде груза в текущем месте установки
+    pub fn cargo(&self) -> LoadCargo {
+        let half = (self.bulkhead.bound_x2 - self.bulkhead.bound_x1) / 2.;
+        let mut bulkhead = self.bulkhead.clone();
+        bulkhead.bound_x1 = self.place.x - half;
+        bulkhead.bound_x2 = self.place.x + half;
+        bulkhead.mass_shift_x = Some(self.place.x);
+        LoadCargo::from(bulkhead)
+    }
+}
+/// Часть трюма между переборками и перегородками
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoldPart {
+    /// Номер части трюма от кормы
+    pub index: i32,
+    /// Границы части трюма по длине
+    pub bound_x: Bound,
+    /// Вместимость части трюма, м^3
+    pub volume: f64,
+    /// Отстояние центра объема части трюма от миделя, м
+    pub shift_x: f64,
+}
+/// Трюм, делимый съемными перегородками на части
+pub struct Hold {
+    /// Данные трюма
+    data: HoldData,
+    /// Объем от кормовой границы трюма до сечения
+    capacity: Curve<f64>,
+    /// Сечения, в которых задана кривая вместимости
+    knots: Vec<f64>,
+}
+//
+impl Hold {
+    /// Основной конструктор
+    /// * data - Данные трюма
+    /// * capacity - Кривая вместимости: (x, объем от кормовой границы)
+    pub fn new(data: HoldData, capacity: &[(f64, f64)]) -> Result<Self, Error> {
+        let mut knots: Vec<f64> = capacity.iter().map(|v| v.0).collect();
+        knots.sort_by(f64::total_cmp);
+        let capacity = Curve::new_linear(capacity)
+            .map_err(|e| Error::FromString(format!("Hold new {} capacity error: {e}", data.name)))?;
+        Ok(Self { data, capacity, knots })
+    }
+    /// Части трюма при текущем положении перегородок
+    /// * bulkheads - Съемные перегородки
+    pub fn parts(&self, bulkheads: &[MovableBulkhead]) -> Result<Vec<HoldPart>, Error> {
+        let mut bounds = vec![self.data.bound_x1];
+        let mut xs: Vec<f64> = bulkheads
+            .iter()
+            .filter(|v| v.hold_id() == self.data.id)
+            .map(|v| v.x())
+            .collect();
+        xs.sort_by(f64::total_cmp);
+        for x in xs {
+            if x <= self.data.bound_x1 || x >= self.data.bound_x2 {
+                return Err(Error::FromString(format!(
+                    "Hold parts error: bulkhead x {x} out of hold {}",
+                    self.data.name
+                )));
+            }
+            bounds.push(x);
+        }
+        bounds.push(self.data.bound_x2);
+        let mut result = Vec::new();
+        for (index, pair) in bounds.windows(2).enumerate() {
+            let (start, end) = (self.capacity.value(pair[0])?, self.capacity.value(pair[1])?);
+            result.push(HoldPart {
+                index: index as i32,
+                bound_x: Bound::new(pair[0], pair[1])?,
+                volume: end - start,
+                shift_x: self.center(pair[0], pair[1])?,
+            });
+        }
+        Ok(result)
+    }
+    /// Отстояние центра объема части трюма ∫x·dV / V.
+    /// Интеграл берется по частям: ∫x·dV = x·V|a,b - ∫V·dx,
+    /// объем между узлами кривой вместимости линеен по x
+    fn center(&self, start: f64, end: f64) -> Result<f64, Error> {
+        let mut xs = vec![start];
+        xs.extend(self.knots.iter().filter(|&&x| x > start && x < end));
+        xs.push(end);
+        let mut integral = 0.;
+        for pair in xs.windows(2) {
+            integral += (self.capacity.value(pair[0])? + self.capacity.value(pair[1])?) / 2. * (pair[1] - pair[0]);
+        }
+        let (v_start, v_end) = (self.capacity.value(start)?, self.capacity.value(end)?);
+        let volume = v_end - v_start;
+        if volume <= 0. {
+            return Err(Error::FromString(format!(
+                "Hold center error: no capacity between {start} and {end} in hold {}",
+                self.data.name
+            )));
+        }
+        Ok((end * v_end - start * v_start - integral) / volume)
+    }
+    /// Обновление границ частей трюма в данных помещений
+    /// * bulkheads - Съемные перегородки
+    /// * compartments - Помещения судна, части трюма
+    ///   определяются по ИД трюма и номеру части
+    pub fn update(
+        &self,
+        bulkheads: &[MovableBulkhead],
+        compartments: &mut [CompartmentData],
+    ) -> Result<(), Error> {
+        let parts = self.parts(bulkheads)?;
+        for v in compartments.iter_mut().filter(|v| v.hold_id == Some(self.data.id)) {
+            let part = v
+                .part_index
+                .and_then(|index| parts.iter().find(|part| part.index == index))
+                .ok_or(Error::FromString(format!(
+                    "Hold update error: no part {:?} in hold {} for {}",
+                    v.part_index, self.data.name, v.name
+                )))?;
+            if let Some(volume) = v.volume {
+                if volume > part.volume {
+                    return Err(Error::FromString(format!(
+                        "Hold update error: volume {volume} of {} > part capacity {}",
+                        v.name, part.volume
+                    )));
+                }
+            }
+            let (Some(start), Some(end)) = (part.bound_x.start(), part.bound_x.end()) else {
+                return Err(Error::FromString(format!(
+                    "Hold update error: no bounds of part {} in hold {} (id {})",
+                    part.index, self.data.name, self.data.id
+                )));
+            };
+            v.bound_x1 = start;
+            v.bound_x2 = end;
+            if v.mass_shift_x.is_some() {
+                v.mass_shift_x = Some(part.shift_x);
+            }
+        }
+        Ok(())
+    }
+}
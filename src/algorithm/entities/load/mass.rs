@@ -1,5 +1,7 @@
 //! Масса груз
-use crate::{math::*, Error, ILoad, LoadingType};
+use crate::{algorithm::entities::math::*, kernel::error::error::Error};
+
+use super::{ILoad, LoadingType};
 /// Абстрактная масса груза.
 /// Может вернуть какая масса попадает в указанные границы
 pub trait ILoadMass: ILoad {
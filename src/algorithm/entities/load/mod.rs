@@ -2,21 +2,35 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    data::structs::loads::{
-        CargoGeneralCategory, CompartmentData, LoadCargo, LoadConstantData, LoadConstantType,
-        MatterType,
+    algorithm::entities::{
+        loads::{
+            CargoGeneralCategory, CompartmentData, LoadCargo, LoadConstantData, LoadConstantType,
+            MatterType,
+        },
+        math::{Bound, InertiaMoment, Position},
+        stability::ship_type::ShipType,
     },
-    Bound, Error, InertiaMoment, Position,
+    kernel::error::error::Error,
 };
 mod bulk;
+mod deck_load;
 mod desk;
+mod hold;
 mod mass;
+mod sounding;
+mod stowage;
 mod tank;
+mod volume_correction;
 
 pub use bulk::*;
+pub use deck_load::*;
 pub use desk::*;
+pub use hold::*;
 pub use mass::*;
+pub use sounding::*;
+pub use stowage::*;
 pub use tank::*;
+pub use volume_correction::*;
 
 type Shell<T> = Rc<RefCell<Option<Rc<Vec<Rc<T>>>>>>;
 
@@ -84,6 +98,8 @@ pub struct Loads<'a> {
     shift_const: Position,
     cargoes: &'a Vec<LoadCargo>,
     compartments: &'a Vec<CompartmentData>,
+    soundings: &'a Soundings,
+    ship_type: ShipType,
     tanks: Shell<dyn ITank>,
     desks: Shell<dyn IDesk>,
     bulks: Shell<dyn IBulk>,
@@ -99,17 +115,23 @@ impl<'a> Loads<'_> {
     /// * shift_const - Смещение центра масс постоянной нагрузки на судно
     /// * cargoes - Нагрузка судна без жидких грузов
     /// * compartments - Нагрузка судна: цистерны и трюмы
+    /// * soundings - Калибровочные таблицы цистерн и условия замера
+    /// * ship_type - Тип судна
     pub fn new(
         load_constants: &'a Vec<LoadConstantData>,
         shift_const: Position,
         cargoes: &'a Vec<LoadCargo>,
         compartments: &'a Vec<CompartmentData>,
+        soundings: &'a Soundings,
+        ship_type: ShipType,
     ) -> Loads<'a> {
         Loads {
             load_constants,
             shift_const,
             cargoes,
             compartments,
+            soundings,
+            ship_type,
             tanks: Rc::new(RefCell::new(None)),
             desks: Rc::new(RefCell::new(None)),
             bulks: Rc::new(RefCell::new(None)),
@@ -157,70 +179,8 @@ impl<'a> Loads<'_> {
             log::trace!("\t Mass load_variable from cargoes:{:?} ", load);
             load_variable.push(load.clone());
 
-            if v.is_on_deck {                
-                let bound_y = if let (Some(bound_y1), Some(bound_y2)) = (v.bound_y1, v.bound_y2) {
-                    Bound::new(bound_y1, bound_y2)?
-                } else {
-                    Bound::Full
-                };
-                let bound_z = if let (Some(bound_z1), Some(bound_z2)) = (v.bound_z1, v.bound_z2) {
-                    Bound::new(bound_z1, bound_z2)?
-                } else {
-                    Bound::Full
-                };
-                let (vertical_area, horizontal_area) = 
-                    if let (Some(vertical_area), Some(horizontal_area)) = (v.vertical_area, v.horizontal_area) {
-                        (vertical_area, horizontal_area)
-                } else {
-                    if let (Some(bound_x), Some(bound_y), Some(bound_z)) = (bound_x.length(), bound_y.length(), bound_z.length()) {
-                        (bound_z*bound_x, bound_y*bound_x)
-                    } else {
-                        return Err(Error::FromString(format!("Loads create error: no areas for cargo {}", v.name)));
-                    }
-                };
-                let mass_shift = if let Some(mass_shift) = mass_shift {
-                    mass_shift
-                } else {
-                    if let (Bound::Value(_, _), Bound::Value(_, _)) = (bound_y, bound_z) {
-                        Some(Position::new(
-                            bound_x.center().unwrap(),
-                            bound_y.center().unwrap(),
-                            bound_z.center().unwrap(),
-                        ))
-                    } else {
-                        None
-                    }
-                    .ok_or(Error::FromString("Load create Desk error: no center of mass!".to_string()))?
-                };
-                let vertical_shift = if let (
-                    Some(vertical_area_shift_x),
-                    Some(vertical_area_shift_y),
-                    Some(vertical_area_shift_z),
-                ) = (
-                    v.vertical_area_shift_x,
-                    v.vertical_area_shift_y,
-                    v.vertical_area_shift_z,
-                ) {
-                    Position::new(
-                        vertical_area_shift_x,
-                        vertical_area_shift_y,
-                        vertical_area_shift_z,
-                    )
-                } else {
-                    mass_shift
-                };
-                let desk: Rc<dyn IDesk> = Rc::new(Desk::new(
-                    v.mass.ok_or("LoadCargo error: no mass!".to_string())?,
-                    mass_shift,
-                    bound_x,
-                    bound_y,
-                    bound_z,
-                    vertical_area,
-                    vertical_shift,
-                    horizontal_area,
-                    v.timber,
-                    v.container.unwrap_or(false),
-                ));
+            if v.is_on_deck {
+                let desk: Rc<dyn IDesk> = Rc::new(Desk::from_cargo(v)?);
                 desks.push(desk);
             }
             if v.timber {
@@ -242,6 +202,53 @@ impl<'a> Loads<'_> {
                 None
             };
             let bound_x = Bound::new(v.bound_x1, v.bound_x2)?;
+            if v.matter_type == MatterType::Liquid {
+                let calibration = v.space_id.and_then(|space_id| self.soundings.calibration(space_id));
+                let correction = match self.ship_type {
+                    ShipType::OilTanker | ShipType::ChemicalTanker => v.volume_correction()?,
+                    _ => None,
+                };
+                // плотность при наблюдаемой температуре, если груз задан плотностью при 15°C
+                let density = match (&correction, v.temperature) {
+                    (Some(correction), Some(temperature)) => Some(correction.density(temperature)),
+                    _ => v.density,
+                };
+                let tank = if let (Some(calibration), Some(reading)) = (calibration, v.sounding_reading()) {
+                    Some(Tank::from_sounding(
+                        density.ok_or("CompartmentData error: no density for sounding!".to_string())?,
+                        calibration,
+                        reading,
+                        self.soundings.trim(),
+                        self.soundings.heel(),
+                        bound_x,
+                        LoadingType::from(v.general_category),
+                    )?)
+                } else if correction.is_some() {
+                    Some(Tank::new(
+                        density.ok_or("CompartmentData error: no density for volume correction!".to_string())?,
+                        v.volume.ok_or("CompartmentData error: no volume for volume correction!".to_string())?,
+                        bound_x,
+                        mass_shift,
+                        InertiaMoment::new(v.m_f_s_x.unwrap_or(0.), v.m_f_s_y.unwrap_or(0.)),
+                        LoadingType::from(v.general_category),
+                    )?)
+                } else {
+                    None
+                };
+                if let Some(tank) = tank {
+                    let load = Rc::new(LoadMass::new(
+                        tank.mass(),
+                        bound_x,
+                        Some(tank.shift()),
+                        LoadingType::from(v.general_category),
+                    )?);
+                    log::trace!("\t Mass load_variable and tank from sounding or volume correction src:{:?} trg:{:?}", v, tank, );
+                    load_variable.push(load);
+                    let tank: Rc<dyn ITank> = Rc::new(tank);
+                    tanks.push(tank);
+                    continue;
+                }
+            }
             if let Some(mass) = v.mass {
                 let load = Rc::new(LoadMass::new(
                     mass,
@@ -269,10 +276,14 @@ impl<'a> Loads<'_> {
                 tanks.push(tank);
             }
             if v.matter_type == MatterType::Bulk {
-                let bulk: Rc<dyn IBulk> = Rc::new(Bulk::new(
-                    1. / v.density.ok_or("CompartmentData error: no density for PhysicalType::Bulk!".to_string())?,
-                    v.grain_moment.ok_or("CompartmentData error: no grain_moment for PhysicalType::Bulk!".to_string())?,
-                )?);
+                let density = v.density.ok_or("CompartmentData error: no density for PhysicalType::Bulk!".to_string())?;
+                let shift = v.bulk_shift();
+                let moment = match shift {
+                    BulkShift::Fixed(_) => v.grain_moment.unwrap_or(0.),
+                    _ => v.grain_moment.ok_or("CompartmentData error: no grain_moment for PhysicalType::Bulk!".to_string())?,
+                };
+                let mass = v.mass.unwrap_or(density * v.volume.unwrap_or(0.));
+                let bulk: Rc<dyn IBulk> = Rc::new(Bulk::new_shift(1. / density, moment, mass, shift)?);
                 bulks.push(bulk);
             }
         }
@@ -0,0 +1,182 @@
+//! Калибровочные таблицы цистерны
+use std::collections::HashMap;
+
+use crate::{
+    algorithm::entities::{loads::{SoundingData, SoundingTables}, math::*},
+    kernel::error::error::Error,
+};
+
+/// Показание замера уровня жидкости в цистерне
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SoundingReading {
+    /// Замер уровня жидкости от днища цистерны, м
+    Sounding(f64),
+    /// Замер пустоты от точки отсчета (верха мерительной трубки), м
+    /// * ullage - высота пустоты, м
+    /// * reference - высота точки отсчета над днищем цистерны, м
+    Ullage { ullage: f64, reference: f64 },
+}
+//
+impl SoundingReading {
+    /// Уровень жидкости от днища цистерны, м
+    pub fn sounding(&self) -> f64 {
+        match self {
+            SoundingReading::Sounding(sounding) => *sounding,
+            SoundingReading::Ullage { ullage, reference } => reference - ullage,
+        }
+    }
+}
+/// Калибровочные таблицы цистерны: объем, центр величины и
+/// момент инерции свободной поверхности в зависимости от
+/// уровня заполнения, с поправками на дифферент и крен
+#[derive(Clone)]
+pub struct TankCalibration {
+    /// Объем жидкости от замера
+    volume: Curve<f64>,
+    /// Отстояние центра величины от замера
+    shift: Curve<Position>,
+    /// Поперечный момент инерции свободной поверхности от замера
+    inertia_x: Curve<f64>,
+    /// Продольный момент инерции свободной поверхности от замера
+    inertia_y: Curve<f64>,
+    /// Поправка к замеру на дифферент
+    trim_correction: Option<Curve2D<f64>>,
+    /// Поправка к замеру на крен
+    heel_correction: Option<Curve2D<f64>>,
+}
+//
+impl TankCalibration {
+    /// Основной конструктор
+    /// * table - Калибровочная таблица цистерны
+    /// * trim_correction - Поправка к замеру: (дифферент, (замер, поправка))
+    /// * heel_correction - Поправка к замеру: (крен, (замер, поправка))
+    pub fn new(
+        table: &[SoundingData],
+        trim_correction: &[(f64, Vec<(f64, f64)>)],
+        heel_correction: &[(f64, Vec<(f64, f64)>)],
+    ) -> Result<Self, Error> {
+        if let Some(v) = table.iter().find(|v| v.sounding.is_nan()) {
+            return Err(Error::FromString(format!(
+                "TankCalibration new error: sounding is NaN in {v}"
+            )));
+        }
+        let mut table = table.to_vec();
+        table.sort_by(|a, b| a.sounding.total_cmp(&b.sounding));
+        let map = |f: fn(&SoundingData) -> f64| -> Vec<(f64, f64)> {
+            table.iter().map(|v| (v.sounding, f(v))).collect()
+        };
+        let correction = |src: &[(f64, Vec<(f64, f64)>)]| -> Result<Option<Curve2D<f64>>, Error> {
+            if src.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(Curve2D::from_values_linear(src)?))
+            }
+        };
+        Ok(Self {
+            volume: Curve::new_linear(&map(|v| v.volume))
+                .map_err(|e| Error::FromString(format!("TankCalibration new volume error: {e}")))?,
+            shift: Curve::new_linear(
+                &table
+                    .iter()
+                    .map(|v| (v.sounding, Position::new(v.shift_x, v.shift_y, v.shift_z)))
+                    .collect::<Vec<_>>(),
+            )
+            .map_err(|e| Error::FromString(format!("TankCalibration new shift error: {e}")))?,
+            inertia_x: Curve::new_linear(&map(|v| v.m_f_s_x))
+                .map_err(|e| Error::FromString(format!("TankCalibration new inertia_x error: {e}")))?,
+            inertia_y: Curve::new_linear(&map(|v| v.m_f_s_y))
+                .map_err(|e| Error::FromString(format!("TankCalibration new inertia_y error: {e}")))?,
+            trim_correction: correction(trim_correction)?,
+            heel_correction: correction(heel_correction)?,
+        })
+    }
+    /// Уровень заполнения с учетом поправок на дифферент и крен, м
+    /// * reading - Показание замера
+    /// * trim - Дифферент судна в момент замера, м
+    /// * heel - Крен судна в момент замера, градус
+    pub fn sounding(&self, reading: SoundingReading, trim: f64, heel: f64) -> Result<f64, Error> {
+        let observed = reading.sounding();
+        if observed < 0. {
+            return Err(Error::FromString(format!(
+                "TankCalibration sounding error: observed sounding {observed} < 0."
+            )));
+        }
+        let mut sounding = observed;
+        if let Some(curve) = &self.trim_correction {
+            sounding += curve.value(trim, observed)?.value;
+        }
+        if let Some(curve) = &self.heel_correction {
+            sounding += curve.value(heel, observed)?.value;
+        }
+        Ok(sounding.max(0.))
+    }
+    /// Объем жидкости при заданном уровне заполнения, м^3
+    pub fn volume(&self, sounding: f64) -> Result<f64, Error> {
+        self.volume.value(sounding)
+    }
+    /// Отстояние центра величины при заданном уровне заполнения, м
+    pub fn shift(&self, sounding: f64) -> Result<Position, Error> {
+        self.shift.value(sounding)
+    }
+    /// Момент инерции свободной поверхности при заданном уровне заполнения, м^4
+    pub fn inertia(&self, sounding: f64) -> Result<InertiaMoment, Error> {
+        Ok(InertiaMoment::new(
+            self.inertia_x.value(sounding)?,
+            self.inertia_y.value(sounding)?,
+        ))
+    }
+}
+/// Калибровочные таблицы цистерн судна и условия, при которых
+/// выполнялись замеры
+#[derive(Clone, Default)]
+pub struct Soundings {
+    /// Калибровочные таблицы по ИД помещения
+    calibrations: HashMap<i32, TankCalibration>,
+    /// Дифферент судна в момент замера, м
+    trim: f64,
+    /// Крен судна в момент замера, градус
+    heel: f64,
+}
+//
+impl Soundings {
+    /// Основной конструктор
+    /// * calibrations - Калибровочные таблицы по ИД помещения
+    /// * trim - Дифферент судна в момент замера, м
+    /// * heel - Крен судна в момент замера, градус
+    pub fn new(calibrations: HashMap<i32, TankCalibration>, trim: f64, heel: f64) -> Self {
+        Self {
+            calibrations,
+            trim,
+            heel,
+        }
+    }
+    /// Конструктор из данных, полученных из БД
+    /// * tables - Калибровочные таблицы цистерн с поправками
+    /// * trim - Дифферент судна в момент замера, м
+    /// * heel - Крен судна в момент замера, градус
+    pub fn from_data(tables: &SoundingTables, trim: f64, heel: f64) -> Result<Self, Error> {
+        let mut calibrations = HashMap::new();
+        for (space_id, table) in &tables.table {
+            let calibration = TankCalibration::new(
+                table,
+                tables.trim_correction.get(space_id).map(|v| v.as_slice()).unwrap_or(&[]),
+                tables.heel_correction.get(space_id).map(|v| v.as_slice()).unwrap_or(&[]),
+            )
+            .map_err(|e| Error::FromString(format!("Soundings from_data space_id:{space_id} error: {e}")))?;
+            calibrations.insert(*space_id, calibration);
+        }
+        Ok(Self::new(calibrations, trim, heel))
+    }
+    /// Калибровочные таблицы цистерны
+    pub fn calibration(&self, space_id: i32) -> Option<&TankCalibration> {
+        self.calibrations.get(&space_id)
+    }
+    /// Дифферент судна в момент замера, м
+    pub fn trim(&self) -> f64 {
+        self.trim
+    }
+    /// Крен судна в момент замера, градус
+    pub fn heel(&self) -> f64 {
+        self.heel
+    }
+}
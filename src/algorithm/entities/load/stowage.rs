@@ -0,0 +1,290 @@
+//! Размещение контейнеров по ячейкам: ряд по длине (bay),
+//! ряд по ширине (row) и ярус (tier)
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{
+        loads::{Container, ContainerStackLimit},
+        math::Position,
+    },
+    kernel::error::error::Error,
+};
+
+/// Типоразмер контейнера по длине
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ContainerSize {
+    /// 20 футов
+    Twenty,
+    /// 40 футов
+    Forty,
+    /// 45 футов, размещается в ячейке 40'
+    FortyFive,
+}
+//
+impl ContainerSize {
+    /// Разбор типоразмера по коду ISO 6346
+    /// * iso_code - Код размера и типа, например "22G1" или "45G1"
+    pub fn from_iso(iso_code: &str) -> Result<Self, Error> {
+        match iso_code.trim().chars().next() {
+            Some('2') => Ok(ContainerSize::Twenty),
+            Some('4') => Ok(ContainerSize::Forty),
+            Some('L') | Some('l') => Ok(ContainerSize::FortyFive),
+            _ => Err(Error::FromString(format!(
+                "ContainerSize from_iso error: unknown size code {iso_code}"
+            ))),
+        }
+    }
+    /// Признак контейнера длиной 20'
+    pub fn is_twenty(&self) -> bool {
+        *self == ContainerSize::Twenty
+    }
+}
+/// Адрес ячейки контейнера в формате BBRRTT
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SlotAddress {
+    /// Ряд по длине: нечетный для 20', четный для 40'
+    pub bay: i32,
+    /// Ряд по ширине
+    pub row: i32,
+    /// Ярус: 02..78 в трюме, 80 и выше на палубе
+    pub tier: i32,
+}
+//
+impl SlotAddress {
+    /// Номер первого палубного яруса
+    const DECK_TIER: i32 = 80;
+    /// Основной конструктор
+    pub fn new(bay: i32, row: i32, tier: i32) -> Self {
+        Self { bay, row, tier }
+    }
+    /// Признак ячейки на палубе
+    pub fn is_on_deck(&self) -> bool {
+        self.tier >= Self::DECK_TIER
+    }
+    /// Номер ряда 40', в который входит ряд 20'.
+    /// Ряды 40' охватывают пары рядов 20': 02 - 01 и 03, 06 - 05 и 07 и т.д.
+    pub fn forty_bay(bay: i32) -> i32 {
+        if bay % 2 == 0 {
+            bay
+        } else if bay % 4 == 1 {
+            bay + 1
+        } else {
+            bay - 1
+        }
+    }
+}
+//
+impl std::fmt::Display for SlotAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}{:02}{:02}", self.bay, self.row, self.tier)
+    }
+}
+/// Нарушение правил размещения контейнеров
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StowageViolation {
+    /// Превышена допустимая масса штабеля
+    StackMass {
+        bay: i32,
+        row: i32,
+        is_on_deck: bool,
+        mass: f64,
+        limit: f64,
+    },
+    /// Контейнер 20' установлен поверх контейнера 40'
+    Mixing { lower: i32, upper: i32 },
+    /// Два контейнера занимают одну ячейку
+    SlotConflict { first: i32, second: i32 },
+}
+//
+impl std::fmt::Display for StowageViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StowageViolation::StackMass { bay, row, is_on_deck, mass, limit } => write!(
+                f,
+                "StackMass(bay:{bay} row:{row} is_on_deck:{is_on_deck} mass:{mass} limit:{limit})"
+            ),
+            StowageViolation::Mixing { lower, upper } => {
+                write!(f, "Mixing(lower:{lower} upper:{upper})")
+            }
+            StowageViolation::SlotConflict { first, second } => {
+                write!(f, "SlotConflict(first:{first} second:{second})")
+            }
+        }
+    }
+}
+/// Контейнер в ячейке
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerSlot {
+    /// ИД груза
+    pub id: i32,
+    /// Адрес ячейки
+    pub address: SlotAddress,
+    /// Типоразмер контейнера
+    pub size: ContainerSize,
+    /// Масса контейнера, т. Берется VGM, если задана
+    pub mass: f64,
+}
+/// Модель размещения контейнеров по ячейкам
+#[derive(Debug, Clone, Default)]
+pub struct Stowage {
+    /// Контейнеры с заданным адресом ячейки
+    slots: Vec<ContainerSlot>,
+}
+//
+impl Stowage {
+    /// Основной конструктор. Контейнеры без адреса ячейки не учитываются
+    /// * containers - Контейнеры
+    pub fn new(containers: &[Container]) -> Result<Self, Error> {
+        let mut slots = Vec::new();
+        for v in containers {
+            let (Some(bay), Some(row), Some(tier)) = (v.bay, v.row, v.tier) else {
+                continue;
+            };
+            let address = SlotAddress::new(bay, row, tier);
+            let size = match &v.iso_code {
+                Some(iso_code) => ContainerSize::from_iso(iso_code)?,
+                None if bay % 2 == 0 => ContainerSize::Forty,
+                None => ContainerSize::Twenty,
+            };
+            if size.is_twenty() == (bay % 2 == 0) {
+                return Err(Error::FromString(format!(
+                    "Stowage new error: container {} size {:?} does not match bay {bay}",
+                    v.id, size
+                )));
+            }
+            slots.push(ContainerSlot {
+                id: v.id,
+                address,
+                size,
+                mass: v.vgm.unwrap_or(v.mass),
+            });
+        }
+        Ok(Self { slots })
+    }
+    /// Масса штабеля, т. Для ряда 20' учитывается половина массы
+    /// контейнеров 40', опирающихся на него, для ряда 40' - полная масса
+    /// контейнеров обоих входящих в него рядов 20'
+    /// * bay - Ряд по длине
+    /// * row - Ряд по ширине
+    /// * is_on_deck - Признак штабеля на палубе
+    pub fn stack_mass(&self, bay: i32, row: i32, is_on_deck: bool) -> f64 {
+        self.slots
+            .iter()
+            .filter(|v| v.address.row == row && v.address.is_on_deck() == is_on_deck)
+            .map(|v| {
+                let slot_bay = v.address.bay;
+                match (bay % 2 == 0, v.size.is_twenty()) {
+                    (true, true) if SlotAddress::forty_bay(slot_bay) == bay => v.mass,
+                    (true, false) if slot_bay == bay => v.mass,
+                    (false, true) if slot_bay == bay => v.mass,
+                    (false, false) if SlotAddress::forty_bay(bay) == slot_bay => v.mass / 2.,
+                    _ => 0.,
+                }
+            })
+            .sum()
+    }
+    /// Проверка масс штабелей
+    /// * limits - Допустимые массы штабелей
+    pub fn check_stack_mass(&self, limits: &[ContainerStackLimit]) -> Vec<StowageViolation> {
+        limits
+            .iter()
+            .filter_map(|limit| {
+                let mass = self.stack_mass(limit.bay, limit.row, limit.is_on_deck);
+                (mass > limit.max_mass).then_some(StowageViolation::StackMass {
+                    bay: limit.bay,
+                    row: limit.row,
+                    is_on_deck: limit.is_on_deck,
+                    mass,
+                    limit: limit.max_mass,
+                })
+            })
+            .collect()
+    }
+    /// Проверка правил совместного размещения 20' и 40':
+    /// 20' не ставится поверх 40' в том же штабеле, и
+    /// ячейка не может быть занята дважды
+    pub fn check_mixing(&self) -> Vec<StowageViolation> {
+        let mut result = Vec::new();
+        for (i, a) in self.slots.iter().enumerate() {
+            for b in self.slots.iter().skip(i + 1) {
+                if a.address.row != b.address.row
+                    || a.address.is_on_deck() != b.address.is_on_deck()
+                    || SlotAddress::forty_bay(a.address.bay) != SlotAddress::forty_bay(b.address.bay)
+                {
+                    continue;
+                }
+                // 20' в разных рядах 20' не пересекаются
+                if a.size.is_twenty() && b.size.is_twenty() && a.address.bay != b.address.bay {
+                    continue;
+                }
+                if a.address.tier == b.address.tier {
+                    result.push(StowageViolation::SlotConflict { first: a.id, second: b.id });
+                    continue;
+                }
+                let (lower, upper) = if a.address.tier < b.address.tier { (a, b) } else { (b, a) };
+                if !lower.size.is_twenty() && upper.size.is_twenty() {
+                    result.push(StowageViolation::Mixing { lower: lower.id, upper: upper.id });
+                }
+            }
+        }
+        result
+    }
+    /// Парусность палубных контейнеров по силуэту штабелей.
+    /// Силуэт делится на участки по длине между границами контейнеров,
+    /// площадь участка считается по объединению высот перекрывающих его
+    /// контейнеров и делится поровну между ними, поэтому площадь
+    /// контейнеров 20' и 40' в одном ряду учитывается один раз.
+    /// Возвращает мапу ИД груза / (площадь, м^2, центр площади)
+    /// * containers - Контейнеры
+    pub fn windage(containers: &[Container]) -> HashMap<i32, (f64, Position)> {
+        let containers: Vec<_> = containers.iter().filter(|v| v.is_on_deck).collect();
+        let mut xs: Vec<f64> = containers.iter().flat_map(|v| [v.bound_x1, v.bound_x2]).collect();
+        xs.sort_by(f64::total_cmp);
+        xs.dedup_by(|a, b| (*a - *b).abs() < 1.0e-3);
+        // ИД груза / (площадь, статический момент площади по x и z)
+        let mut shares: HashMap<i32, (f64, f64, f64)> = HashMap::new();
+        for pair in xs.windows(2) {
+            let (x1, x2) = (pair[0], pair[1]);
+            let covering: Vec<_> = containers
+                .iter()
+                .filter(|v| v.bound_x1 < x2 - 1.0e-3 && v.bound_x2 > x1 + 1.0e-3)
+                .collect();
+            if covering.is_empty() {
+                continue;
+            }
+            let mut zs: Vec<(f64, f64)> = covering.iter().map(|v| (v.bound_z1, v.bound_z2)).collect();
+            zs.sort_by(|a, b| a.0.total_cmp(&b.0));
+            // объединение интервалов по высоте: (высота, статический момент)
+            let (mut height, mut moment_z) = (0., 0.);
+            let mut current = zs[0];
+            for &(z1, z2) in zs.iter().skip(1) {
+                if z1 > current.1 {
+                    height += current.1 - current.0;
+                    moment_z += (current.1.powi(2) - current.0.powi(2)) / 2.;
+                    current = (z1, z2);
+                } else {
+                    current.1 = current.1.max(z2);
+                }
+            }
+            height += current.1 - current.0;
+            moment_z += (current.1.powi(2) - current.0.powi(2)) / 2.;
+            let area = (x2 - x1) * height / covering.len() as f64;
+            let z = moment_z / height;
+            covering.iter().for_each(|v| {
+                let share = shares.entry(v.id).or_insert((0., 0., 0.));
+                share.0 += area;
+                share.1 += area * (x1 + x2) / 2.;
+                share.2 += area * z;
+            });
+        }
+        containers
+            .iter()
+            .filter_map(|v| {
+                let &(area, moment_x, moment_z) = shares.get(&v.id)?;
+                let y = (v.bound_y1 + v.bound_y2) / 2.;
+                Some((v.id, (area, Position::new(moment_x / area, y, moment_z / area))))
+            })
+            .collect()
+    }
+}
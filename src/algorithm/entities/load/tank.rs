@@ -1,5 +1,7 @@
 //! Цистерна с жидкостью
-use crate::{math::*, Error, ILoad, ILoadMass, LoadingType};
+use crate::{algorithm::entities::math::*, kernel::error::error::Error};
+
+use super::{ILoad, ILoadMass, LoadingType, SoundingReading, TankCalibration};
 
 /// Цистерна с жидкостью.
 /// Имеет свойства свободной поверхности жидкости.
@@ -58,6 +60,33 @@ impl Tank {
             load_type,
         })
     }
+    /// Конструктор по замеру уровня жидкости
+    /// * density - Плотность жидкости в цистерне
+    /// * calibration - Калибровочные таблицы цистерны
+    /// * reading - Показание замера или пустоты
+    /// * trim - Дифферент судна в момент замера, м
+    /// * heel - Крен судна в момент замера, градус
+    /// * bound_x - Границы цистерны по оси Х
+    /// * load_type - Тип груза
+    pub fn from_sounding(
+        density: f64,
+        calibration: &TankCalibration,
+        reading: SoundingReading,
+        trim: f64,
+        heel: f64,
+        bound_x: Bound,
+        load_type: LoadingType,
+    ) -> Result<Self, Error> {
+        let sounding = calibration.sounding(reading, trim, heel)?;
+        Self::new(
+            density,
+            calibration.volume(sounding)?,
+            bound_x,
+            Some(calibration.shift(sounding)?),
+            calibration.inertia(sounding)?,
+            load_type,
+        )
+    }
 }
 //
 impl ITank for Tank {
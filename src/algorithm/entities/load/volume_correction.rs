@@ -0,0 +1,124 @@
+//! Температурная поправка объема жидкого груза
+use crate::kernel::error::error::Error;
+
+/// Таблица поправочных коэффициентов объема (VCF) по ASTM D1250
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum VcfTable {
+    /// Табл. 54A - сырая нефть
+    Crude,
+    /// Табл. 54B - нефтепродукты
+    Products,
+    /// Табл. 54C - груз с известным коэффициентом
+    /// температурного расширения, 1/°C
+    Special(f64),
+    /// Табл. 54D - смазочные масла
+    Lubricants,
+}
+//
+impl VcfTable {
+    /// Разбор типа таблицы из строки
+    /// * src - "54A", "54B", "54C" или "54D"
+    /// * alpha - Коэффициент температурного расширения для табл. 54C, 1/°C
+    pub fn from_str(src: &str, alpha: Option<f64>) -> Result<Self, Error> {
+        Ok(match src.trim().to_uppercase().as_str() {
+            "54A" => VcfTable::Crude,
+            "54B" => VcfTable::Products,
+            "54C" => VcfTable::Special(alpha.ok_or(Error::FromString(
+                "VcfTable from_str error: no alpha for table 54C".to_owned(),
+            ))?),
+            "54D" => VcfTable::Lubricants,
+            src => return Err(Error::FromString(format!("VcfTable from_str error: no table {src}"))),
+        })
+    }
+}
+//
+impl std::fmt::Display for VcfTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VcfTable::Crude => write!(f, "54A"),
+            VcfTable::Products => write!(f, "54B"),
+            VcfTable::Special(alpha) => write!(f, "54C(alpha:{alpha})"),
+            VcfTable::Lubricants => write!(f, "54D"),
+        }
+    }
+}
+/// Температурная поправка объема жидкого груза.
+/// Приводит наблюдаемый объем к стандартной температуре 15°C
+#[derive(Debug, Clone)]
+pub struct VolumeCorrection {
+    /// Таблица поправочных коэффициентов
+    table: VcfTable,
+    /// Плотность груза при 15°C, т/м^3
+    density_15: f64,
+}
+//
+impl VolumeCorrection {
+    /// Стандартная температура, °C
+    const T_15: f64 = 15.;
+    /// Поправка на плавучесть в воздухе, т/м^3
+    const AIR_BUOYANCY: f64 = 0.0011;
+    /// Основной конструктор
+    /// * table - Таблица поправочных коэффициентов
+    /// * density_15 - Плотность груза при 15°C, т/м^3
+    pub fn new(table: VcfTable, density_15: f64) -> Result<Self, Error> {
+        let (min, max) = match table {
+            VcfTable::Crude => (0.6105, 1.075),
+            VcfTable::Products => (0.653, 1.075),
+            VcfTable::Special(_) => (0., f64::MAX),
+            VcfTable::Lubricants => (0.8, 1.164),
+        };
+        if density_15 <= min || density_15 > max {
+            return Err(Error::FromString(format!(
+                "VolumeCorrection new error: density_15 {density_15} out of range ({min}, {max}] for table {table}"
+            )));
+        }
+        Ok(Self { table, density_15 })
+    }
+    /// Коэффициент температурного расширения при 15°C, 1/°C
+    fn alpha(&self) -> f64 {
+        // плотность в кг/м^3, как в таблицах ASTM
+        let rho = self.density_15 * 1000.;
+        let (k0, k1) = match self.table {
+            VcfTable::Crude => (613.9723, 0.),
+            VcfTable::Products => match rho {
+                rho if rho >= 839. => (186.9696, 0.4862),
+                rho if rho >= 787.5 => (594.5418, 0.),
+                rho if rho >= 770.5 => return -0.00336312 + 2680.32 / (rho * rho),
+                _ => (346.4228, 0.4388),
+            },
+            VcfTable::Special(alpha) => return alpha,
+            VcfTable::Lubricants => (0., 0.6278),
+        };
+        k0 / (rho * rho) + k1 / rho
+    }
+    /// Поправочный коэффициент объема (VCF)
+    /// * temperature - Температура груза, °C
+    pub fn vcf(&self, temperature: f64) -> f64 {
+        let alpha = self.alpha();
+        let delta_t = temperature - Self::T_15;
+        (-alpha * delta_t * (1. + 0.8 * alpha * delta_t)).exp()
+    }
+    /// Объем груза при 15°C, м^3
+    /// * volume - Наблюдаемый объем, м^3
+    /// * temperature - Температура груза, °C
+    pub fn standard_volume(&self, volume: f64, temperature: f64) -> f64 {
+        volume * self.vcf(temperature)
+    }
+    /// Плотность груза при наблюдаемой температуре, т/м^3
+    /// * temperature - Температура груза, °C
+    pub fn density(&self, temperature: f64) -> f64 {
+        self.density_15 * self.vcf(temperature)
+    }
+    /// Масса груза в вакууме, т
+    /// * volume - Наблюдаемый объем, м^3
+    /// * temperature - Температура груза, °C
+    pub fn mass(&self, volume: f64, temperature: f64) -> f64 {
+        self.standard_volume(volume, temperature) * self.density_15
+    }
+    /// Масса груза в воздухе, как в коносаменте, т
+    /// * volume - Наблюдаемый объем, м^3
+    /// * temperature - Температура груза, °C
+    pub fn mass_in_air(&self, volume: f64, temperature: f64) -> f64 {
+        self.standard_volume(volume, temperature) * (self.density_15 - Self::AIR_BUOYANCY)
+    }
+}
@@ -1,10 +1,14 @@
 //! Промежуточные структуры для serde_json для парсинга данных зерновой перегородки
-use crate::data::structs::DataArray;
+use crate::algorithm::entities::DataArray;
 use serde::{Deserialize, Serialize};
 use super::{CargoGeneralCategory, LoadCargo};
 /// Зерновая перегородка
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Bulkhead {
+    /// ИД съемной перегородки
+    pub id: Option<i32>,
+    /// Номер места установки съемной перегородки
+    pub position: Option<i32>,
     /// Имя
     pub name: String,
     /// Общая масса, т
@@ -36,36 +40,39 @@ impl std::fmt::Display for Bulkhead {
         )
     }
 }
+//
+impl From<Bulkhead> for LoadCargo {
+    fn from(v: Bulkhead) -> Self {
+        LoadCargo {
+            name: v.name,
+            mass: v.mass,
+            general_category: v.general_category,
+            timber: false,
+            is_on_deck: false,
+            container: None,
+            bound_x1: v.bound_x1,
+            bound_x2: v.bound_x2,
+            bound_y1: None,
+            bound_y2: None,
+            bound_z1: None,
+            bound_z2: None,
+            mass_shift_x: v.mass_shift_x,
+            mass_shift_y: v.mass_shift_y,
+            mass_shift_z: v.mass_shift_z,
+            horizontal_area: None,
+            vertical_area: None,
+            vertical_area_shift_x: None,
+            vertical_area_shift_y: None,
+            vertical_area_shift_z: None,
+        }
+    }
+}
 /// Массив данных по грузам
 pub type BulkheadArray = DataArray<Bulkhead>;
 //
 impl BulkheadArray {
-    //
+    /// Перегородки в виде грузов в исходном положении
     pub fn data(self) -> Vec<LoadCargo> {
-        self.data
-            .into_iter()
-            .map(|v| LoadCargo {
-                name: v.name,
-                mass: v.mass,
-                general_category: v.general_category,
-                timber: false,
-                is_on_deck: false,
-                container: None,
-                bound_x1: v.bound_x1,
-                bound_x2: v.bound_x2,
-                bound_y1: None,
-                bound_y2: None,
-                bound_z1: None,
-                bound_z2: None,
-                mass_shift_x: v.mass_shift_x,
-                mass_shift_y: v.mass_shift_y,
-                mass_shift_z: v.mass_shift_z,
-                horizontal_area: None,
-                vertical_area: None,
-                vertical_area_shift_x: None,
-                vertical_area_shift_y: None,
-                vertical_area_shift_z: None,
-            })
-            .collect()
+        self.data.into_iter().map(LoadCargo::from).collect()
     }
 }
@@ -1,10 +1,16 @@
 //! Промежуточные структуры для serde_json для парсинга данных груза
-use crate::data::structs::DataArray;
+use crate::algorithm::entities::DataArray;
 use serde::{Deserialize, Serialize};
+use crate::{
+    algorithm::entities::load::{BulkShift, SoundingReading, VcfTable, VolumeCorrection},
+    kernel::error::error::Error,
+};
 use super::{CargoGeneralCategory, MatterType};
 /// Помещения судна: цистерны и трюмы  
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct CompartmentData {
+    /// ИД помещения
+    pub space_id: Option<i32>,
     /// Имя груза
     pub name: String,
     /// Общая масса, т
@@ -25,10 +31,32 @@ pub struct CompartmentData {
     pub m_f_s_x: Option<f64>,
     /// Кренящий момент от смещения сыпучего груза, м4
     pub grain_moment: Option<f64>,
+    /// Угол естественного откоса навалочного груза, градус
+    pub angle_of_repose: Option<f64>,
+    /// Фиксированное поперечное смещение навалочного груза, м
+    pub bulk_shift_y: Option<f64>,
     /// Классификация груза
     pub general_category: CargoGeneralCategory,
     /// Физический тип груза судна
     pub matter_type: MatterType,
+    /// Замер уровня жидкости, м
+    pub sounding: Option<f64>,
+    /// Замер пустоты, м
+    pub ullage: Option<f64>,
+    /// Высота точки отсчета замера пустоты над днищем, м
+    pub ullage_reference: Option<f64>,
+    /// Температура жидкого груза, °C
+    pub temperature: Option<f64>,
+    /// Плотность жидкого груза при 15°C, т/м^3
+    pub density_15: Option<f64>,
+    /// Таблица поправочных коэффициентов объема: 54A, 54B, 54C, 54D
+    pub vcf_table: Option<String>,
+    /// Коэффициент температурного расширения для табл. 54C, 1/°C
+    pub vcf_alpha: Option<f64>,
+    /// ИД трюма, если помещение - часть трюма
+    pub hold_id: Option<i32>,
+    /// Номер части трюма от кормы
+    pub part_index: Option<i32>,
 }
 //
 impl std::fmt::Display for CompartmentData {
@@ -36,7 +64,8 @@ impl std::fmt::Display for CompartmentData {
         write!(
             f,
             "CompartmentData(name:{} mass:{} density:{} volume:{} bound:({}, {}) \
-             mass_shift:({}, {}, {}) m_f_s_y:{} m_f_s_x:{} grain_moment:{} general_category:{} matter_type:{})",
+             mass_shift:({}, {}, {}) m_f_s_y:{} m_f_s_x:{} grain_moment:{} general_category:{} matter_type:{} \
+             sounding:{} ullage:{} temperature:{} density_15:{})",
             self.name,
             self.mass.unwrap_or(0.),
             self.density.unwrap_or(0.),
@@ -51,9 +80,47 @@ impl std::fmt::Display for CompartmentData {
             self.grain_moment.unwrap_or(0.),
             self.general_category,
             self.matter_type,
+            self.sounding.unwrap_or(0.),
+            self.ullage.unwrap_or(0.),
+            self.temperature.unwrap_or(0.),
+            self.density_15.unwrap_or(0.),
         )
     }
 }
+//
+impl CompartmentData {
+    /// Показание замера, если он задан: уровень жидкости или пустота
+    pub fn sounding_reading(&self) -> Option<SoundingReading> {
+        match (self.sounding, self.ullage, self.ullage_reference) {
+            (Some(sounding), _, _) => Some(SoundingReading::Sounding(sounding)),
+            (None, Some(ullage), Some(reference)) => Some(SoundingReading::Ullage { ullage, reference }),
+            _ => None,
+        }
+    }
+    /// Способ смещения навалочного груза: фиксированное смещение,
+    /// по углу естественного откоса или по таблицам зерна
+    pub fn bulk_shift(&self) -> BulkShift {
+        match (self.bulk_shift_y, self.angle_of_repose) {
+            (Some(shift_y), _) => BulkShift::Fixed(shift_y),
+            (None, Some(angle)) => BulkShift::Repose(angle),
+            (None, None) => BulkShift::Grain,
+        }
+    }
+    /// Температурная поправка объема, если заданы температура и
+    /// плотность при 15°C. По умолчанию используется табл. 54B
+    pub fn volume_correction(&self) -> Result<Option<VolumeCorrection>, Error> {
+        match (self.temperature, self.density_15) {
+            (Some(_), Some(density_15)) => {
+                let table = match &self.vcf_table {
+                    Some(table) => VcfTable::from_str(table, self.vcf_alpha)?,
+                    None => VcfTable::Products,
+                };
+                Ok(Some(VolumeCorrection::new(table, density_15)?))
+            }
+            _ => Ok(None),
+        }
+    }
+}
 /// Массив данных по грузам
 pub type CompartmentArray = DataArray<CompartmentData>;
 //
@@ -1,6 +1,6 @@
 //! Промежуточные структуры для serde_json для парсинга данных контейнеров
 use super::{CargoGeneralCategory, LoadCargo};
-use crate::data::structs::DataArray;
+use crate::algorithm::entities::{load::Stowage, DataArray};
 use serde::{Deserialize, Serialize};
 /// Контейнеры, груз без привязки к помещению, всегда твердый
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -26,6 +26,16 @@ pub struct Container {
     pub mass_shift_z: f64,
     /// Признак нахождения контейнера на палубе
     pub is_on_deck: bool,
+    /// Адрес ячейки: ряд по длине (bay)
+    pub bay: Option<i32>,
+    /// Адрес ячейки: ряд по ширине (row)
+    pub row: Option<i32>,
+    /// Адрес ячейки: ярус (tier)
+    pub tier: Option<i32>,
+    /// Код размера и типа по ISO 6346, например "22G1"
+    pub iso_code: Option<String>,
+    /// Подтвержденная масса брутто (VGM), т
+    pub vgm: Option<f64>,
 }
 //
 impl std::fmt::Display for Container {
@@ -33,7 +43,7 @@ impl std::fmt::Display for Container {
         write!(
             f,
             "Container(id:{} mass:{} general_category:{} 
-            bound_x:({}, {}) bound_y:({}, {}) bound_z:({}, {}) is_on_deck:{} )",
+            bound_x:({}, {}) bound_y:({}, {}) bound_z:({}, {}) is_on_deck:{} slot:({}, {}, {}) iso_code:{} vgm:{} )",
             self.id,
             self.mass,
             self.general_category,
@@ -44,6 +54,11 @@ impl std::fmt::Display for Container {
             self.bound_z1,
             self.bound_z2,
             self.is_on_deck,
+            self.bay.unwrap_or(0),
+            self.row.unwrap_or(0),
+            self.tier.unwrap_or(0),
+            self.iso_code.clone().unwrap_or_default(),
+            self.vgm.unwrap_or(0.),
         )
     }
 }
@@ -51,8 +66,12 @@ impl std::fmt::Display for Container {
 pub type ContainerArray = DataArray<Container>;
 //
 impl ContainerArray {
-    //
+    /// Преобразование в груз без привязки к помещению.
+    /// Площадь парусности палубных контейнеров берется из силуэта
+    /// штабелей и делится поровну между контейнерами штабеля,
+    /// так что контейнеры, закрытые соседними рядами, не учитываются дважды
     pub fn data(self) -> Vec<LoadCargo> {
+        let windage = Stowage::windage(&self.data);
         self.data
             .into_iter()
             .map(|v| {
@@ -62,20 +81,19 @@ impl ContainerArray {
                     vertical_area_shift_x,
                     vertical_area_shift_y,
                     vertical_area_shift_z,
-                ) = if v.is_on_deck {
-                    (
+                ) = match (v.is_on_deck, windage.get(&v.id)) {
+                    (true, Some((area, shift))) => (
                         Some((v.bound_x2 - v.bound_x1) * (v.bound_y2 - v.bound_y1)),
-                        Some((v.bound_x2 - v.bound_x1) * (v.bound_z2 - v.bound_z1)),
-                        Some((v.bound_x1 + v.bound_x2) / 2.),
-                        Some((v.bound_y1 + v.bound_y2) / 2.),
-                        Some((v.bound_z1 + v.bound_z2) / 2.),
-                    )
-                } else {
-                    (None, None, None, None, None)
+                        Some(*area),
+                        Some(shift.x()),
+                        Some(shift.y()),
+                        Some(shift.z()),
+                    ),
+                    _ => (None, None, None, None, None),
                 };
                 LoadCargo {
                     name: format!("container_{}", v.id),
-                    mass: Some(v.mass),
+                    mass: Some(v.vgm.unwrap_or(v.mass)),
                     general_category: v.general_category,
                     timber: false,
                     is_on_deck: v.is_on_deck,
@@ -99,3 +117,34 @@ impl ContainerArray {
             .collect()
     }
 }
+/// Допустимая масса штабеля контейнеров
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerStackLimit {
+    /// Ряд по длине (bay): нечетный для 20', четный для 40'
+    pub bay: i32,
+    /// Ряд по ширине (row)
+    pub row: i32,
+    /// Признак штабеля на палубе
+    pub is_on_deck: bool,
+    /// Допустимая масса штабеля, т
+    pub max_mass: f64,
+}
+//
+impl std::fmt::Display for ContainerStackLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ContainerStackLimit(bay:{} row:{} is_on_deck:{} max_mass:{} )",
+            self.bay, self.row, self.is_on_deck, self.max_mass,
+        )
+    }
+}
+/// Массив допустимых масс штабелей
+pub type ContainerStackLimitArray = DataArray<ContainerStackLimit>;
+//
+impl ContainerStackLimitArray {
+    //
+    pub fn data(self) -> Vec<ContainerStackLimit> {
+        self.data
+    }
+}
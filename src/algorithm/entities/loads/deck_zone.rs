@@ -0,0 +1,46 @@
+//! Промежуточные структуры для serde_json для парсинга
+//! допускаемых нагрузок на палубы и настил второго дна
+use crate::algorithm::entities::DataArray;
+use serde::{Deserialize, Serialize};
+/// Зона палубы или настила второго дна с допускаемой
+/// равномерно распределенной нагрузкой
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeckZoneData {
+    /// Имя зоны
+    pub name: String,
+    /// Признак зоны настила второго дна в трюме
+    pub is_tank_top: bool,
+    /// Диапазон по длинне, м
+    pub bound_x1: f64,
+    pub bound_x2: f64,
+    /// Диапазон по ширине, м
+    pub bound_y1: f64,
+    pub bound_y2: f64,
+    /// Допускаемая нагрузка, т/м^2
+    pub max_load: f64,
+}
+//
+impl std::fmt::Display for DeckZoneData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DeckZoneData(name:{} is_tank_top:{} bound_x:({}, {}) bound_y:({}, {}) max_load:{} )",
+            self.name,
+            self.is_tank_top,
+            self.bound_x1,
+            self.bound_x2,
+            self.bound_y1,
+            self.bound_y2,
+            self.max_load,
+        )
+    }
+}
+/// Массив зон палуб и настила второго дна
+pub type DeckZoneArray = DataArray<DeckZoneData>;
+//
+impl DeckZoneArray {
+    //
+    pub fn data(self) -> Vec<DeckZoneData> {
+        self.data
+    }
+}
@@ -0,0 +1,111 @@
+//! Промежуточные структуры для serde_json для парсинга данных
+//! трюмов и мест установки съемных зерновых перегородок
+use std::collections::HashMap;
+use crate::algorithm::entities::DataArray;
+use serde::{Deserialize, Serialize};
+/// Трюм, делимый съемными перегородками на части
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HoldData {
+    /// ИД трюма
+    pub id: i32,
+    /// Имя трюма
+    pub name: String,
+    /// Диапазон по длинне, м
+    pub bound_x1: f64,
+    pub bound_x2: f64,
+}
+//
+impl std::fmt::Display for HoldData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HoldData(id:{} name:{} bound_x:({}, {}) )",
+            self.id, self.name, self.bound_x1, self.bound_x2,
+        )
+    }
+}
+/// Массив трюмов
+pub type HoldArray = DataArray<HoldData>;
+//
+impl HoldArray {
+    //
+    pub fn data(self) -> Vec<HoldData> {
+        self.data
+    }
+}
+/// Строка кривой вместимости трюма: объем от кормовой
+/// границы трюма до сечения
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HoldCapacityData {
+    /// ИД трюма
+    pub hold_id: i32,
+    /// Отстояние сечения от миделя, м
+    pub x: f64,
+    /// Объем от кормовой границы трюма до сечения, м^3
+    pub volume: f64,
+}
+//
+impl std::fmt::Display for HoldCapacityData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HoldCapacityData(hold_id:{} x:{} volume:{} )",
+            self.hold_id, self.x, self.volume,
+        )
+    }
+}
+/// Кривые вместимости трюмов
+pub type HoldCapacityArray = DataArray<HoldCapacityData>;
+//
+impl HoldCapacityArray {
+    /// Преобразование и возвращает данные в виде мапы ИД трюма / вектор (x, объем)
+    pub fn data(self) -> HashMap<i32, Vec<(f64, f64)>> {
+        let mut map: HashMap<i32, Vec<(f64, f64)>> = HashMap::new();
+        self.data.into_iter().for_each(|v| {
+            map.entry(v.hold_id).or_default().push((v.x, v.volume));
+        });
+        map
+    }
+}
+/// Место установки съемной перегородки
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BulkheadPlaceData {
+    /// ИД перегородки
+    pub bulkhead_id: i32,
+    /// ИД трюма
+    pub hold_id: i32,
+    /// Номер места установки
+    pub position: i32,
+    /// Отстояние места установки от миделя, м
+    pub x: f64,
+}
+//
+impl std::fmt::Display for BulkheadPlaceData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BulkheadPlaceData(bulkhead_id:{} hold_id:{} position:{} x:{} )",
+            self.bulkhead_id, self.hold_id, self.position, self.x,
+        )
+    }
+}
+/// Места установки съемных перегородок
+pub type BulkheadPlaceArray = DataArray<BulkheadPlaceData>;
+//
+impl BulkheadPlaceArray {
+    //
+    pub fn data(self) -> Vec<BulkheadPlaceData> {
+        self.data
+    }
+}
+/// Трюмы судна с кривыми вместимости и местами
+/// установки съемных перегородок
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HoldTables {
+    /// Трюмы
+    pub holds: Vec<HoldData>,
+    /// Кривые вместимости по ИД трюма
+    pub capacity: HashMap<i32, Vec<(f64, f64)>>,
+    /// Места установки перегородок
+    pub places: Vec<BulkheadPlaceData>,
+}
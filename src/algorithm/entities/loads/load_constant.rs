@@ -1,5 +1,5 @@
 //! Промежуточные структуры для serde_json для парсинга данных груза
-use crate::data::structs::DataArray;
+use crate::algorithm::entities::DataArray;
 use serde::{Deserialize, Serialize};
 /// Тип груза, приходящегося на шпацию
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
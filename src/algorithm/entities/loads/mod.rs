@@ -2,12 +2,18 @@ pub mod bulkhead;
 pub mod cargo;
 pub mod compartment;
 pub mod container;
+pub mod deck_zone;
+pub mod hold;
 pub mod load_constant;
+pub mod sounding;
 pub use bulkhead::*;
 pub use cargo::*;
 pub use compartment::*;
 pub use container::*;
+pub use deck_zone::*;
+pub use hold::*;
 pub use load_constant::*;
+pub use sounding::*;
 
 use serde::{Deserialize, Serialize};
 /// Тип груза по назначению
@@ -0,0 +1,106 @@
+//! Промежуточные структуры для serde_json для парсинга
+//! калибровочных таблиц цистерн
+use std::collections::HashMap;
+use crate::algorithm::entities::DataArray;
+use serde::{Deserialize, Serialize};
+/// Строка калибровочной таблицы цистерны:
+/// параметры жидкости в зависимости от уровня заполнения
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SoundingData {
+    /// ИД помещения
+    pub space_id: i32,
+    /// Уровень заполнения (замер), м
+    pub sounding: f64,
+    /// Объем жидкости, м^3
+    pub volume: f64,
+    /// Отстояние центра величины, м
+    pub shift_x: f64,
+    pub shift_y: f64,
+    pub shift_z: f64,
+    /// Момент инерции площади свободной поверхности, м^4
+    pub m_f_s_y: f64,
+    pub m_f_s_x: f64,
+}
+//
+impl std::fmt::Display for SoundingData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SoundingData(space_id:{} sounding:{} volume:{} shift:({}, {}, {}) m_f_s_y:{} m_f_s_x:{})",
+            self.space_id,
+            self.sounding,
+            self.volume,
+            self.shift_x,
+            self.shift_y,
+            self.shift_z,
+            self.m_f_s_y,
+            self.m_f_s_x,
+        )
+    }
+}
+/// Калибровочные таблицы цистерн
+pub type SoundingArray = DataArray<SoundingData>;
+//
+impl SoundingArray {
+    /// Преобразование и возвращает данные в виде мапы ИД помещения / таблица
+    pub fn data(self) -> HashMap<i32, Vec<SoundingData>> {
+        let mut map: HashMap<i32, Vec<SoundingData>> = HashMap::new();
+        self.data.into_iter().for_each(|v| {
+            map.entry(v.space_id).or_default().push(v);
+        });
+        map
+    }
+}
+/// Поправки к замеру цистерны: вектор (дифферент или крен, вектор (замер, поправка))
+pub type SoundingCorrection = Vec<(f64, Vec<(f64, f64)>)>;
+/// Калибровочные таблицы цистерн судна с поправками на дифферент и крен
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SoundingTables {
+    /// Калибровочные таблицы по ИД помещения
+    pub table: HashMap<i32, Vec<SoundingData>>,
+    /// Поправки к замеру на дифферент по ИД помещения
+    pub trim_correction: HashMap<i32, SoundingCorrection>,
+    /// Поправки к замеру на крен по ИД помещения
+    pub heel_correction: HashMap<i32, SoundingCorrection>,
+}
+/// Строка таблицы поправок к замеру на дифферент или крен
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SoundingCorrectionData {
+    /// ИД помещения
+    pub space_id: i32,
+    /// Дифферент, м или крен, градус
+    pub key: f64,
+    /// Замер, м
+    pub sounding: f64,
+    /// Поправка к замеру, м
+    pub value: f64,
+}
+//
+impl std::fmt::Display for SoundingCorrectionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SoundingCorrectionData(space_id:{} key:{} sounding:{} value:{})",
+            self.space_id, self.key, self.sounding, self.value,
+        )
+    }
+}
+/// Таблицы поправок к замеру цистерн
+pub type SoundingCorrectionArray = DataArray<SoundingCorrectionData>;
+//
+impl SoundingCorrectionArray {
+    /// Преобразование и возвращает данные в виде мапы ИД помещения / поправки к замеру
+    pub fn data(mut self) -> HashMap<i32, SoundingCorrection> {
+        let mut map: HashMap<i32, SoundingCorrection> = HashMap::new();
+        self.data.sort_by(|a, b| a.key.total_cmp(&b.key));
+        self.data.into_iter().for_each(|v| {
+            let vec = map.entry(v.space_id).or_default();
+            if vec.last().is_none() || vec.last().unwrap().0 != v.key {
+                vec.push((v.key, vec![(v.sounding, v.value)]));
+            } else {
+                vec.last_mut().unwrap().1.push((v.sounding, v.value));
+            }
+        });
+        map
+    }
+}
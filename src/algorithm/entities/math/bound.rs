@@ -1,8 +1,10 @@
 //! Диапазон значений
 
+use serde::{Deserialize, Serialize};
+
 use crate::kernel::error::error::Error;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Bound {
     None,
     Full,
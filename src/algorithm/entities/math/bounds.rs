@@ -30,7 +30,6 @@ impl Bounds {
     /// * loa - L.O.A
     /// * middle_x - X midship from Fr0
     /// * n - Number of Parts
-    #[allow(unused)]
     pub fn from_n(loa: f64, middle_x: f64, n: usize) -> Result<Self, Error> {
         if loa <= 0. {
             return Err(Error::FromString(format!(
@@ -7,6 +7,7 @@ pub mod bound;
 pub mod pos_shift;
 pub mod liquid;
 pub mod bounds;
+pub mod root;
 
 #[allow(unused)]
 pub use vec::integral_sum::IntegralSum as IntegralSum;
@@ -21,4 +22,5 @@ pub use curve::*;
 pub use bound::*;
 pub use pos_shift::*;
 pub use liquid::*;
-pub use bounds::*;
\ No newline at end of file
+pub use bounds::*;
+pub use root::*;
\ No newline at end of file
@@ -0,0 +1,46 @@
+//! Поиск корня функции на отрезке
+use crate::kernel::error::error::Error;
+
+/// Точность уточнения корня по аргументу
+const TOLERANCE: f64 = 1.0e-9;
+/// Первый корень функции на отрезке: наименьший аргумент,
+/// при котором значение функции становится неотрицательным.
+/// Корень отделяется проходом по отрезку с шагом step
+/// и уточняется делением шага пополам.
+/// None - функция отрицательна на всем отрезке
+/// * f - Функция
+/// * start - Начало отрезка
+/// * end - Конец отрезка
+/// * step - Шаг отделения корня
+pub fn first_root(
+    f: impl Fn(f64) -> Result<f64, Error>,
+    start: f64,
+    end: f64,
+    step: f64,
+) -> Result<Option<f64>, Error> {
+    if step <= 0. {
+        return Err(Error::FromString(format!("first_root error: step {step} <= 0.")));
+    }
+    if f(start)? >= 0. {
+        return Ok(Some(start));
+    }
+    let steps = ((end - start) / step).ceil().max(0.) as usize;
+    let mut low = start;
+    for i in 1..=steps {
+        let high = (start + step * i as f64).min(end);
+        if f(high)? >= 0. {
+            let (mut low, mut high) = (low, high);
+            while high - low > TOLERANCE {
+                let middle = (low + high) / 2.;
+                if f(middle)? >= 0. {
+                    high = middle;
+                } else {
+                    low = middle;
+                }
+            }
+            return Ok(Some((low + high) / 2.));
+        }
+        low = high;
+    }
+    Ok(None)
+}
@@ -7,13 +7,21 @@ pub mod ship_data;
 pub mod data_array;
 pub mod icing_stab;
 pub mod icing_timber;
-// pub mod loads;
+pub mod bulk_heel;
+pub mod roll_period;
+pub mod second_generation;
+pub mod squat;
+pub mod visibility;
+pub mod wind_heel;
+pub mod loads;
+pub mod load;
 // pub mod stability;
 pub mod strength;
 pub mod serde_parser;
 
 pub mod math;
 pub mod area;
+pub mod girder;
 
 pub use data_array::*;
 pub use ship::*;
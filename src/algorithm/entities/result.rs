@@ -254,7 +254,7 @@ impl ParsedShipData {
             "ParsedShipData parse error: no forward_trim for ship id:{}",
             ship_id
         ))?;
-        let velocity = voyage.operational_speed*0.514444444; // knot to m/s
+        let velocity = voyage.operational_speed * KNOT;
         let deadweight = *ship_data.get("DWT").ok_or(format!(
             "ParsedShipData parse error: no deadweight for ship id:{}",
             ship_id
@@ -0,0 +1,88 @@
+//! Период собственных бортовых колебаний судна
+use serde::{Deserialize, Serialize};
+use crate::kernel::error::error::Error;
+
+/// Результат расчета периода качки или метацентрической
+/// высоты с исходными данными расчета
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollPeriodData {
+    /// Период бортовой качки, с
+    pub period: f64,
+    /// Исправленная метацентрическая высота, м
+    pub h: f64,
+    /// Инерционный коэффициент c
+    pub c: f64,
+    /// Ширина судна, м
+    pub width: f64,
+}
+//
+impl std::fmt::Display for RollPeriodData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RollPeriodData(period:{} h:{} c:{} width:{} )",
+            self.period, self.h, self.c, self.width,
+        )
+    }
+}
+/// Период собственных бортовых колебаний T = 2cB/√h, п. 2.1.5.1 Правил РС.
+/// Позволяет оценить метацентрическую высоту по периоду,
+/// замеренному экипажем
+pub struct RollPeriod {
+    /// Ширина судна, м
+    width: f64,
+    /// Инерционный коэффициент c
+    c: f64,
+}
+//
+impl RollPeriod {
+    /// Основной конструктор
+    /// * width - Ширина судна, м
+    /// * c - Инерционный коэффициент
+    pub fn new(width: f64, c: f64) -> Result<Self, Error> {
+        if width <= 0. {
+            return Err(Error::FromString(format!("RollPeriod new error: width {width} <= 0.")));
+        }
+        if c <= 0. {
+            return Err(Error::FromString(format!("RollPeriod new error: c {c} <= 0.")));
+        }
+        Ok(Self { width, c })
+    }
+    /// Конструктор с инерционным коэффициентом
+    /// c = 0.373 + 0.023B/d - 0.043L_wl/100
+    /// * width - Ширина судна, м
+    /// * draught - Средняя осадка, м
+    /// * length_wl - Длина судна по ватерлинии, м
+    pub fn from_hull(width: f64, draught: f64, length_wl: f64) -> Result<Self, Error> {
+        if draught <= 0. {
+            return Err(Error::FromString(format!("RollPeriod from_hull error: draught {draught} <= 0.")));
+        }
+        Self::new(width, 0.373 + 0.023 * width / draught - 0.043 * length_wl / 100.)
+    }
+    /// Период качки по метацентрической высоте
+    /// * h - Исправленная метацентрическая высота, м
+    pub fn period(&self, h: f64) -> Result<RollPeriodData, Error> {
+        if h <= 0. {
+            return Err(Error::FromString(format!("RollPeriod period error: h {h} <= 0.")));
+        }
+        Ok(RollPeriodData {
+            period: 2. * self.c * self.width / h.sqrt(),
+            h,
+            c: self.c,
+            width: self.width,
+        })
+    }
+    /// Метацентрическая высота по замеренному периоду качки
+    /// * period - Замеренный период качки, с
+    pub fn h(&self, period: f64) -> Result<RollPeriodData, Error> {
+        if period <= 0. {
+            return Err(Error::FromString(format!("RollPeriod h error: period {period} <= 0.")));
+        }
+        Ok(RollPeriodData {
+            period,
+            h: (2. * self.c * self.width / period).powi(2),
+            c: self.c,
+            width: self.width,
+        })
+    }
+}
@@ -0,0 +1,123 @@
+//! Результат проверки уязвимости по критериям второго поколения
+use crate::{
+    algorithm::entities::{
+        math::{Curve, ICurve},
+        stability::CriterionResult,
+    },
+    kernel::error::error::Error,
+};
+
+/// Критерий остойчивости второго поколения
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Criterion {
+    /// Параметрический резонанс
+    ParametricRoll,
+    /// Потеря остойчивости на вершине волны
+    PureLoss,
+    /// Остойчивость судна, потерявшего ход
+    DeadShip,
+}
+//
+impl Criterion {
+    /// Идентификатор критерия в таблице критериев, см. [CriterionResult]
+    pub fn id(&self) -> i32 {
+        match self {
+            Criterion::ParametricRoll => 101,
+            Criterion::PureLoss => 102,
+            Criterion::DeadShip => 103,
+        }
+    }
+    /// Отношение значения к нормативному. Для параметрического
+    /// резонанса значение должно быть меньше нормативного,
+    /// для остальных критериев - не меньше
+    pub fn relation(&self) -> &'static str {
+        match self {
+            Criterion::ParametricRoll => "<",
+            Criterion::PureLoss | Criterion::DeadShip => ">=",
+        }
+    }
+    /// Результат проверки уязвимости, отдельная запись
+    /// наряду с классическими критериями
+    /// * value - Значение критерия
+    /// * limit - Нормативное значение критерия
+    pub fn result(&self, value: f64, limit: f64) -> CriterionResult {
+        CriterionResult {
+            id: self.id(),
+            value,
+            limit,
+            relation: self.relation().to_owned(),
+        }
+    }
+}
+//
+impl std::fmt::Display for Criterion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Criterion::ParametricRoll => "ParametricRoll",
+                Criterion::PureLoss => "PureLoss",
+                Criterion::DeadShip => "DeadShip",
+            }
+        )
+    }
+}
+/// Ватерлиния судна на волне: момент инерции площади
+/// ватерлинии по кривым метацентрического радиуса и объема
+pub(super) struct Waterplane {
+    /// Поперечный метацентрический радиус от осадки
+    rad_trans: Curve<f64>,
+    /// Объемное водоизмещение от осадки
+    volume: Curve<f64>,
+}
+//
+impl Waterplane {
+    /// Основной конструктор
+    /// * rad_trans - Поперечный метацентрический радиус: (осадка, радиус)
+    /// * volume - Объемное водоизмещение: (осадка, объем)
+    pub fn new(rad_trans: &[(f64, f64)], volume: &[(f64, f64)]) -> Result<Self, Error> {
+        Ok(Self {
+            rad_trans: Curve::new_linear(rad_trans)
+                .map_err(|e| Error::FromString(format!("Waterplane new rad_trans error: {e}")))?,
+            volume: Curve::new_linear(volume)
+                .map_err(|e| Error::FromString(format!("Waterplane new volume error: {e}")))?,
+        })
+    }
+    /// Объемное водоизмещение при осадке, м^3
+    pub fn volume(&self, draught: f64) -> Result<f64, Error> {
+        self.volume.value(draught)
+    }
+    /// Момент инерции площади ватерлинии при осадке, м^4
+    pub fn inertia(&self, draught: f64) -> Result<f64, Error> {
+        Ok(self.rad_trans.value(draught)? * self.volume.value(draught)?)
+    }
+    /// Изменение осадки на волне ΔD_L = min(d - 0.25d_full, L·S_W/2), м
+    /// * length - Длина судна, м
+    /// * draught - Осадка в рассматриваемом случае нагрузки, м
+    /// * draught_full - Осадка по летнюю грузовую марку, м
+    /// * s_w - Крутизна волны
+    pub fn delta_draught(length: f64, draught: f64, draught_full: f64, s_w: f64) -> Result<f64, Error> {
+        let delta = (draught - 0.25 * draught_full).min(length * s_w / 2.);
+        if delta <= 0. {
+            return Err(Error::FromString(format!(
+                "Waterplane delta_draught error: draught {draught} <= 0.25 * draught_full {draught_full}"
+            )));
+        }
+        Ok(delta)
+    }
+    /// Увеличение осадки на вершине волны ΔD_H = min(D - d, L·S_W/2), м
+    /// * length - Длина судна, м
+    /// * draught - Осадка в рассматриваемом случае нагрузки, м
+    /// * moulded_depth - Теоретическая высота борта, м
+    /// * s_w - Крутизна волны
+    pub fn delta_draught_crest(length: f64, draught: f64, moulded_depth: f64, s_w: f64) -> Result<f64, Error> {
+        let delta = (moulded_depth - draught).min(length * s_w / 2.);
+        if delta <= 0. {
+            return Err(Error::FromString(format!(
+                "Waterplane delta_draught_crest error: draught {draught} >= moulded_depth {moulded_depth}"
+            )));
+        }
+        Ok(delta)
+    }
+}
@@ -0,0 +1,159 @@
+//! Остойчивость судна, потерявшего ход, первый уровень уязвимости
+use super::Criterion;
+use crate::{
+    algorithm::entities::{
+        math::{first_root, Curve, ICurve},
+        stability::CriterionResult,
+        wind_heel::WindHeel,
+    },
+    kernel::error::error::Error,
+};
+
+/// Остойчивость судна, потерявшего ход, первый уровень уязвимости.
+/// Критерий погоды b/a >= 1 с давлением ветра 504 Па и
+/// множителем крутизны волны s по уточненной таблице MSC.1/Circ.1627.
+/// Кренящее плечо при порыве l_w2 = 1.5·l_w1 не зависит от угла крена
+pub struct DeadShip {
+    /// Диаграмма статической остойчивости
+    gz: Curve<f64>,
+    /// Наибольший угол крена диаграммы, градус
+    max_angle: f64,
+    /// Кренящее плечо от постоянного ветра l_w1, м
+    lever: f64,
+    /// Амплитуда качки θ1, градус
+    amplitude: f64,
+    /// Угол заливания, градус
+    flooding_angle: f64,
+}
+//
+impl DeadShip {
+    /// Давление ветра, Па
+    const PRESSURE: f64 = 504.;
+    /// Наибольший учитываемый угол крена θ2, градус
+    const ANGLE_LIMIT: f64 = 50.;
+    /// Шаг интегрирования по углу крена, градус
+    const STEP: f64 = 0.1;
+    /// Основной конструктор
+    /// * gz - Диаграмма статической остойчивости: (угол крена, градус; плечо, м)
+    /// * wind - Кренящее плечо от давления ветра
+    /// * amplitude - Амплитуда качки θ1, градус
+    /// * flooding_angle - Угол заливания, градус
+    pub fn new(
+        gz: &[(f64, f64)],
+        wind: &WindHeel,
+        amplitude: f64,
+        flooding_angle: f64,
+    ) -> Result<Self, Error> {
+        let max_angle = gz.iter().map(|(angle, _)| *angle).fold(f64::MIN, f64::max);
+        Ok(Self {
+            gz: Curve::new_linear(gz)
+                .map_err(|e| Error::FromString(format!("DeadShip new gz error: {e}")))?,
+            max_angle,
+            lever: wind.lever(Self::PRESSURE, 0.),
+            amplitude,
+            flooding_angle,
+        })
+    }
+    /// Множитель крутизны волны s по периоду качки
+    /// * period - Период бортовой качки, с
+    pub fn steepness(period: f64) -> Result<f64, Error> {
+        Curve::new_linear(&[
+            (6., 0.100),
+            (7., 0.0981),
+            (8., 0.0925),
+            (12., 0.0653),
+            (14., 0.0553),
+            (16., 0.0469),
+            (18., 0.0400),
+            (20., 0.0343),
+        ])?
+        .value(period)
+    }
+    /// Амплитуда качки θ1 = 109·k·X1·X2·√(r·s), градус
+    /// * k - Коэффициент, учитывающий скуловые кили
+    /// * x1 - Безразмерный множитель X1
+    /// * x2 - Безразмерный множитель X2
+    /// * kg - Аппликата центра тяжести судна, м
+    /// * draught - Средняя осадка, м
+    /// * period - Период бортовой качки, с
+    pub fn amplitude(
+        k: f64,
+        x1: f64,
+        x2: f64,
+        kg: f64,
+        draught: f64,
+        period: f64,
+    ) -> Result<f64, Error> {
+        if draught <= 0. {
+            return Err(Error::FromString(format!(
+                "DeadShip amplitude error: draught {draught} <= 0."
+            )));
+        }
+        let r = 0.73 + 0.6 * (kg - draught) / draught;
+        if r <= 0. {
+            return Err(Error::FromString(format!("DeadShip amplitude error: r {r} <= 0.")));
+        }
+        Ok(109. * k * x1 * x2 * (r * Self::steepness(period)?).sqrt())
+    }
+    /// Плечо диаграммы, для отрицательных углов крена
+    /// диаграмма симметрична относительно начала координат
+    fn gz(&self, angle: f64) -> Result<f64, Error> {
+        if angle < 0. {
+            Ok(-self.gz.value(-angle)?)
+        } else {
+            self.gz.value(angle)
+        }
+    }
+    /// Первое пересечение диаграммы с постоянным плечом начиная с угла, градус
+    fn intersection(&self, start: f64, lever: f64) -> Result<Option<f64>, Error> {
+        first_root(|angle| Ok(self.gz(angle)? - lever), start, self.max_angle, Self::STEP)
+    }
+    /// Площадь между диаграммой и кренящим плечом, м·рад
+    fn area(&self, start: f64, end: f64, lever: f64) -> Result<f64, Error> {
+        let steps = ((end - start) / Self::STEP).ceil().max(1.) as usize;
+        let step = (end - start) / steps as f64;
+        let mut area = 0.;
+        for i in 0..steps {
+            let (a, b) = (start + step * i as f64, start + step * (i + 1) as f64);
+            area += (self.gz(a)? + self.gz(b)? - 2. * lever) / 2. * step;
+        }
+        Ok(area.to_radians())
+    }
+    /// Площади a и b критерия погоды, м·рад.
+    /// None - диаграмма не уравновешивает кренящее плечо
+    pub fn areas(&self) -> Result<Option<(f64, f64)>, Error> {
+        let gust = 1.5 * self.lever;
+        let Some(theta_0) = self.intersection(0., self.lever)? else {
+            return Ok(None);
+        };
+        let Some(theta_c) = self.intersection(0., gust)? else {
+            return Ok(None);
+        };
+        // второе пересечение, где диаграмма опускается ниже плеча
+        let mut theta_2 = Self::ANGLE_LIMIT.min(self.flooding_angle).min(self.max_angle);
+        let mut angle = theta_c;
+        while angle + Self::STEP <= theta_2 {
+            if self.gz(angle + Self::STEP)? < gust {
+                theta_2 = angle + Self::STEP;
+                break;
+            }
+            angle += Self::STEP;
+        }
+        let a = -self.area(theta_0 - self.amplitude, theta_c, gust)?;
+        let b = if theta_2 > theta_c {
+            self.area(theta_c, theta_2, gust)?
+        } else {
+            0.
+        };
+        Ok(Some((a, b)))
+    }
+    /// Проверка уязвимости: b/a >= 1
+    pub fn check(&self) -> Result<CriterionResult, Error> {
+        let value = match self.areas()? {
+            Some((a, b)) if a > 0. => b / a,
+            Some(_) => f64::INFINITY,
+            None => 0.,
+        };
+        Ok(Criterion::DeadShip.result(value, 1.))
+    }
+}
@@ -0,0 +1,11 @@
+//! Критерии остойчивости второго поколения ИМО,
+//! первый уровень уязвимости (MSC.1/Circ.1627)
+mod criterion;
+mod dead_ship;
+mod parametric_roll;
+mod pure_loss;
+
+pub use criterion::*;
+pub use dead_ship::*;
+pub use parametric_roll::*;
+pub use pure_loss::*;
@@ -0,0 +1,106 @@
+//! Параметрический резонанс, первый уровень уязвимости
+use super::{Criterion, Waterplane};
+use crate::{algorithm::entities::stability::CriterionResult, kernel::error::error::Error};
+
+/// Параметрический резонанс, первый уровень уязвимости.
+/// Изменение метацентрической высоты на волне оценивается
+/// по моментам инерции ватерлинии при осадках на вершине
+/// и подошве волны с крутизной 0.0167
+pub struct ParametricRoll {
+    /// Длина судна, м
+    length: f64,
+    /// Ширина судна, м
+    breadth: f64,
+    /// Осадка в рассматриваемом случае нагрузки, м
+    draught: f64,
+    /// Осадка по летнюю грузовую марку, м
+    draught_full: f64,
+    /// Теоретическая высота борта, м
+    moulded_depth: f64,
+    /// Коэффициент полноты мидель-шпангоута
+    c_m: f64,
+    /// Суммарная площадь скуловых килей, м^2
+    keel_area: f64,
+    /// Объемное водоизмещение, м^3
+    volume: f64,
+    /// Ватерлиния судна на волне
+    waterplane: Waterplane,
+}
+//
+impl ParametricRoll {
+    /// Крутизна волны
+    const S_W: f64 = 0.0167;
+    /// Основной конструктор
+    /// * length - Длина судна, м
+    /// * breadth - Ширина судна, м
+    /// * draught - Осадка в рассматриваемом случае нагрузки, м
+    /// * draught_full - Осадка по летнюю грузовую марку, м
+    /// * moulded_depth - Теоретическая высота борта, м
+    /// * c_m - Коэффициент полноты мидель-шпангоута
+    /// * keel_area - Суммарная площадь скуловых килей, м^2
+    /// * rad_trans - Поперечный метацентрический радиус: (осадка, радиус)
+    /// * volume_curve - Объемное водоизмещение: (осадка, объем)
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        length: f64,
+        breadth: f64,
+        draught: f64,
+        draught_full: f64,
+        moulded_depth: f64,
+        c_m: f64,
+        keel_area: f64,
+        rad_trans: &[(f64, f64)],
+        volume_curve: &[(f64, f64)],
+    ) -> Result<Self, Error> {
+        if length <= 0. || breadth <= 0. || draught <= 0. || draught_full <= 0. || moulded_depth <= 0. {
+            return Err(Error::FromString(format!(
+                "ParametricRoll new error: nonpositive dimensions length:{length} breadth:{breadth} draught:{draught} draught_full:{draught_full} moulded_depth:{moulded_depth}"
+            )));
+        }
+        let waterplane = Waterplane::new(rad_trans, volume_curve)?;
+        Ok(Self {
+            length,
+            breadth,
+            draught,
+            draught_full,
+            moulded_depth,
+            c_m,
+            keel_area,
+            volume: waterplane.volume(draught)?,
+            waterplane,
+        })
+    }
+    /// Изменение метацентрической высоты на волне ΔGM1, м.
+    /// Осадка на вершине волны увеличивается на ΔD_H, на подошве
+    /// уменьшается на ΔD_L
+    pub fn delta_gm(&self) -> Result<f64, Error> {
+        let delta_d_h =
+            Waterplane::delta_draught_crest(self.length, self.draught, self.moulded_depth, Self::S_W)?;
+        let delta_d_l =
+            Waterplane::delta_draught(self.length, self.draught, self.draught_full, Self::S_W)?;
+        let i_h = self.waterplane.inertia(self.draught + delta_d_h)?;
+        let i_l = self.waterplane.inertia(self.draught - delta_d_l)?;
+        Ok((i_h - i_l) / (2. * self.volume))
+    }
+    /// Нормативное значение R_PR в зависимости от
+    /// полноты мидель-шпангоута и площади скуловых килей
+    pub fn limit(&self) -> f64 {
+        let keel = 100. * self.keel_area / (self.length * self.breadth);
+        let limit = if self.c_m > 0.96 {
+            0.17 + 0.425 * keel
+        } else if self.c_m > 0.94 {
+            0.17 + (10.625 * self.c_m - 9.775) * keel
+        } else {
+            0.17 + 0.2125 * keel
+        };
+        limit.min(0.6)
+    }
+    /// Проверка уязвимости: ΔGM1/GM < R_PR
+    /// * gm - Исправленная метацентрическая высота на тихой воде, м
+    pub fn check(&self, gm: f64) -> Result<CriterionResult, Error> {
+        if gm <= 0. {
+            return Err(Error::FromString(format!("ParametricRoll check error: gm {gm} <= 0.")));
+        }
+        Ok(Criterion::ParametricRoll.result(self.delta_gm()? / gm, self.limit()))
+    }
+}
@@ -0,0 +1,79 @@
+//! Потеря остойчивости на вершине волны, первый уровень уязвимости
+use super::{Criterion, Waterplane};
+use crate::{algorithm::entities::{girder::G, stability::CriterionResult}, kernel::error::error::Error};
+
+/// Потеря остойчивости на вершине волны, первый уровень уязвимости.
+/// Минимальная метацентрическая высота GM_min = KB + I_L/V - KG
+/// определяется по моменту инерции ватерлинии при осадке
+/// на вершине волны с крутизной 0.0334
+pub struct PureLoss {
+    /// Длина судна, м
+    length: f64,
+    /// Осадка в рассматриваемом случае нагрузки, м
+    draught: f64,
+    /// Осадка по летнюю грузовую марку, м
+    draught_full: f64,
+    /// Аппликата центра величины, м
+    kb: f64,
+    /// Объемное водоизмещение, м^3
+    volume: f64,
+    /// Ватерлиния судна на волне
+    waterplane: Waterplane,
+}
+//
+impl PureLoss {
+    /// Крутизна волны
+    const S_W: f64 = 0.0334;
+    /// Нормативное значение R_PL1, м
+    const R_PL1: f64 = 0.05;
+    /// Число Фруда, с которого проверяется критерий
+    const FROUDE_MIN: f64 = 0.24;
+    /// Основной конструктор
+    /// * length - Длина судна, м
+    /// * draught - Осадка в рассматриваемом случае нагрузки, м
+    /// * draught_full - Осадка по летнюю грузовую марку, м
+    /// * kb - Аппликата центра величины в рассматриваемом случае нагрузки, м
+    /// * rad_trans - Поперечный метацентрический радиус: (осадка, радиус)
+    /// * volume_curve - Объемное водоизмещение: (осадка, объем)
+    pub fn new(
+        length: f64,
+        draught: f64,
+        draught_full: f64,
+        kb: f64,
+        rad_trans: &[(f64, f64)],
+        volume_curve: &[(f64, f64)],
+    ) -> Result<Self, Error> {
+        if length <= 0. || draught <= 0. || draught_full <= 0. {
+            return Err(Error::FromString(format!(
+                "PureLoss new error: nonpositive dimensions length:{length} draught:{draught} draught_full:{draught_full}"
+            )));
+        }
+        let waterplane = Waterplane::new(rad_trans, volume_curve)?;
+        Ok(Self {
+            length,
+            draught,
+            draught_full,
+            kb,
+            volume: waterplane.volume(draught)?,
+            waterplane,
+        })
+    }
+    /// Критерий применяется при числе Фруда не менее 0.24
+    /// * speed - Эксплуатационная скорость, м/с
+    pub fn is_applicable(&self, speed: f64) -> bool {
+        speed / (G * self.length).sqrt() >= Self::FROUDE_MIN
+    }
+    /// Минимальная метацентрическая высота на вершине волны, м
+    /// * kg - Аппликата центра тяжести судна, м
+    pub fn gm_min(&self, kg: f64) -> Result<f64, Error> {
+        let delta_d =
+            Waterplane::delta_draught(self.length, self.draught, self.draught_full, Self::S_W)?;
+        let i_l = self.waterplane.inertia(self.draught - delta_d)?;
+        Ok(self.kb + i_l / self.volume - kg)
+    }
+    /// Проверка уязвимости: GM_min >= R_PL1
+    /// * kg - Аппликата центра тяжести судна, м
+    pub fn check(&self, kg: f64) -> Result<CriterionResult, Error> {
+        Ok(Criterion::PureLoss.result(self.gm_min(kg)?, Self::R_PL1))
+    }
+}
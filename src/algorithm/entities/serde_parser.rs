@@ -3,7 +3,7 @@
 use crate::kernel::error::error::Error;
 
 /// Интерфес для парсинга json от  АПИ сервера,  
-/// проверяет наличие строки с ошибкой
+/// проверяет наличие строки с ошибкой, пришедшей от БД
 pub trait IFromJson {
     /// Строка с ошибкой если она пришла с сервера
     fn error(&self) -> Option<&String>;
@@ -12,7 +12,7 @@ pub trait IFromJson {
         let res: Self = serde_json::from_slice(src)?;
         if let Some(error) = res.error() {
             if !error.is_empty() {
-                return Err(Error::ApiRequest(error.to_string()));
+                return Err(Error::Database(error.to_string()));
             }
         }
         Ok(res)
@@ -47,3 +47,10 @@ impl Ship {
         })
     }
 }
+/// Ревизия данных судна, увеличивается при каждом изменении данных
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ShipRevision {
+    pub revision: usize,
+}
+//
+pub type ShipRevisionArray = DataArray<ShipRevision>;
@@ -0,0 +1,180 @@
+//! Проседание судна на мелководье и запас воды под килем
+use serde::{Deserialize, Serialize};
+use crate::{algorithm::entities::{girder::G, KNOT}, kernel::error::error::Error};
+
+/// Результат расчета проседания и запаса воды под килем
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SquatData {
+    /// Проседание, м
+    pub squat: f64,
+    /// Динамическая осадка, м
+    pub dynamic_draught: f64,
+    /// Запас воды под килем, м
+    pub ukc: f64,
+    /// Требуемый запас воды под килем, м. None - не задан
+    pub ukc_required: Option<f64>,
+    /// Наибольшая скорость при требуемом запасе воды под килем, узлы.
+    /// None - запас не задан или не обеспечивается без хода
+    pub max_speed: Option<f64>,
+}
+//
+impl SquatData {
+    /// Признак выполнения требования к запасу воды под килем
+    pub fn is_ok(&self) -> bool {
+        self.ukc_required.is_none_or(|ukc_required| self.ukc >= ukc_required)
+    }
+}
+
+/// Проседание судна (squat) на мелководье и в канале,
+/// динамическая осадка и запас воды под килем (UKC)
+pub struct Squat {
+    /// Длина судна между перпендикулярами, м
+    length: f64,
+    /// Ширина судна, м
+    breadth: f64,
+    /// Максимальная осадка, м
+    draught: f64,
+    /// Коэффициент общей полноты
+    c_b: f64,
+    /// Глубина воды, м
+    depth: f64,
+    /// Ширина канала, м. None - открытое мелководье
+    channel_width: Option<f64>,
+}
+//
+impl Squat {
+    /// Основной конструктор
+    /// * length - Длина судна между перпендикулярами, м
+    /// * breadth - Ширина судна, м
+    /// * draught - Максимальная осадка, м
+    /// * c_b - Коэффициент общей полноты
+    /// * depth - Глубина воды, м
+    /// * channel_width - Ширина канала, м. None - открытое мелководье
+    pub fn new(
+        length: f64,
+        breadth: f64,
+        draught: f64,
+        c_b: f64,
+        depth: f64,
+        channel_width: Option<f64>,
+    ) -> Result<Self, Error> {
+        if length <= 0. || breadth <= 0. || draught <= 0. {
+            return Err(Error::FromString(format!(
+                "Squat new error: nonpositive dimensions length:{length} breadth:{breadth} draught:{draught}"
+            )));
+        }
+        if c_b <= 0. || c_b > 1. {
+            return Err(Error::FromString(format!("Squat new error: c_b {c_b} out of range (0, 1]")));
+        }
+        if depth <= draught {
+            return Err(Error::FromString(format!(
+                "Squat new error: depth {depth} <= draught {draught}"
+            )));
+        }
+        if let Some(channel_width) = channel_width {
+            if channel_width < breadth {
+                return Err(Error::FromString(format!(
+                    "Squat new error: channel_width {channel_width} < breadth {breadth}"
+                )));
+            }
+        }
+        Ok(Self {
+            length,
+            breadth,
+            draught,
+            c_b,
+            depth,
+            channel_width,
+        })
+    }
+    /// Проседание по формуле Барраса, м
+    /// * velocity - Скорость судна, м/с
+    pub fn barrass(&self, velocity: f64) -> f64 {
+        // коэффициент стесненности фарватера, от 1 для открытой воды до 2
+        let k = match self.channel_width {
+            Some(channel_width) => {
+                let blockage = self.breadth * self.draught / (channel_width * self.depth);
+                (5.74 * blockage.powf(0.76)).clamp(1., 2.)
+            }
+            None => 1.,
+        };
+        k * self.c_b * (velocity / KNOT).powi(2) / 100.
+    }
+    /// Проседание по формуле ICORELS, м
+    /// * velocity - Скорость судна, м/с
+    pub fn icorels(&self, velocity: f64) -> Result<f64, Error> {
+        let f_nh = self.froude_depth(velocity);
+        if f_nh >= 1. {
+            return Err(Error::FromString(format!(
+                "Squat icorels error: depth Froude number {f_nh} >= 1"
+            )));
+        }
+        let volume = self.c_b * self.length * self.breadth * self.draught;
+        Ok(2.4 * volume / self.length.powi(2) * f_nh.powi(2) / (1. - f_nh.powi(2)).sqrt())
+    }
+    /// Число Фруда по глубине
+    /// * velocity - Скорость судна, м/с
+    fn froude_depth(&self, velocity: f64) -> f64 {
+        velocity / (G * self.depth).sqrt()
+    }
+    /// Расчетное проседание, наибольшее по двум формулам, м
+    /// * velocity - Скорость судна, м/с
+    pub fn value(&self, velocity: f64) -> Result<f64, Error> {
+        Ok(self.barrass(velocity).max(self.icorels(velocity)?))
+    }
+    /// Динамическая осадка с учетом проседания, м
+    /// * velocity - Скорость судна, м/с
+    pub fn dynamic_draught(&self, velocity: f64) -> Result<f64, Error> {
+        Ok(self.draught + self.value(velocity)?)
+    }
+    /// Запас воды под килем с учетом проседания, м
+    /// * velocity - Скорость судна, м/с
+    pub fn ukc(&self, velocity: f64) -> Result<f64, Error> {
+        Ok(self.depth - self.dynamic_draught(velocity)?)
+    }
+    /// Наибольшая скорость, при которой обеспечивается
+    /// требуемый запас воды под килем, м/с
+    /// * ukc_required - Требуемый запас воды под килем, м
+    pub fn max_speed(&self, ukc_required: f64) -> Result<f64, Error> {
+        if self.ukc(0.)? < ukc_required {
+            return Err(Error::FromString(format!(
+                "Squat max_speed error: static ukc {} < ukc_required {ukc_required}",
+                self.ukc(0.)?
+            )));
+        }
+        // скорость ограничена областью применимости формулы ICORELS
+        let mut max = 0.99 * (G * self.depth).sqrt();
+        if self.ukc(max)? >= ukc_required {
+            return Ok(max);
+        }
+        let mut min = 0.;
+        for _ in 0..100 {
+            let velocity = (min + max) / 2.;
+            if self.ukc(velocity)? >= ukc_required {
+                min = velocity;
+            } else {
+                max = velocity;
+            }
+            if max - min < 1.0e-6 {
+                break;
+            }
+        }
+        Ok(min)
+    }
+    /// Проседание, динамическая осадка и запас воды под килем
+    /// * velocity - Скорость судна, м/с
+    /// * ukc_required - Требуемый запас воды под килем, м
+    pub fn data(&self, velocity: f64, ukc_required: Option<f64>) -> Result<SquatData, Error> {
+        let max_speed = match ukc_required {
+            Some(ukc_required) if self.ukc(0.)? >= ukc_required => Some(self.max_speed(ukc_required)? / KNOT),
+            _ => None,
+        };
+        Ok(SquatData {
+            squat: self.value(velocity)?,
+            dynamic_draught: self.dynamic_draught(velocity)?,
+            ukc: self.ukc(velocity)?,
+            ukc_required,
+            max_speed,
+        })
+    }
+}
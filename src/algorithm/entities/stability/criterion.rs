@@ -30,3 +30,39 @@ impl CriterionRelationArray {
             .collect()
     }
 }
+/// Результат проверки критерия с идентификатором критерия,
+/// отдельная запись в отчете по критериям
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CriterionResult {
+    /// Идентификатор критерия, см. [CriterionRelation]
+    pub id: i32,
+    /// Значение критерия
+    pub value: f64,
+    /// Нормативное значение критерия
+    pub limit: f64,
+    /// Отношение значения к нормативному: ">=", ">", "<=", "<"
+    pub relation: String,
+}
+//
+impl CriterionResult {
+    /// Признак выполнения критерия, неизвестное отношение - не выполнен
+    pub fn is_ok(&self) -> bool {
+        match self.relation.as_str() {
+            ">=" => self.value >= self.limit,
+            ">" => self.value > self.limit,
+            "<=" => self.value <= self.limit,
+            "<" => self.value < self.limit,
+            _ => false,
+        }
+    }
+}
+//
+impl std::fmt::Display for CriterionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CriterionResult(id:{} value:{} {} limit:{} )",
+            self.id, self.value, self.relation, self.limit,
+        )
+    }
+}
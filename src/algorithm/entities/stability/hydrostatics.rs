@@ -0,0 +1,163 @@
+//! Гидростатические таблицы и диаграмма статической остойчивости
+//! судна на ровный киль
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::math::{Curve, Curve2D, ICurve, ICurve2D},
+    kernel::error::error::Error,
+};
+/// Гидростатические таблицы судна на ровный киль. Из таблиц,
+/// заданных по дифференту, берутся данные при дифференте,
+/// ближайшем к нулю
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HydrostaticTables {
+    /// Средняя осадка: (объемное водоизмещение, м^3; осадка, м)
+    pub mean_draught: Vec<(f64, f64)>,
+    /// Поперечный метацентрический радиус: (объемное водоизмещение, м^3; радиус, м)
+    pub rad_trans: Vec<(f64, f64)>,
+    /// Аппликата центра величины: (объемное водоизмещение, м^3; аппликата, м)
+    pub center_z: Vec<(f64, f64)>,
+    /// Плечи остойчивости формы: (осадка, м; [(крен, градус; плечо, м)])
+    pub pantocaren: Vec<(f64, Vec<(f64, f64)>)>,
+    /// Угол входа кромки верхней палубы в воду: (осадка, м; угол, градус)
+    pub entry_angle: Option<Vec<(f64, f64)>>,
+    /// Длина по ватерлинии: (осадка, м; длина, м)
+    pub waterline_length: Option<Vec<(f64, f64)>>,
+    /// Инерционный коэффициент для периода качки: (осадка, м; коэффициент c)
+    pub inertia_coefficient: Option<Vec<(f64, f64)>>,
+    /// Угол заливания отверстий: (осадка, м; угол, градус)
+    pub flooding_angle: Option<Vec<(f64, f64)>>,
+    /// Безразмерный множитель X1 для амплитуды качки: (B/d; X1)
+    pub multipler_x1: Option<Vec<(f64, f64)>>,
+    /// Безразмерный множитель X2 для амплитуды качки: (коэффициент общей полноты; X2)
+    pub multipler_x2: Option<Vec<(f64, f64)>>,
+    /// Коэффициент k для судов со скуловыми килями: (A_k·100/(L·B), %; k)
+    pub coefficient_k: Option<Vec<(f64, f64)>>,
+}
+//
+impl HydrostaticTables {
+    /// Данные таблицы при дифференте, ближайшем к нулю
+    /// * data - Таблица по дифференту: (дифферент, данные)
+    pub fn even_keel<T>(data: Vec<(f64, Vec<T>)>) -> Option<Vec<T>> {
+        data.into_iter()
+            .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+            .map(|(_, v)| v)
+    }
+    /// Кривая таблицы
+    /// * name - Имя таблицы для сообщения об ошибке
+    /// * values - Значения таблицы
+    fn curve(name: &str, values: &[(f64, f64)]) -> Result<Curve<f64>, Error> {
+        Curve::new_linear(values)
+            .map_err(|e| Error::FromString(format!("HydrostaticTables {name} error: {e}")))
+    }
+    /// Средняя осадка, м
+    /// * volume - Объемное водоизмещение, м^3
+    pub fn draught(&self, volume: f64) -> Result<f64, Error> {
+        Self::curve("mean_draught", &self.mean_draught)?.value(volume)
+    }
+    /// Аппликата поперечного метацентра KM = z_c + r, м
+    /// * volume - Объемное водоизмещение, м^3
+    pub fn km(&self, volume: f64) -> Result<f64, Error> {
+        Ok(Self::curve("center_z", &self.center_z)?.value(volume)?
+            + Self::curve("rad_trans", &self.rad_trans)?.value(volume)?)
+    }
+    /// Аппликата центра величины, м
+    /// * volume - Объемное водоизмещение, м^3
+    pub fn kb(&self, volume: f64) -> Result<f64, Error> {
+        Self::curve("center_z", &self.center_z)?.value(volume)
+    }
+    /// Ватерлиния по осадке: поперечный метацентрический радиус
+    /// (осадка, м; радиус, м) и объемное водоизмещение (осадка, м; объем, м^3)
+    #[allow(clippy::type_complexity)]
+    pub fn waterplane(&self) -> Result<(Vec<(f64, f64)>, Vec<(f64, f64)>), Error> {
+        let rad_trans = self
+            .rad_trans
+            .iter()
+            .map(|(volume, radius)| Ok((self.draught(*volume)?, *radius)))
+            .collect::<Result<_, Error>>()?;
+        let volume = self.mean_draught.iter().map(|(volume, draught)| (*draught, *volume)).collect();
+        Ok((rad_trans, volume))
+    }
+    /// Угол входа кромки верхней палубы в воду, градус
+    /// * draught - Средняя осадка, м
+    pub fn entry_angle(&self, draught: f64) -> Result<Option<f64>, Error> {
+        self.entry_angle
+            .as_ref()
+            .map(|values| Self::curve("entry_angle", values)?.value(draught))
+            .transpose()
+    }
+    /// Длина по ватерлинии, м
+    /// * draught - Средняя осадка, м
+    pub fn waterline_length(&self, draught: f64) -> Result<Option<f64>, Error> {
+        self.waterline_length
+            .as_ref()
+            .map(|values| Self::curve("waterline_length", values)?.value(draught))
+            .transpose()
+    }
+    /// Инерционный коэффициент c для периода качки
+    /// * draught - Средняя осадка, м
+    pub fn inertia_coefficient(&self, draught: f64) -> Result<Option<f64>, Error> {
+        self.inertia_coefficient
+            .as_ref()
+            .map(|values| Self::curve("coefficient_c", values)?.value(draught))
+            .transpose()
+    }
+    /// Угол заливания отверстий, градус
+    /// * draught - Средняя осадка, м
+    pub fn flooding_angle(&self, draught: f64) -> Result<Option<f64>, Error> {
+        self.flooding_angle
+            .as_ref()
+            .map(|values| Self::curve("flooding_angle", values)?.value(draught))
+            .transpose()
+    }
+    /// Безразмерный множитель X1 для амплитуды качки
+    /// * ratio - Отношение ширины судна к средней осадке B/d
+    pub fn multipler_x1(&self, ratio: f64) -> Result<Option<f64>, Error> {
+        self.multipler_x1
+            .as_ref()
+            .map(|values| Self::curve("multipler_x1", values)?.value(ratio))
+            .transpose()
+    }
+    /// Безразмерный множитель X2 для амплитуды качки
+    /// * c_b - Коэффициент общей полноты
+    pub fn multipler_x2(&self, c_b: f64) -> Result<Option<f64>, Error> {
+        self.multipler_x2
+            .as_ref()
+            .map(|values| Self::curve("multipler_x2", values)?.value(c_b))
+            .transpose()
+    }
+    /// Коэффициент k для судов со скуловыми килями
+    /// * ratio - Отношение площади скуловых килей к L·B, %
+    pub fn coefficient_k(&self, ratio: f64) -> Result<Option<f64>, Error> {
+        self.coefficient_k
+            .as_ref()
+            .map(|values| Self::curve("coefficient_k", values)?.value(ratio))
+            .transpose()
+    }
+    /// Диаграмма статической остойчивости
+    /// GZ(θ) = l_ф(θ) - z_g·sinθ - y_g·cosθ: (крен, градус; плечо, м).
+    /// Углы крена берутся из таблицы плечей остойчивости формы
+    /// * draught - Средняя осадка, м
+    /// * z_g - Аппликата центра тяжести с поправкой на свободные поверхности, м
+    /// * y_g - Поперечное отстояние центра тяжести, м
+    pub fn gz(&self, draught: f64, z_g: f64, y_g: f64) -> Result<Vec<(f64, f64)>, Error> {
+        let pantocaren = Curve2D::from_values_linear(&self.pantocaren)
+            .map_err(|e| Error::FromString(format!("HydrostaticTables pantocaren error: {e}")))?;
+        let angles: Vec<f64> = self
+            .pantocaren
+            .first()
+            .ok_or(Error::FromString("HydrostaticTables gz error: no pantocaren".to_owned()))?
+            .1
+            .iter()
+            .map(|(angle, _)| *angle)
+            .collect();
+        angles
+            .into_iter()
+            .map(|angle| {
+                let l_f = pantocaren.value(draught, angle)?.value;
+                let (sin, cos) = angle.to_radians().sin_cos();
+                Ok((angle, l_f - z_g * sin - y_g * cos))
+            })
+            .collect()
+    }
+}
@@ -3,6 +3,7 @@
 pub mod bow_board;
 pub mod draft_mark;
 pub mod horizontal_area;
+pub mod hydrostatics;
 pub mod icing;
 pub mod load_line;
 pub mod multipler_s;
@@ -19,6 +20,7 @@ pub mod criterion;
 pub use bow_board::*;
 pub use draft_mark::*;
 pub use horizontal_area::*;
+pub use hydrostatics::*;
 pub use vertical_area::*;
 pub use icing::*;
 pub use load_line::*;
@@ -40,3 +42,6 @@ pub type MultiplerX2Array = DataArray<Pair>;
 pub type CoefficientKArray = DataArray<Pair>;
 /// Коэффициент k_theta учитывающий особенности качки судов смешанного типа
 pub type CoefficientKThetaArray = DataArray<Pair>;
+/// Инерционный коэффициент c по средней осадке
+/// для расчета периода бортовой качки, п. 2.1.5.1
+pub type CoefficientCArray = DataArray<Pair>;
@@ -0,0 +1,71 @@
+//! Промежуточные структуры для serde_json для парсинга
+//! допускаемых изгибающих моментов и перерезывающих сил
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{stability::NavigationArea, DataArray},
+    kernel::error::error::Error,
+};
+/// Допускаемые изгибающий момент и перерезывающая сила
+/// в сечении корпуса для района плавания
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GirderLimitData {
+    /// Район плавания судна
+    pub area: NavigationArea,
+    /// Отстояние сечения от миделя, м
+    pub frame_x: f64,
+    /// Допускаемый изгибающий момент при перегибе, т*м
+    pub bending_max: f64,
+    /// Допускаемый изгибающий момент при прогибе, т*м
+    pub bending_min: f64,
+    /// Допускаемая положительная перерезывающая сила, т
+    pub shear_max: f64,
+    /// Допускаемая отрицательная перерезывающая сила, т
+    pub shear_min: f64,
+}
+//
+impl std::fmt::Display for GirderLimitData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GirderLimitData(area:{} frame_x:{} bending:({}, {}) shear:({}, {}) )",
+            self.area, self.frame_x, self.bending_min, self.bending_max, self.shear_min, self.shear_max,
+        )
+    }
+}
+pub type GirderLimitArray = DataArray<GirderLimitData>;
+//
+impl GirderLimitArray {
+    /// Допускаемые значения для всех районов плавания, отсортированные по отстоянию сечения
+    pub fn data(mut self) -> Result<Vec<GirderLimitData>, Error> {
+        if let Some(v) = self.data.iter().find(|v| v.frame_x.is_nan()) {
+            return Err(Error::FromString(format!(
+                "GirderLimitArray data error: frame_x is NaN in {v}"
+            )));
+        }
+        self.data.sort_by(|a, b| a.frame_x.total_cmp(&b.frame_x));
+        Ok(self.data)
+    }
+}
+/// Коэффициент редукции волновых нагрузок для района плавания
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WaveReductionData {
+    /// Район плавания судна
+    pub area: NavigationArea,
+    /// Коэффициент редукции
+    pub value: f64,
+}
+//
+impl std::fmt::Display for WaveReductionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WaveReductionData(area:{} value:{} )", self.area, self.value)
+    }
+}
+pub type WaveReductionArray = DataArray<WaveReductionData>;
+//
+impl WaveReductionArray {
+    /// Преобразование и возвращает данные в виде вектора (район плавания, коэффициент)
+    pub fn data(self) -> Vec<(NavigationArea, f64)> {
+        self.data.into_iter().map(|v| (v.area, v.value)).collect()
+    }
+}
@@ -3,11 +3,17 @@
 pub mod computed_frame;
 pub mod frame_area;
 pub mod frame_index;
+pub mod girder_limit;
 pub mod horizontal_area;
+pub mod section_modulus;
+pub mod torsion_limit;
 pub mod vertical_area;
 
 pub use computed_frame::*;
 pub use frame_area::*;
 pub use frame_index::*;
+pub use girder_limit::*;
 pub use horizontal_area::*;
+pub use section_modulus::*;
+pub use torsion_limit::*;
 pub use vertical_area::*;
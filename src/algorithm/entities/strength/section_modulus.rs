@@ -0,0 +1,38 @@
+//! Промежуточные структуры для serde_json для парсинга
+//! моментов сопротивления поперечных сечений корпуса
+use serde::{Deserialize, Serialize};
+
+use crate::algorithm::entities::DataArray;
+/// Геометрические характеристики поперечного сечения корпуса по шпангоуту
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SectionModulusData {
+    /// Индекс шпангоута
+    pub index: i32,
+    /// Момент сопротивления сечения на уровне палубы, м^3
+    pub w_deck: f64,
+    /// Момент сопротивления сечения на уровне днища, м^3
+    pub w_bottom: f64,
+    /// Площадь стенок, воспринимающих перерезывающую силу, м^2
+    pub shear_area: f64,
+    /// Категория стали сечения
+    pub steel_grade: String,
+}
+//
+impl std::fmt::Display for SectionModulusData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SectionModulusData(index:{} w_deck:{} w_bottom:{} shear_area:{} steel_grade:{} )",
+            self.index, self.w_deck, self.w_bottom, self.shear_area, self.steel_grade,
+        )
+    }
+}
+pub type SectionModulusArray = DataArray<SectionModulusData>;
+//
+impl SectionModulusArray {
+    /// Данные, отсортированные по индексу шпангоута
+    pub fn data(mut self) -> Vec<SectionModulusData> {
+        self.data.sort_by_key(|v| v.index);
+        self.data
+    }
+}
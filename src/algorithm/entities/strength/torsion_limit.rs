@@ -0,0 +1,38 @@
+//! Промежуточные структуры для serde_json для парсинга
+//! допускаемых крутящих моментов корпуса
+use serde::{Deserialize, Serialize};
+
+use crate::{algorithm::entities::DataArray, kernel::error::error::Error};
+/// Допускаемый крутящий момент в сечении корпуса
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TorsionLimitData {
+    /// Отстояние сечения от миделя, м
+    pub frame_x: f64,
+    /// Допускаемый крутящий момент, т*м
+    pub value: f64,
+}
+//
+impl std::fmt::Display for TorsionLimitData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TorsionLimitData(frame_x:{} value:{} )",
+            self.frame_x, self.value,
+        )
+    }
+}
+pub type TorsionLimitArray = DataArray<TorsionLimitData>;
+//
+impl TorsionLimitArray {
+    /// Преобразование и возвращает данные в виде вектора (отстояние сечения, допускаемый момент)
+    pub fn data(self) -> Result<Vec<(f64, f64)>, Error> {
+        if let Some(v) = self.data.iter().find(|v| v.frame_x.is_nan()) {
+            return Err(Error::FromString(format!(
+                "TorsionLimitArray data error: frame_x is NaN in {v}"
+            )));
+        }
+        let mut result: Vec<_> = self.data.into_iter().map(|v| (v.frame_x, v.value)).collect();
+        result.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Ok(result)
+    }
+}
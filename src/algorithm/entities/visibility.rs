@@ -0,0 +1,127 @@
+//! Обзор с ходового мостика, требование МК СОЛАС V/22
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{load::IDesk, math::Position},
+    kernel::error::error::Error,
+};
+
+/// Результат проверки обзора с ходового мостика
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VisibilityCheck {
+    /// Длина зоны затенения впереди форштевня, м.
+    /// None - препятствие выше глаза наблюдателя, зона бесконечна
+    pub blind_sector: Option<f64>,
+    /// Допустимая длина зоны затенения, м
+    pub limit: f64,
+}
+//
+impl VisibilityCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.blind_sector.is_some_and(|blind_sector| blind_sector <= self.limit)
+    }
+}
+/// Обзор поверхности моря с поста управления судном.
+/// Зона затенения впереди форштевня определяется линией
+/// визирования через наиболее высокие препятствия в нос
+/// от глаза наблюдателя: палубный груз и носовую оконечность
+pub struct Visibility {
+    /// Длина судна между перпендикулярами, м
+    length: f64,
+    /// Положение глаза наблюдателя, м
+    eye: Position,
+    /// Верхняя точка носовой оконечности, м
+    bow: Position,
+    /// Осадка на кормовом перпендикуляре, м
+    draught_aft: f64,
+    /// Осадка на носовом перпендикуляре, м
+    draught_fwd: f64,
+}
+//
+impl Visibility {
+    /// Основной конструктор
+    /// * length - Длина судна между перпендикулярами, м
+    /// * eye - Положение глаза наблюдателя на посту управления, м
+    /// * bow - Верхняя точка носовой оконечности (фальшборта), м
+    /// * draught_aft - Осадка на кормовом перпендикуляре, м
+    /// * draught_fwd - Осадка на носовом перпендикуляре, м
+    pub fn new(
+        length: f64,
+        eye: Position,
+        bow: Position,
+        draught_aft: f64,
+        draught_fwd: f64,
+    ) -> Result<Self, Error> {
+        if length <= 0. {
+            return Err(Error::FromString(format!("Visibility new error: length {length} <= 0.")));
+        }
+        if bow.x() <= eye.x() {
+            return Err(Error::FromString(format!(
+                "Visibility new error: bow.x {} <= eye.x {}",
+                bow.x(),
+                eye.x()
+            )));
+        }
+        Ok(Self {
+            length,
+            eye,
+            bow,
+            draught_aft,
+            draught_fwd,
+        })
+    }
+    /// Допустимая длина зоны затенения: 2L, но не более 500 м
+    pub fn limit(&self) -> f64 {
+        (2. * self.length).min(500.)
+    }
+    /// Длина зоны затенения впереди форштевня, м.
+    /// Если препятствие выше глаза наблюдателя, зона бесконечна
+    /// * desks - Палубные грузы
+    pub fn blind_sector(&self, desks: &[Rc<dyn IDesk>]) -> Result<f64, Error> {
+        self.blind_sector_by_tops(&Self::tops(desks))
+    }
+    /// Длина зоны затенения впереди форштевня по верхним точкам палубных грузов, м.
+    /// Если препятствие выше глаза наблюдателя, зона бесконечна
+    /// * tops - Верхние точки палубных грузов: (наибольший x, м; наибольший z, м)
+    pub fn blind_sector_by_tops(&self, tops: &[(f64, f64)]) -> Result<f64, Error> {
+        // тангенс наименьшего угла визирования ниже горизонта
+        let mut tan = (self.eye.z() - self.bow.z()) / (self.bow.x() - self.eye.x());
+        for (max_x, max_z) in tops.iter().copied() {
+            if max_x <= self.eye.x() {
+                continue;
+            }
+            tan = tan.min((self.eye.z() - max_z) / (max_x - self.eye.x()));
+        }
+        // наклон ватерлинии по длине судна
+        let slope = (self.draught_fwd - self.draught_aft) / self.length;
+        if tan + slope <= 0. {
+            return Ok(f64::INFINITY);
+        }
+        // пересечение линии визирования с ватерлинией, ватерлиния
+        // отсчитывается от кормового перпендикуляра в точке x = -L/2
+        let x = (self.eye.z() + tan * self.eye.x() - self.draught_aft - slope * self.length / 2.)
+            / (tan + slope);
+        Ok((x - self.bow.x()).max(0.))
+    }
+    /// Проверка зоны затенения
+    /// * desks - Палубные грузы
+    pub fn check(&self, desks: &[Rc<dyn IDesk>]) -> Result<VisibilityCheck, Error> {
+        self.check_by_tops(&Self::tops(desks))
+    }
+    /// Проверка зоны затенения по верхним точкам палубных грузов
+    /// * tops - Верхние точки палубных грузов: (наибольший x, м; наибольший z, м)
+    pub fn check_by_tops(&self, tops: &[(f64, f64)]) -> Result<VisibilityCheck, Error> {
+        let blind_sector = self.blind_sector_by_tops(tops)?;
+        Ok(VisibilityCheck {
+            blind_sector: Some(blind_sector).filter(|v| v.is_finite()),
+            limit: self.limit(),
+        })
+    }
+    /// Верхние точки палубных грузов: (наибольший x, м; наибольший z, м)
+    /// * desks - Палубные грузы
+    pub fn tops(desks: &[Rc<dyn IDesk>]) -> Vec<(f64, f64)> {
+        desks.iter().filter_map(|desk| Some((desk.max_x()?, desk.max_z()?))).collect()
+    }
+}
@@ -1,13 +1,15 @@
 //! Промежуточные структуры для serde_json для парсинга данных судна
 use super::DataArray;
 use serde::{Deserialize, Serialize};
+/// Скорость 1 узел, м/с
+pub const KNOT: f64 = 0.514444444;
 /// Общие по судну и расчету
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 
 pub struct Voyage {
     /// плотность воды
     pub density: f64,
-    /// Эксплуатационная скорость судна, m/s
+    /// Эксплуатационная скорость судна, узлы
     pub operational_speed: f64,
     /// Cтепень намокания палубного лесного груза, %
     pub wetting_timber: f64,
@@ -15,6 +17,47 @@ pub struct Voyage {
     pub icing_type: String,
     /// Тип облединения палубного груза - леса
     pub icing_timber_type: String,
+    /// Дифферент судна в момент замера уровня в цистернах, м
+    #[serde(default)]
+    pub sounding_trim: Option<f64>,
+    /// Крен судна в момент замера уровня в цистернах, градус
+    #[serde(default)]
+    pub sounding_heel: Option<f64>,
+    /// Амплитуда бортовой качки для смещения навалочного груза, градус
+    #[serde(default)]
+    pub roll_amplitude: Option<f64>,
+    /// Период бортовой качки, замеренный экипажем, с
+    #[serde(default)]
+    pub roll_period: Option<f64>,
+    /// Глубина воды на участке плавания, м. None - глубокая вода
+    #[serde(default)]
+    pub water_depth: Option<f64>,
+    /// Ширина канала, м. None - открытое мелководье
+    #[serde(default)]
+    pub channel_width: Option<f64>,
+    /// Требуемый запас воды под килем, м
+    #[serde(default)]
+    pub ukc_required: Option<f64>,
+}
+//
+impl Default for Voyage {
+    /// Морская вода, без обледенения и намокания
+    fn default() -> Self {
+        Self {
+            density: 1.025,
+            operational_speed: 0.,
+            wetting_timber: 0.,
+            icing_type: "none".to_owned(),
+            icing_timber_type: "full".to_owned(),
+            sounding_trim: None,
+            sounding_heel: None,
+            roll_amplitude: None,
+            roll_period: None,
+            water_depth: None,
+            channel_width: None,
+            ukc_required: None,
+        }
+    }
 }
 //
 pub type VoyageArray = DataArray<Voyage>;
@@ -0,0 +1,166 @@
+//! Кренящее плечо от давления ветра
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorithm::entities::{
+        girder::G,
+        icing_stab::IIcingStab,
+        load::IDesk,
+        math::{first_root, Bound, Curve, ICurve},
+        stability::VerticalAreaArray,
+    },
+    kernel::error::error::Error,
+};
+
+/// Половина плотности воздуха, кг/м^3
+const HALF_RHO_AIR: f64 = 0.613;
+/// Результат проверки угла крена от постоянного ветра
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindHeelCheck {
+    /// Угол крена, градус. None - плечо остойчивости
+    /// не уравновешивает кренящее плечо
+    pub angle: Option<f64>,
+    /// Допустимый угол крена, градус
+    pub limit: f64,
+}
+//
+impl WindHeelCheck {
+    /// Признак выполнения требования
+    pub fn is_ok(&self) -> bool {
+        self.angle.is_some_and(|angle| angle <= self.limit)
+    }
+}
+/// Кренящее плечо от давления ветра для произвольного
+/// давления p_v и добавки на порывистость m.
+/// Площадь парусности корпуса берется по осадке и
+/// увеличивается с учетом несплошных поверхностей,
+/// обледенения и палубного груза. Плечо парусности
+/// отсчитывается от половины осадки
+pub struct WindHeel {
+    /// Водоизмещение, т
+    displacement: f64,
+    /// Площадь парусности, м^2
+    area: f64,
+    /// Плечо парусности над половиной осадки, м
+    z: f64,
+}
+//
+impl WindHeel {
+    /// Допустимый угол крена от постоянного ветра, градус
+    const ANGLE_LIMIT: f64 = 16.;
+    /// Основной конструктор
+    /// * draught - Средняя осадка, м
+    /// * displacement - Водоизмещение, т
+    /// * area_v - Площадь и статические моменты парусности корпуса по осадке
+    /// * icing - Обледенение судна
+    /// * desks - Палубные грузы
+    pub fn new(
+        draught: f64,
+        displacement: f64,
+        area_v: &VerticalAreaArray,
+        icing: &dyn IIcingStab,
+        desks: &[Rc<dyn IDesk>],
+    ) -> Result<Self, Error> {
+        let mut deck = (0., 0.);
+        for desk in desks {
+            deck.0 += desk.windage_area(&Bound::Full, &Bound::Full)?;
+            deck.1 += desk.windage_moment()?.z();
+        }
+        Self::from_windage(draught, displacement, area_v, icing, deck)
+    }
+    /// Конструктор по суммарной парусности палубных грузов
+    /// * draught - Средняя осадка, м
+    /// * displacement - Водоизмещение, т
+    /// * area_v - Площадь и статические моменты парусности корпуса по осадке
+    /// * icing - Обледенение судна
+    /// * deck - Площадь парусности палубных грузов, м^2, и ее статический момент по вертикали, м^3
+    pub fn from_windage(
+        draught: f64,
+        displacement: f64,
+        area_v: &VerticalAreaArray,
+        icing: &dyn IIcingStab,
+        deck: (f64, f64),
+    ) -> Result<Self, Error> {
+        if displacement <= 0. {
+            return Err(Error::FromString(format!(
+                "WindHeel new error: displacement {displacement} <= 0."
+            )));
+        }
+        let area = Curve::new_linear(&area_v.area())
+            .map_err(|e| Error::FromString(format!("WindHeel new area error: {e}")))?
+            .value(draught)?
+            * (1. + icing.coef_v_area())
+            + deck.0;
+        let moment_z = Curve::new_linear(&area_v.moment_z())
+            .map_err(|e| Error::FromString(format!("WindHeel new moment_z error: {e}")))?
+            .value(draught)?
+            * (1. + icing.coef_v_moment())
+            + deck.1;
+        if area <= 0. {
+            return Err(Error::FromString(format!("WindHeel new error: area {area} <= 0.")));
+        }
+        Ok(Self {
+            displacement,
+            area,
+            z: moment_z / area - draught / 2.,
+        })
+    }
+    /// Давление ветра по скорости, Па
+    /// * wind_speed - Скорость ветра, м/с
+    pub fn pressure(wind_speed: f64) -> f64 {
+        HALF_RHO_AIR * wind_speed.powi(2)
+    }
+    /// Площадь парусности, м^2
+    pub fn area(&self) -> f64 {
+        self.area
+    }
+    /// Кренящее плечо на прямом положении, м
+    /// * p_v - Давление ветра, Па
+    /// * m - Добавка на порывистость ветра, Па
+    pub fn lever(&self, p_v: f64, m: f64) -> f64 {
+        (p_v + m) * self.area * self.z / (1000. * G * self.displacement)
+    }
+    /// Кривая кренящего плеча: (угол крена, градус; плечо, м).
+    /// Плечо уменьшается с креном пропорционально cos²θ
+    /// * p_v - Давление ветра, Па
+    /// * m - Добавка на порывистость ветра, Па
+    /// * angles - Углы крена, градус
+    pub fn curve(&self, p_v: f64, m: f64, angles: &[f64]) -> Vec<(f64, f64)> {
+        let lever = self.lever(p_v, m);
+        angles
+            .iter()
+            .map(|angle| (*angle, lever * angle.to_radians().cos().powi(2)))
+            .collect()
+    }
+    /// Угол установившегося крена под действием ветра, градус.
+    /// None - плечо остойчивости не уравновешивает кренящее плечо
+    /// * p_v - Давление ветра, Па
+    /// * m - Добавка на порывистость ветра, Па
+    /// * gz - Диаграмма статической остойчивости: (угол крена, градус; плечо, м)
+    pub fn steady_heel(&self, p_v: f64, m: f64, gz: &[(f64, f64)]) -> Result<Option<f64>, Error> {
+        let max_angle = gz
+            .iter()
+            .map(|(angle, _)| *angle)
+            .fold(f64::MIN, f64::max);
+        let gz = Curve::new_linear(gz)
+            .map_err(|e| Error::FromString(format!("WindHeel steady_heel gz error: {e}")))?;
+        let lever = self.lever(p_v, m);
+        let delta = |angle: f64| -> Result<f64, Error> {
+            Ok(gz.value(angle)? - lever * angle.to_radians().cos().powi(2))
+        };
+        first_root(delta, 0., max_angle, 0.1)
+    }
+    /// Проверка угла установившегося крена: не более 16° и не более
+    /// 0.8 угла входа кромки палубы в воду
+    /// * p_v - Давление ветра, Па
+    /// * m - Добавка на порывистость ветра, Па
+    /// * gz - Диаграмма статической остойчивости: (угол крена, градус; плечо, м)
+    /// * entry_angle - Угол входа кромки верхней палубы в воду, градус
+    pub fn check(&self, p_v: f64, m: f64, gz: &[(f64, f64)], entry_angle: Option<f64>) -> Result<WindHeelCheck, Error> {
+        Ok(WindHeelCheck {
+            angle: self.steady_heel(p_v, m, gz)?,
+            limit: entry_angle.map_or(Self::ANGLE_LIMIT, |v| (0.8 * v).min(Self::ANGLE_LIMIT)),
+        })
+    }
+}
@@ -1,11 +1,33 @@
-use sal_sync::services::entity::error::str_err::StrErr;
+use std::collections::HashMap;
+use serde::de::DeserializeOwned;
 use crate::{
     algorithm::{
-        context::{context::Context, context_access::{ContextReadRef, ContextWrite}, ctx_result::CtxResult},
-        entities::{serde_parser::IFromJson, strength::{ComputedFrameData, ComputedFrameDataArray}, DataArray},
+        context::{context::Context, context_access::{ContextRead, ContextWrite}, ctx_result::CtxResult, ctx_store::CtxType},
+        pipeline::step::Step,
+        entities::{
+            loads::{
+                BulkheadPlaceArray, ContainerStackLimit, ContainerStackLimitArray, DeckZoneArray, DeckZoneData, HoldArray, HoldCapacityArray, HoldTables,
+                LoadConstantArray, LoadConstantData, SoundingArray, SoundingCorrectionArray, SoundingTables,
+            },
+            Ship, ShipArray, ShipParametersArray, ShipRevisionArray,
+            EntryAngleDataArray, FloodingAngleDataArray, MeanDraughtDataArray, RadTransDataArray, WaterlineLengthArray,
+            serde_parser::IFromJson,
+            stability::{
+                CenterDraughtShiftArray, CoefficientCArray, CoefficientKArray, HydrostaticTables, IcingArray, MultiplerX1Array,
+                MultiplerX2Array, NavigationArea, PantocarenDataArray,
+                VerticalArea, VerticalAreaArray,
+            },
+            strength::{
+                ComputedFrameDataArray, FrameAreaDataArray, FrameIndexDataArray, GirderLimitArray, GirderLimitData,
+                SectionModulusArray, SectionModulusData, TorsionLimitArray, WaveReductionArray,
+            },
+        },
     },
     infrostructure::api::client::api_client::ApiClient,
-    kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult}
+    kernel::{
+        dbgid::dbgid::DbgId, error::{error::Error, eval_error::{EvalError, EvalErrorKind}},
+        eval::Eval, types::eval_result::EvalResult,
+    },
 };
 
 use super::initial_ctx::InitialCtx;
@@ -17,7 +39,6 @@ use super::initial_ctx::InitialCtx;
 pub struct Initial {
     dbg: DbgId,
     api_client: ApiClient,
-    ctx: Context,
 }
 //
 //
@@ -25,40 +46,453 @@ impl Initial {
     ///
     /// Fetches all initiall data
     /// - 'api_client' - access to the database
-    pub fn new(parent: impl Into<String>, api_client: ApiClient, ctx: Context) -> Self {
+    pub fn new(parent: impl Into<String>, api_client: ApiClient) -> Self {
         let dbg = DbgId::with_parent(&DbgId(parent.into()), "Initial");
         Self {
             dbg,
             api_client,
-            ctx
         }
     }
     //
     //
 }
-impl Eval<(), EvalResult> for Initial {
-    fn eval(&mut self, _: ()) -> futures::future::BoxFuture<'_, EvalResult> {
+impl Eval<Context, EvalResult> for Initial {
+    fn eval(&mut self, ctx: Context) -> futures::future::BoxFuture<'_, EvalResult> {
         Box::pin(async move {
-            let initial_ctx: &InitialCtx = ContextReadRef::read(&self.ctx);
-            let mut initial_ctx = initial_ctx.to_owned();
-            let bounds = self.api_client
-                .fetch(&format!(
-                    "SELECT index, start_x, end_x FROM computed_frame_space WHERE ship_id={};",
-                    initial_ctx.ship_id
-                ));
-            match bounds {
-                Ok(bounds) => {
-                    match ComputedFrameDataArray::parse(&bounds) {
-                        Ok(bounds) => {
-                            let bounds: DataArray<ComputedFrameData> = bounds;
-                            initial_ctx.bounds = Some(bounds.data());
-                            self.ctx.clone().write(initial_ctx.to_owned())
-                        }
-                        Err(err) => CtxResult::Err(StrErr(format!("{}.eval | Error: {err}", self.dbg))),
-                    }
-                }
-                Err(err) => CtxResult::Err(StrErr(format!("{}.eval | Error: {err}", self.dbg))),
-            }
+            CtxResult::try_async(async {
+                let mut initial_ctx: InitialCtx = ContextRead::read(&ctx)?;
+                let api_client = self.api_client
+                    .with_cancel(ctx.cancel_token().clone())
+                    .with_stats(ctx.query_stats().clone())
+                    .with_replies(ctx.api_replies().clone());
+                // blocking queries are not preempted by the cancel token,
+                // the step returns on cancellation and the thread finishes the query
+                let initial_ctx = tokio::task::spawn_blocking(move || {
+                    initial_ctx.bounds = Some(bounds(&api_client, initial_ctx.ship_id)?);
+                    fetch_tables(&api_client, &mut initial_ctx)?;
+                    Ok::<_, EvalError>(initial_ctx)
+                })
+                .await
+                .map_err(|err| EvalError::internal(format!("Fetch task error: {err}")))??;
+                ctx.write(initial_ctx).into()
+            })
+            .await
+            .map_err(|err| err.context(format!("{}.eval | Error", self.dbg)))
         })
     }
 }
+//
+//
+impl Step for Initial {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<InitialCtx>()]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<InitialCtx>()]
+    }
+}
+///
+/// Fetches and parses the rows of the `table`
+/// - 'sql' - the query of the rows
+///
+/// The errors are mapped by [EvalError::query]
+pub(crate) fn fetch<T: IFromJson + DeserializeOwned>(api_client: &ApiClient, ship_id: usize, table: &str, sql: &str) -> Result<T, EvalError> {
+    api_client
+        .fetch(sql)
+        .and_then(|reply| T::parse(&reply))
+        .map_err(|err| EvalError::query(err, table, ship_id).context("fetch | Error"))
+}
+///
+/// Fetches the split of the hull into frames, required for all calculations
+fn bounds(api_client: &ApiClient, ship_id: usize) -> Result<Vec<(f64, f64)>, EvalError> {
+    let table = "computed_frame_space";
+    let bounds: ComputedFrameDataArray = fetch(
+        api_client,
+        ship_id,
+        table,
+        &format!("SELECT index, start_x, end_x FROM computed_frame_space WHERE ship_id={};", ship_id),
+    )?;
+    let bounds = bounds.data();
+    if bounds.is_empty() {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: table.to_owned(), ship_id },
+            format!("bounds | No frames of the ship {ship_id}"),
+        ));
+    }
+    Ok(bounds)
+}
+///
+/// Fetches and parses the rows of the optional `table`
+/// - returns None if the table is absent in the database
+pub(crate) fn fetch_optional<T: IFromJson + DeserializeOwned>(api_client: &ApiClient, ship_id: usize, table: &str, sql: &str) -> Result<Option<T>, EvalError> {
+    match fetch(api_client, ship_id, table, sql) {
+        Ok(rows) => Ok(Some(rows)),
+        Err(err) if matches!(err.kind, EvalErrorKind::DataMissing { .. }) => {
+            log::warn!("fetch_optional | Table '{table}' skipped: {err}");
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+///
+/// Fetches the optional ship tables, absent or empty table is left None
+fn fetch_tables(api_client: &ApiClient, initial_ctx: &mut InitialCtx) -> Result<(), EvalError> {
+    let ship_id = initial_ctx.ship_id;
+    initial_ctx.revision = revision(api_client, ship_id)?;
+    initial_ctx.ship = ship(api_client, ship_id)?;
+    initial_ctx.ship_parameters = ship_parameters(api_client, ship_id)?;
+    initial_ctx.load_constants = load_constants(api_client, ship_id)?;
+    initial_ctx.sounding_tables = sounding_tables(api_client, ship_id)?;
+    initial_ctx.torsion_limits = torsion_limits(api_client, ship_id)?;
+    initial_ctx.girder_limits = girder_limits(api_client, ship_id)?;
+    initial_ctx.wave_reductions = wave_reductions(api_client, ship_id)?;
+    initial_ctx.section_moduli = section_moduli(api_client, ship_id)?;
+    initial_ctx.deck_zones = deck_zones(api_client, ship_id)?;
+    initial_ctx.hold_tables = hold_tables(api_client, ship_id)?;
+    initial_ctx.container_stack_limits = container_stack_limits(api_client, ship_id)?;
+    initial_ctx.hydrostatics = hydrostatics(api_client, ship_id)?;
+    initial_ctx.bonjean = bonjean(api_client, ship_id)?;
+    initial_ctx.windage = windage(api_client, ship_id)?;
+    initial_ctx.icing = icing(api_client, ship_id)?;
+    Ok(())
+}
+///
+/// Returns the error of the invalid rows of the `table`
+fn invalid(table: &str, err: Error) -> EvalError {
+    EvalError::new(
+        EvalErrorKind::InvalidData { field: table.to_owned(), value: err.to_string() },
+        format!("fetch | '{table}' invalid rows"),
+    )
+}
+///
+/// Returns None for the empty rows
+fn non_empty<T>(rows: Vec<T>) -> Option<Vec<T>> {
+    (!rows.is_empty()).then_some(rows)
+}
+///
+/// Fetches the revision of the ship data, None if the database has no revisions
+fn revision(api_client: &ApiClient, ship_id: usize) -> Result<Option<usize>, EvalError> {
+    Ok(fetch_optional::<ShipRevisionArray>(
+        api_client,
+        ship_id,
+        "ship_revision",
+        &format!("SELECT revision FROM ship WHERE id={};", ship_id),
+    )?
+    .and_then(|v| v.data.into_iter().next())
+    .map(|v| v.revision))
+}
+///
+/// Fetches the type and navigation area of the ship
+fn ship(api_client: &ApiClient, ship_id: usize) -> Result<Option<Ship>, EvalError> {
+    Ok(fetch_optional::<ShipArray>(
+        api_client,
+        ship_id,
+        "ship",
+        &format!("SELECT name, ship_type, navigation_area, p_v, m, freeboard_type FROM ship WHERE id={};", ship_id),
+    )?
+    .and_then(|v| v.data.into_iter().next()))
+}
+///
+/// Fetches the ship parameters by key
+fn ship_parameters(api_client: &ApiClient, ship_id: usize) -> Result<Option<HashMap<String, f64>>, EvalError> {
+    Ok(fetch_optional::<ShipParametersArray>(
+        api_client,
+        ship_id,
+        "ship_parameters",
+        &format!("SELECT key, value FROM ship_parameters WHERE ship_id={};", ship_id),
+    )?
+    .map(|v| v.data())
+    .filter(|v| !v.is_empty()))
+}
+///
+/// Fetches the constant load of the ship by frames
+fn load_constants(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<LoadConstantData>>, EvalError> {
+    Ok(fetch_optional::<LoadConstantArray>(
+        api_client,
+        ship_id,
+        "load_constant",
+        &format!("SELECT mass, bound_x1, bound_x2, loading_type FROM load_constant WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data())))
+}
+///
+/// Fetches holds with capacity curves and movable bulkhead places
+fn hold_tables(api_client: &ApiClient, ship_id: usize) -> Result<Option<HoldTables>, EvalError> {
+    let Some(holds) = fetch_optional::<HoldArray>(
+        api_client,
+        ship_id,
+        "hold",
+        &format!("SELECT id, name, bound_x1, bound_x2 FROM hold WHERE ship_id={};", ship_id),
+    )?.and_then(|holds| non_empty(holds.data())) else {
+        return Ok(None);
+    };
+    let capacity = fetch_optional::<HoldCapacityArray>(
+        api_client,
+        ship_id,
+        "hold_capacity",
+        &format!("SELECT hold_id, x, volume FROM hold_capacity WHERE ship_id={};", ship_id),
+    )?;
+    let places = fetch_optional::<BulkheadPlaceArray>(
+        api_client,
+        ship_id,
+        "bulkhead_place",
+        &format!("SELECT bulkhead_id, hold_id, position, x FROM bulkhead_place WHERE ship_id={};", ship_id),
+    )?;
+    Ok(Some(HoldTables {
+        holds,
+        capacity: capacity.map(|v| v.data()).unwrap_or_default(),
+        places: places.map(|v| v.data()).unwrap_or_default(),
+    }))
+}
+///
+/// Fetches permissible uniform loads on deck and tank-top zones
+fn deck_zones(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<DeckZoneData>>, EvalError> {
+    Ok(fetch_optional::<DeckZoneArray>(
+        api_client,
+        ship_id,
+        "deck_zone",
+        &format!("SELECT name, is_tank_top, bound_x1, bound_x2, bound_y1, bound_y2, max_load FROM deck_zone WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data())))
+}
+///
+/// Fetches the hydrostatic tables and the pantocarens on even keel
+/// - Returns None if any of the draught, metacentric radius, center of buoyancy or pantocaren tables is absent
+/// - The entry and flooding angles, the roll period and the roll amplitude tables are optional
+fn hydrostatics(api_client: &ApiClient, ship_id: usize) -> Result<Option<HydrostaticTables>, EvalError> {
+    let Some(mean_draught) = fetch_optional::<MeanDraughtDataArray>(
+        api_client,
+        ship_id,
+        "mean_draught",
+        &format!("SELECT trim, volume, value FROM mean_draught WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data())) else {
+        return Ok(None);
+    };
+    let Some(rad_trans) = fetch_optional::<RadTransDataArray>(
+        api_client,
+        ship_id,
+        "rad_trans",
+        &format!("SELECT trim, volume, value FROM rad_trans WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data())) else {
+        return Ok(None);
+    };
+    let Some(center_z) = fetch_optional::<CenterDraughtShiftArray>(
+        api_client,
+        ship_id,
+        "center_draught_shift",
+        &format!("SELECT trim, volume, value_x, value_y, value_z FROM center_draught_shift WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data())) else {
+        return Ok(None);
+    };
+    let Some(pantocaren) = fetch_optional::<PantocarenDataArray>(
+        api_client,
+        ship_id,
+        "pantocaren",
+        &format!("SELECT trim, draught, roll, moment FROM pantocaren WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data())) else {
+        return Ok(None);
+    };
+    let entry_angle = fetch_optional::<EntryAngleDataArray>(
+        api_client,
+        ship_id,
+        "entry_angle",
+        &format!("SELECT trim, draught, value FROM entry_angle WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data()));
+    let waterline_length = fetch_optional::<WaterlineLengthArray>(
+        api_client,
+        ship_id,
+        "waterline_length",
+        &format!("SELECT key, value FROM waterline_length WHERE ship_id={};", ship_id),
+    )?.and_then(|v| non_empty(v.data()));
+    let inertia_coefficient = fetch_optional::<CoefficientCArray>(
+        api_client,
+        ship_id,
+        "coefficient_c",
+        &format!("SELECT key, value FROM coefficient_c WHERE ship_id={};", ship_id),
+    )?.and_then(|v| non_empty(v.data()));
+    let flooding_angle = fetch_optional::<FloodingAngleDataArray>(
+        api_client,
+        ship_id,
+        "flooding_angle",
+        &format!("SELECT trim, draught, value FROM flooding_angle WHERE ship_id={};", ship_id),
+    )?.and_then(|v| HydrostaticTables::even_keel(v.data()));
+    let multipler_x1 = fetch_optional::<MultiplerX1Array>(
+        api_client,
+        ship_id,
+        "multipler_x1",
+        &format!("SELECT key, value FROM multipler_x1 WHERE ship_id={};", ship_id),
+    )?.and_then(|v| non_empty(v.data()));
+    let multipler_x2 = fetch_optional::<MultiplerX2Array>(
+        api_client,
+        ship_id,
+        "multipler_x2",
+        &format!("SELECT key, value FROM multipler_x2 WHERE ship_id={};", ship_id),
+    )?.and_then(|v| non_empty(v.data()));
+    let coefficient_k = fetch_optional::<CoefficientKArray>(
+        api_client,
+        ship_id,
+        "coefficient_k",
+        &format!("SELECT key, value FROM coefficient_k WHERE ship_id={};", ship_id),
+    )?.and_then(|v| non_empty(v.data()));
+    Ok(Some(HydrostaticTables {
+        mean_draught,
+        rad_trans,
+        center_z: center_z.into_iter().map(|(volume, center)| (volume, center.z())).collect(),
+        pantocaren,
+        entry_angle,
+        waterline_length,
+        inertia_coefficient,
+        flooding_angle,
+        multipler_x1,
+        multipler_x2,
+        coefficient_k,
+    }))
+}
+///
+/// Fetches the immersed areas of the frames by the draught, sorted by the frame position
+#[allow(clippy::type_complexity)]
+fn bonjean(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<(f64, Vec<(f64, f64)>)>>, EvalError> {
+    let Some(frames) = fetch_optional::<FrameIndexDataArray>(
+        api_client,
+        ship_id,
+        "bonjean_frame",
+        &format!("SELECT frame_index, pos_x FROM bonjean_frame WHERE ship_id={};", ship_id),
+    )?.map(|v| v.data()).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let areas = fetch_optional::<FrameAreaDataArray>(
+        api_client,
+        ship_id,
+        "frame_area",
+        &format!("SELECT frame_index, draft, area FROM frame_area WHERE ship_id={};", ship_id),
+    )?.map(|v| v.data()).unwrap_or_default();
+    let mut bonjean = frames.into_iter()
+        .map(|(index, x)| match areas.get(&index) {
+            Some(area) => Ok((x, area.clone())),
+            None => Err(EvalError::new(
+                EvalErrorKind::DataMissing { table: "frame_area".to_owned(), ship_id },
+                format!("bonjean | No immersed area of the frame {index}"),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    bonjean.sort_by(|a, b| a.0.total_cmp(&b.0));
+    Ok(Some(bonjean))
+}
+///
+/// Fetches the windage area of the hull and its static moments by the draught
+fn windage(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<VerticalArea>>, EvalError> {
+    Ok(fetch_optional::<VerticalAreaArray>(
+        api_client,
+        ship_id,
+        "vertical_area_stability",
+        &format!("SELECT draught, area, moment_x, moment_z FROM vertical_area_stability WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data)))
+}
+///
+/// Fetches the masses of the ice and the windage coefficients of the icing by key
+fn icing(api_client: &ApiClient, ship_id: usize) -> Result<Option<HashMap<String, f64>>, EvalError> {
+    Ok(fetch_optional::<IcingArray>(
+        api_client,
+        ship_id,
+        "icing",
+        &format!("SELECT key, value FROM icing WHERE ship_id={};", ship_id),
+    )?
+    .map(|v| v.data())
+    .filter(|v| !v.is_empty()))
+}
+///
+/// Fetches permissible masses of the container stacks
+fn container_stack_limits(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<ContainerStackLimit>>, EvalError> {
+    Ok(fetch_optional::<ContainerStackLimitArray>(
+        api_client,
+        ship_id,
+        "container_stack_limit",
+        &format!("SELECT bay, row, is_on_deck, max_mass FROM container_stack_limit WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data())))
+}
+///
+/// Fetches hull section moduli and shear areas by computed frame
+fn section_moduli(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<SectionModulusData>>, EvalError> {
+    Ok(fetch_optional::<SectionModulusArray>(
+        api_client,
+        ship_id,
+        "section_modulus",
+        &format!("SELECT index, w_deck, w_bottom, shear_area, steel_grade FROM section_modulus WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data())))
+}
+///
+/// Fetches permissible hull girder bending moments and shear forces for all navigation areas
+fn girder_limits(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<GirderLimitData>>, EvalError> {
+    Ok(fetch_optional::<GirderLimitArray>(
+        api_client,
+        ship_id,
+        "girder_limit",
+        &format!("SELECT area, frame_x, bending_max, bending_min, shear_max, shear_min FROM girder_limit WHERE ship_id={};", ship_id),
+    )?
+    .map(|v| v.data())
+    .transpose()
+    .map_err(|err| invalid("girder_limit", err))?
+    .and_then(non_empty))
+}
+///
+/// Fetches wave load reduction factors by navigation area
+fn wave_reductions(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<(NavigationArea, f64)>>, EvalError> {
+    Ok(fetch_optional::<WaveReductionArray>(
+        api_client,
+        ship_id,
+        "wave_load_reduction",
+        &format!("SELECT area, value FROM wave_load_reduction WHERE ship_id={};", ship_id),
+    )?
+    .and_then(|v| non_empty(v.data())))
+}
+///
+/// Fetches permissible hull girder torsional moments
+fn torsion_limits(api_client: &ApiClient, ship_id: usize) -> Result<Option<Vec<(f64, f64)>>, EvalError> {
+    Ok(fetch_optional::<TorsionLimitArray>(
+        api_client,
+        ship_id,
+        "torsion_limit",
+        &format!("SELECT frame_x, value FROM torsion_limit WHERE ship_id={};", ship_id),
+    )?
+    .map(|v| v.data())
+    .transpose()
+    .map_err(|err| invalid("torsion_limit", err))?
+    .and_then(non_empty))
+}
+///
+/// Fetches tank calibration tables with optional trim and heel corrections
+fn sounding_tables(api_client: &ApiClient, ship_id: usize) -> Result<Option<SoundingTables>, EvalError> {
+    let Some(table) = fetch_optional::<SoundingArray>(
+        api_client,
+        ship_id,
+        "tank_sounding",
+        &format!("SELECT space_id, sounding, volume, shift_x, shift_y, shift_z, m_f_s_y, m_f_s_x FROM tank_sounding WHERE ship_id={};", ship_id),
+    )?
+    .map(|v| v.data())
+    .filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    let trim_correction = fetch_optional::<SoundingCorrectionArray>(
+        api_client,
+        ship_id,
+        "tank_sounding_trim_correction",
+        &format!("SELECT space_id, trim AS key, sounding, value FROM tank_sounding_trim_correction WHERE ship_id={};", ship_id),
+    )?;
+    let heel_correction = fetch_optional::<SoundingCorrectionArray>(
+        api_client,
+        ship_id,
+        "tank_sounding_heel_correction",
+        &format!("SELECT space_id, heel AS key, sounding, value FROM tank_sounding_heel_correction WHERE ship_id={};", ship_id),
+    )?;
+    Ok(Some(SoundingTables {
+        table,
+        trim_correction: trim_correction.map(|v| v.data()).unwrap_or_default(),
+        heel_correction: heel_correction.map(|v| v.data()).unwrap_or_default(),
+    }))
+}
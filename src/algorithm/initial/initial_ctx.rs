@@ -1,11 +1,55 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::algorithm::context::ctx_store::StepCtx;
+use crate::algorithm::entities::{
+    Ship,
+    loads::{ContainerStackLimit, DeckZoneData, HoldTables, LoadConstantData, SoundingTables},
+    stability::{HydrostaticTables, NavigationArea, VerticalArea},
+    strength::{GirderLimitData, SectionModulusData},
+};
 ///
 /// Общая структура для ввода данных. Содержит все данные
 /// для расчетов.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitialCtx {
     pub ship_id: usize,
+    /// Ревизия данных судна, при изменении данных
+    /// сохраненные результаты расчетов не используются.
+    /// None - ревизия неизвестна, результаты не сохраняются
+    pub revision: Option<usize>,
     /// разбиение на шпации - фреймы
     pub bounds: Option<Vec<(f64, f64)>>,
+    /// Общие данные судна: тип, район плавания
+    pub ship: Option<Ship>,
+    /// Параметры судна по ключу
+    pub ship_parameters: Option<HashMap<String, f64>>,
+    /// Постоянная нагрузка на судно
+    pub load_constants: Option<Vec<LoadConstantData>>,
+    /// Калибровочные таблицы цистерн
+    pub sounding_tables: Option<SoundingTables>,
+    /// Допускаемые крутящие моменты: (отстояние сечения, момент, т*м)
+    pub torsion_limits: Option<Vec<(f64, f64)>>,
+    /// Допускаемые изгибающие моменты и перерезывающие силы для всех районов плавания
+    pub girder_limits: Option<Vec<GirderLimitData>>,
+    /// Коэффициенты редукции волновых нагрузок: (район плавания, коэффициент)
+    pub wave_reductions: Option<Vec<(NavigationArea, f64)>>,
+    /// Моменты сопротивления и площади сдвига сечений по шпангоутам
+    pub section_moduli: Option<Vec<SectionModulusData>>,
+    /// Зоны палуб и настила второго дна с допускаемыми нагрузками
+    pub deck_zones: Option<Vec<DeckZoneData>>,
+    /// Трюмы с кривыми вместимости и местами установки съемных перегородок
+    pub hold_tables: Option<HoldTables>,
+    /// Допустимые массы штабелей контейнеров
+    pub container_stack_limits: Option<Vec<ContainerStackLimit>>,
+    /// Гидростатические таблицы и плечи остойчивости формы на ровный киль
+    pub hydrostatics: Option<HydrostaticTables>,
+    /// Масштаб Бонжана: (отстояние шпангоута, м; [(осадка, м; погруженная площадь, м^2)])
+    #[allow(clippy::type_complexity)]
+    pub bonjean: Option<Vec<(f64, Vec<(f64, f64)>)>>,
+    /// Площадь и статические моменты парусности корпуса по осадке
+    pub windage: Option<Vec<VerticalArea>>,
+    /// Массы льда и коэффициенты парусности при обледенении по ключу
+    pub icing: Option<HashMap<String, f64>>,
 
     // /// Тип судна
     // pub ship_type: ShipType,
@@ -141,9 +185,31 @@ impl InitialCtx {
     pub fn new(ship_id: usize) -> Self {
         Self {
             ship_id,
+            revision: None,
             bounds: None,
+            ship: None,
+            ship_parameters: None,
+            load_constants: None,
+            sounding_tables: None,
+            torsion_limits: None,
+            girder_limits: None,
+            wave_reductions: None,
+            section_moduli: None,
+            deck_zones: None,
+            hold_tables: None,
+            container_stack_limits: None,
+            hydrostatics: None,
+            bonjean: None,
+            windage: None,
+            icing: None,
         }
     }
+    ///
+    /// Sets the revision of the ship data
+    pub fn with_revision(mut self, revision: usize) -> Self {
+        self.revision = Some(revision);
+        self
+    }
 }
 //
 //
@@ -154,7 +220,28 @@ impl Default for InitialCtx {
     fn default() -> Self {
         Self {
             ship_id: 0,
+            revision: None,
             bounds: None,
+            ship: None,
+            ship_parameters: None,
+            load_constants: None,
+            sounding_tables: None,
+            torsion_limits: None,
+            girder_limits: None,
+            wave_reductions: None,
+            section_moduli: None,
+            deck_zones: None,
+            hold_tables: None,
+            container_stack_limits: None,
+            hydrostatics: None,
+            bonjean: None,
+            windage: None,
+            icing: None,
         }
     }
 }
+//
+//
+impl StepCtx for InitialCtx {
+    const STEP: &'static str = "Initial";
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::{ContextRead, ContextWrite}, ctx_store::{CtxType, StepCtx}},
+        entities::{loads::{Bulkhead, CompartmentData, Container, LoadCargo}, voyage::Voyage},
+    },
+    kernel::error::eval_error::{EvalError, EvalErrorKind},
+};
+use super::loading_ctx::{BulkheadsCtx, CargoesCtx, CompartmentsCtx, ContainersCtx, VoyageCtx};
+///
+/// # Changes of the loading sent with the restart of calculation
+/// - Cargoes and bulkheads are matched by the name, containers by the `id`, compartments by the `space_id`
+/// - Not matched cargoes, containers, bulkheads and compartments are added
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ChangeSet {
+    /// Changed or added cargoes
+    #[serde(default)]
+    pub cargoes: Vec<LoadCargo>,
+    /// Names of the removed cargoes
+    #[serde(default)]
+    pub removed_cargoes: Vec<String>,
+    /// Changed or added containers
+    #[serde(default)]
+    pub containers: Vec<Container>,
+    /// Ids of the removed containers
+    #[serde(default)]
+    pub removed_containers: Vec<i32>,
+    /// Changed or added compartments
+    #[serde(default)]
+    pub compartments: Vec<CompartmentData>,
+    /// Changed or added bulkheads, the movable bulkhead is moved by its `position`
+    #[serde(default)]
+    pub bulkheads: Vec<Bulkhead>,
+    /// New voyage parameters
+    #[serde(default)]
+    pub voyage: Option<Voyage>,
+}
+//
+//
+impl ChangeSet {
+    ///
+    /// Returns true if nothing is changed
+    pub fn is_empty(&self) -> bool {
+        self.cargoes.is_empty()
+            && self.removed_cargoes.is_empty()
+            && self.containers.is_empty()
+            && self.removed_containers.is_empty()
+            && self.compartments.is_empty() && self.bulkheads.is_empty() && self.voyage.is_none()
+    }
+    ///
+    /// Applies the changes to the loading stored in the `ctx`
+    /// - Returns the context with changed loading and the changed context types
+    pub fn apply(&self, ctx: Context) -> Result<(Context, Vec<CtxType>), EvalError> {
+        let mut ctx = ctx;
+        let mut changed = vec![];
+        if !self.cargoes.is_empty() || !self.removed_cargoes.is_empty() {
+            let mut value: CargoesCtx = ContextRead::read(&ctx).unwrap_or_default();
+            value.cargoes.retain(|cargo| !self.removed_cargoes.contains(&cargo.name));
+            for cargo in &self.cargoes {
+                match value.cargoes.iter_mut().find(|v| v.name == cargo.name) {
+                    Some(v) => *v = cargo.clone(),
+                    None => value.cargoes.push(cargo.clone()),
+                }
+            }
+            ctx = write(ctx, value)?;
+            changed.push(CtxType::of::<CargoesCtx>());
+        }
+        if !self.containers.is_empty() || !self.removed_containers.is_empty() {
+            let mut value: ContainersCtx = ContextRead::read(&ctx).unwrap_or_default();
+            value.containers.retain(|container| !self.removed_containers.contains(&container.id));
+            for container in &self.containers {
+                match value.containers.iter_mut().find(|v| v.id == container.id) {
+                    Some(v) => *v = container.clone(),
+                    None => value.containers.push(container.clone()),
+                }
+            }
+            ctx = write(ctx, value)?;
+            changed.push(CtxType::of::<ContainersCtx>());
+        }
+        if !self.compartments.is_empty() {
+            let mut value: CompartmentsCtx = ContextRead::read(&ctx).unwrap_or_default();
+            for compartment in &self.compartments {
+                let Some(space_id) = compartment.space_id else {
+                    return Err(EvalError::new(
+                        EvalErrorKind::InvalidData { field: "space_id".to_owned(), value: "null".to_owned() },
+                        format!("ChangeSet.apply | No space_id in the changed compartment '{}'", compartment.name),
+                    ));
+                };
+                match value.compartments.iter_mut().find(|v| v.space_id == Some(space_id)) {
+                    Some(v) => *v = compartment.clone(),
+                    None => value.compartments.push(compartment.clone()),
+                }
+            }
+            ctx = write(ctx, value)?;
+            changed.push(CtxType::of::<CompartmentsCtx>());
+        }
+        if !self.bulkheads.is_empty() {
+            let mut value: BulkheadsCtx = ContextRead::read(&ctx).unwrap_or_default();
+            for bulkhead in &self.bulkheads {
+                match value.bulkheads.iter_mut().find(|v| v.name == bulkhead.name) {
+                    Some(v) => *v = bulkhead.clone(),
+                    None => value.bulkheads.push(bulkhead.clone()),
+                }
+            }
+            ctx = write(ctx, value)?;
+            changed.push(CtxType::of::<BulkheadsCtx>());
+        }
+        if let Some(voyage) = &self.voyage {
+            ctx = write(ctx, VoyageCtx { voyage: voyage.clone() })?;
+            changed.push(CtxType::of::<VoyageCtx>());
+        }
+        Ok((ctx, changed))
+    }
+    ///
+    /// Applies the changes to the empty loading of the full calculation
+    /// - Returns the context with all loading types written, the voyage is [Voyage::default] if not changed
+    pub fn apply_full(&self, ctx: Context) -> Result<Context, EvalError> {
+        let ctx = write(ctx, CargoesCtx::default())?;
+        let ctx = write(ctx, ContainersCtx::default())?;
+        let ctx = write(ctx, CompartmentsCtx::default())?;
+        let ctx = write(ctx, BulkheadsCtx::default())?;
+        let ctx = write(ctx, VoyageCtx::default())?;
+        let (ctx, _) = self.apply(ctx)?;
+        Ok(ctx)
+    }
+}
+///
+/// Writes the value into the context
+fn write<T: StepCtx>(ctx: Context, value: T) -> Result<Context, EvalError> {
+    ctx.write(value).ok_or_else(|| EvalError::internal("ChangeSet.apply | Write context error: no result"))
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use crate::algorithm::{
+    context::ctx_store::StepCtx,
+    entities::{
+        load::{DeckLoadCheck, StowageViolation},
+        loads::{Bulkhead, CompartmentData, Container, LoadCargo},
+        voyage::Voyage,
+    },
+};
+///
+/// Грузы без привязки к помещениям
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CargoesCtx {
+    pub cargoes: Vec<LoadCargo>,
+}
+//
+//
+impl StepCtx for CargoesCtx {
+    const STEP: &'static str = "Loading";
+}
+///
+/// Контейнеры в ячейках
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ContainersCtx {
+    pub containers: Vec<Container>,
+}
+//
+//
+impl StepCtx for ContainersCtx {
+    const STEP: &'static str = "Loading";
+}
+///
+/// Помещения судна: цистерны и трюмы
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CompartmentsCtx {
+    pub compartments: Vec<CompartmentData>,
+}
+//
+//
+impl StepCtx for CompartmentsCtx {
+    const STEP: &'static str = "Loading";
+}
+///
+/// Зерновые перегородки, съемные перегородки
+/// установлены в одно из мест в трюме
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BulkheadsCtx {
+    pub bulkheads: Vec<Bulkhead>,
+}
+//
+//
+impl StepCtx for BulkheadsCtx {
+    const STEP: &'static str = "Loading";
+}
+///
+/// Параметры рейса
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct VoyageCtx {
+    pub voyage: Voyage,
+}
+//
+//
+impl StepCtx for VoyageCtx {
+    const STEP: &'static str = "Loading";
+}
+///
+/// Нагрузка судна: масса, центр масс и распределение массы по шпациям
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct LoadingCtx {
+    /// Масса судна, т
+    pub mass: f64,
+    /// Отстояние центра масс, м
+    pub shift_x: f64,
+    pub shift_y: f64,
+    pub shift_z: f64,
+    /// Момент свободной поверхности жидких грузов: поперечный и продольный, т·м
+    pub surface_moment_x: f64,
+    pub surface_moment_y: f64,
+    /// Кренящий момент от смещения навалочных грузов при качке, т·м
+    pub bulk_moment: f64,
+    /// Площадь парусности палубных грузов, м^2
+    pub windage_area: f64,
+    /// Статический момент площади парусности палубных грузов по вертикали, м^3
+    pub windage_moment_z: f64,
+    /// Верхние точки палубных грузов: (наибольший x, м; наибольший z, м)
+    pub deck_tops: Vec<(f64, f64)>,
+    /// Шпации распределения массы: (начало, конец), м
+    pub frames: Vec<(f64, f64)>,
+    /// Масса по шпациям, т
+    pub frame_mass: Vec<f64>,
+    /// Поперечный статический момент масс по шпациям, т·м
+    pub frame_moment_y: Vec<f64>,
+    /// Местная нагрузка от грузов на зоны палуб и настила второго дна
+    pub deck_loads: Vec<DeckLoadCheck>,
+    /// Нарушения правил размещения контейнеров
+    pub stowage: Vec<StowageViolation>,
+}
+//
+//
+impl StepCtx for LoadingCtx {
+    const STEP: &'static str = "Loading";
+    const RESULT: bool = true;
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, rc::Rc};
+use futures::future::BoxFuture;
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::{ContextReadRef, ContextWrite}, ctx_result::CtxResult, ctx_store::CtxType},
+        entities::{
+            bulk_heel::BulkHeel,
+            load::{DeckLoad, Hold, ILoad, ILoadMass, LoadMass, Loads, MovableBulkhead, Soundings, Stowage},
+            loads::{CompartmentData, ContainerArray, LoadCargo},
+            math::{Bound, Bounds, FreeSurfaceMoment, Moment, Position},
+            stability::ship_type::ShipType,
+            visibility::Visibility,
+        },
+        initial::initial_ctx::InitialCtx,
+        parameters::parameters_ctx::ParametersCtx,
+        pipeline::step::Step,
+    },
+    kernel::{
+        dbgid::dbgid::DbgId, error::{error::Error, eval_error::{EvalError, EvalErrorKind}},
+        eval::Eval, types::eval_result::EvalResult,
+    },
+};
+use super::loading_ctx::{BulkheadsCtx, CargoesCtx, CompartmentsCtx, ContainersCtx, LoadingCtx, VoyageCtx};
+///
+/// # Loading of the ship
+/// - Evaluates the mass of the constant load, cargoes and compartments, its center and distribution by frames
+/// - Heeling moment of the bulk cargoes shifted by the roll amplitude of the voyage
+/// - Windage area of the deck cargoes and its vertical moment,
+///   the top points of the deck cargoes for the visibility from the bridge
+/// - Tank masses are evaluated by the soundings, if the calibration table of the tank is known,
+///   the trim and heel at the soundings are taken from the voyage
+/// - Movable bulkheads are placed in the holds, the bounds of the hold parts
+///   are updated in the compartments by the capacity curves of the holds
+/// - Containers are loaded as cargoes, its stowage is checked by the mixing rules
+///   and by the allowed stack masses, if the limits are known
+/// - Masses and its transverse moments are distributed by the frames from the database or by the number of frames
+///   configured in the [ParametersCtx]
+/// - Cargoes are checked by the allowed loads of the deck and tank top zones, if the zones are known
+/// - Evaluated again, when the loading is changed by the [ChangeSet](super::change_set::ChangeSet)
+pub struct Loading {
+    dbg: DbgId,
+}
+//
+//
+impl Loading {
+    ///
+    /// New instance
+    pub fn new(parent: impl Into<String>) -> Self {
+        let dbg = DbgId::with_parent(&DbgId(parent.into()), "Loading");
+        Self { dbg }
+    }
+}
+//
+//
+impl Eval<Context, EvalResult> for Loading {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            match loading(&ctx) {
+                Ok(value) => {
+                    value.deck_loads.iter().filter(|v| !v.is_ok()).for_each(|v| {
+                        log::warn!("{}.eval | Deck load exceeded: {:?}", self.dbg, v);
+                    });
+                    value.stowage.iter().for_each(|v| log::warn!("{}.eval | Stowage violation: {}", self.dbg, v));
+                    ctx.write(value)
+                }
+                Err(err) => CtxResult::Err(err.context(format!("{}.eval | Error", self.dbg))),
+            }
+        })
+    }
+}
+//
+//
+impl Step for Loading {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        vec![
+            CtxType::of::<InitialCtx>(),
+            CtxType::of::<CargoesCtx>(),
+            CtxType::of::<ContainersCtx>(),
+            CtxType::of::<CompartmentsCtx>(),
+            CtxType::of::<BulkheadsCtx>(),
+            CtxType::of::<VoyageCtx>(),
+            CtxType::of::<ParametersCtx>(),
+        ]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<LoadingCtx>()]
+    }
+}
+///
+/// Returns the loading of the ship stored in the `ctx`
+fn loading(ctx: &Context) -> Result<LoadingCtx, EvalError> {
+    let initial: &InitialCtx = ContextReadRef::read(ctx)?;
+    let cargoes: &CargoesCtx = ContextReadRef::read(ctx)?;
+    let containers: &ContainersCtx = ContextReadRef::read(ctx)?;
+    let compartments: &CompartmentsCtx = ContextReadRef::read(ctx)?;
+    let bulkheads: &BulkheadsCtx = ContextReadRef::read(ctx)?;
+    let voyage: &VoyageCtx = ContextReadRef::read(ctx)?;
+    let parameters: &ParametersCtx = ContextReadRef::read(ctx)?;
+    let (mut cargoes, compartments) = holds(initial, bulkheads, cargoes, compartments)?;
+    let stowage = Stowage::new(&containers.containers)?;
+    let mut violations = stowage.check_mixing();
+    if let Some(limits) = &initial.container_stack_limits {
+        violations.extend(stowage.check_stack_mass(limits));
+    }
+    cargoes.extend(ContainerArray { data: containers.containers.clone(), error: HashMap::new() }.data());
+    let load_constants = initial.load_constants.clone().unwrap_or_default();
+    let soundings = match &initial.sounding_tables {
+        Some(tables) => Soundings::from_data(
+            tables,
+            voyage.voyage.sounding_trim.unwrap_or(0.),
+            voyage.voyage.sounding_heel.unwrap_or(0.),
+        )?,
+        None => Soundings::default(),
+    };
+    let ship_type = match &initial.ship {
+        Some(ship) => ShipType::from_str(&ship.ship_type)?,
+        None => ShipType::Other,
+    };
+    let loads = Loads::new(
+        &load_constants,
+        shift_const(initial, !load_constants.is_empty())?,
+        &cargoes,
+        &compartments,
+        &soundings,
+        ship_type,
+    );
+    let masses: Vec<Rc<LoadMass>> = loads.loads_const()?.iter()
+        .chain(loads.load_variable()?.iter())
+        .cloned()
+        .collect();
+    let mass: f64 = masses.iter().map(|v| v.mass()).sum();
+    let shift = masses.iter().map(|v| v.moment()).sum::<Moment>().to_pos(mass);
+    let surface_moment: FreeSurfaceMoment = loads.tanks()?.iter().map(|v| v.moment_surface()).sum();
+    let bulk_moment = BulkHeel::moment(&loads.bulks()?, voyage.voyage.roll_amplitude.unwrap_or(BulkHeel::ROLL));
+    let desks = loads.desks()?;
+    let mut windage = (0., 0.);
+    for desk in desks.iter() {
+        windage.0 += desk.windage_area(&Bound::Full, &Bound::Full)?;
+        windage.1 += desk.windage_moment()?.z();
+    }
+    let frames = frames(initial, parameters)?;
+    let frame_mass = frames.iter()
+        .map(|&(start, end)| {
+            let bound = Bound::new(start, end)?;
+            masses.iter().map(|v| v.value(&bound)).sum::<Result<f64, Error>>()
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let frame_moment_y = frames.iter()
+        .map(|&(start, end)| {
+            let bound = Bound::new(start, end)?;
+            masses.iter().map(|v| Ok(v.value(&bound)? * v.shift().y())).sum::<Result<f64, Error>>()
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let deck_loads = match &initial.deck_zones {
+        Some(zones) => DeckLoad::new(zones).check(&cargoes, &compartments)?,
+        None => vec![],
+    };
+    Ok(LoadingCtx {
+        mass,
+        shift_x: shift.x(),
+        shift_y: shift.y(),
+        shift_z: shift.z(),
+        surface_moment_x: surface_moment.x(),
+        surface_moment_y: surface_moment.y(),
+        bulk_moment,
+        windage_area: windage.0,
+        windage_moment_z: windage.1,
+        deck_tops: Visibility::tops(&desks),
+        frames,
+        frame_mass,
+        frame_moment_y,
+        deck_loads,
+        stowage: violations,
+    })
+}
+///
+/// Returns the cargoes with the bulkheads and the compartments with the hold parts
+/// bounded by the movable bulkheads in its current places
+fn holds(
+    initial: &InitialCtx,
+    bulkheads: &BulkheadsCtx,
+    cargoes: &CargoesCtx,
+    compartments: &CompartmentsCtx,
+) -> Result<(Vec<LoadCargo>, Vec<CompartmentData>), EvalError> {
+    let mut cargoes = cargoes.cargoes.clone();
+    let mut compartments = compartments.compartments.clone();
+    let (movable, fixed): (Vec<_>, Vec<_>) = bulkheads.bulkheads.iter()
+        .cloned()
+        .partition(|v| v.id.is_some() && v.position.is_some());
+    cargoes.extend(fixed.into_iter().map(LoadCargo::from));
+    if movable.is_empty() && compartments.iter().all(|v| v.hold_id.is_none()) {
+        return Ok((cargoes, compartments));
+    }
+    let Some(tables) = &initial.hold_tables else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "hold".to_owned(), ship_id: initial.ship_id },
+            "holds | No holds for the movable bulkheads and the hold parts".to_owned(),
+        ));
+    };
+    let movable = movable.into_iter()
+        .map(|v| MovableBulkhead::new(v, &tables.places))
+        .collect::<Result<Vec<_>, Error>>()?;
+    for data in &tables.holds {
+        let capacity = tables.capacity.get(&data.id).map(|v| v.as_slice()).unwrap_or_default();
+        Hold::new(data.clone(), capacity)?.update(&movable, &mut compartments)?;
+    }
+    cargoes.extend(movable.iter().map(|v| v.cargo()));
+    Ok((cargoes, compartments))
+}
+///
+/// Returns the center of the constant load from the ship parameters
+/// - 'required' - the constant load is present, so the center must be known
+fn shift_const(initial: &InitialCtx, required: bool) -> Result<Position, EvalError> {
+    let keys = ["LCG from middle", "TCG from CL", "VCG from BL"];
+    let values: Option<Vec<f64>> = keys.iter()
+        .map(|key| initial.ship_parameters.as_ref().and_then(|parameters| parameters.get(*key).copied()))
+        .collect();
+    match values {
+        Some(values) => Ok(Position::new(values[0], values[1], values[2])),
+        None if !required => Ok(Position::new(0., 0., 0.)),
+        None => Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "ship_parameters".to_owned(), ship_id: initial.ship_id },
+            format!("shift_const | No center of the constant load: {:?}", keys),
+        )),
+    }
+}
+///
+/// Returns the frames to distribute the masses by,
+/// the ship of the 'L.O.A' is split into the configured number of frames
+/// from the 'X midship from Fr0', see [Bounds::from_n]
+fn frames(initial: &InitialCtx, parameters: &ParametersCtx) -> Result<Vec<(f64, f64)>, EvalError> {
+    let Some(n) = parameters.frames else {
+        return Ok(initial.bounds.clone().unwrap_or_default());
+    };
+    let keys = ["L.O.A", "X midship from Fr0"];
+    let values: Option<Vec<f64>> = keys.iter()
+        .map(|key| initial.ship_parameters.as_ref().and_then(|parameters| parameters.get(*key).copied()))
+        .collect();
+    match values {
+        Some(values) => Ok(Bounds::from_n(values[0], values[1], n)?.iter()
+            .filter_map(|bound| Some((bound.start()?, bound.end()?)))
+            .collect()),
+        None => Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "ship_parameters".to_owned(), ship_id: initial.ship_id },
+            format!("frames | No length of the ship to split into {n} frames: {:?}", keys),
+        )),
+    }
+}
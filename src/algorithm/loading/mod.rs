@@ -0,0 +1,10 @@
+//! # Loading of the ship
+//!
+//! Cargoes, compartments and voyage parameters, edited by the user,
+//! the [change set](change_set::ChangeSet) to apply the edits incrementally,
+//! the [loading](stored_loading::StoredLoading) stored in the database
+//! and the [step](loading_step::Loading) evaluating the masses of the loading.
+pub mod change_set;
+pub mod loading_ctx;
+pub mod loading_step;
+pub mod stored_loading;
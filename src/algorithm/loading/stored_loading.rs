@@ -0,0 +1,111 @@
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::ContextReadRef},
+        entities::{loads::{BulkheadArray, CompartmentArray, ContainerArray, LoadCargoArray}, VoyageArray},
+        initial::{initial::fetch_optional, initial_ctx::InitialCtx},
+    },
+    infrostructure::api::client::api_client::ApiClient,
+    kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError},
+};
+use super::change_set::ChangeSet;
+///
+/// # Loading of the ship stored in the database
+/// - Fetched on the full calculation, the changes of the request are applied over it
+/// - Absent tables are left empty, the voyage is [Voyage::default](crate::algorithm::entities::voyage::Voyage) if not stored
+#[derive(Debug)]
+pub struct StoredLoading {
+    dbg: DbgId,
+    api_client: ApiClient,
+}
+//
+//
+impl StoredLoading {
+    ///
+    /// New instance
+    /// - 'api_client' - access to the database
+    pub fn new(parent: impl Into<String>, api_client: ApiClient) -> Self {
+        Self {
+            dbg: DbgId::with_parent(&DbgId(parent.into()), "StoredLoading"),
+            api_client,
+        }
+    }
+    ///
+    /// Returns the loading stored for the ship of the `ctx` as the changes of the empty loading
+    /// - The queries are cancelled, counted and recorded by the `ctx`
+    pub async fn fetch(&self, ctx: &Context) -> Result<ChangeSet, EvalError> {
+        let initial: &InitialCtx = ContextReadRef::read(ctx)?;
+        let ship_id = initial.ship_id;
+        let api_client = self.api_client
+            .with_cancel(ctx.cancel_token().clone())
+            .with_stats(ctx.query_stats().clone())
+            .with_replies(ctx.api_replies().clone());
+        tokio::task::spawn_blocking(move || changes(&api_client, ship_id))
+            .await
+            .map_err(|err| EvalError::internal(format!("Fetch task error: {err}")))?
+            .map_err(|err| err.context(format!("{}.fetch | Error", self.dbg)))
+    }
+}
+///
+/// Fetches the stored cargoes, containers, compartments, bulkheads and voyage of the ship
+fn changes(api_client: &ApiClient, ship_id: usize) -> Result<ChangeSet, EvalError> {
+    let cargoes = fetch_optional::<LoadCargoArray>(
+        api_client,
+        ship_id,
+        "cargo",
+        &format!(
+            "SELECT name, mass, general_category, timber, is_on_deck, container, bound_x1, bound_x2, bound_y1, bound_y2, bound_z1, bound_z2, \
+             mass_shift_x, mass_shift_y, mass_shift_z, horizontal_area, vertical_area, vertical_area_shift_x, vertical_area_shift_y, vertical_area_shift_z \
+             FROM cargo WHERE ship_id={};",
+            ship_id,
+        ),
+    )?;
+    let containers = fetch_optional::<ContainerArray>(
+        api_client,
+        ship_id,
+        "container",
+        &format!(
+            "SELECT id, mass, general_category, bound_x1, bound_x2, bound_y1, bound_y2, bound_z1, bound_z2, \
+             mass_shift_x, mass_shift_y, mass_shift_z, is_on_deck, bay, row, tier, iso_code, vgm FROM container WHERE ship_id={};",
+            ship_id,
+        ),
+    )?;
+    let compartments = fetch_optional::<CompartmentArray>(
+        api_client,
+        ship_id,
+        "compartment",
+        &format!(
+            "SELECT space_id, name, mass, density, volume, bound_x1, bound_x2, mass_shift_x, mass_shift_y, mass_shift_z, m_f_s_y, m_f_s_x, \
+             grain_moment, angle_of_repose, bulk_shift_y, general_category, matter_type, sounding, ullage, ullage_reference, \
+             temperature, density_15, vcf_table, vcf_alpha, hold_id, part_index FROM compartment WHERE ship_id={};",
+            ship_id,
+        ),
+    )?;
+    let bulkheads = fetch_optional::<BulkheadArray>(
+        api_client,
+        ship_id,
+        "bulkhead",
+        &format!(
+            "SELECT id, position, name, mass, general_category, bound_x1, bound_x2, mass_shift_x, mass_shift_y, mass_shift_z \
+             FROM bulkhead WHERE ship_id={};",
+            ship_id,
+        ),
+    )?;
+    let voyage = fetch_optional::<VoyageArray>(
+        api_client,
+        ship_id,
+        "voyage",
+        &format!(
+            "SELECT density, operational_speed, wetting_timber, icing_type, icing_timber_type, \
+             sounding_trim, sounding_heel, roll_amplitude, roll_period, water_depth, channel_width, ukc_required FROM voyage WHERE ship_id={};",
+            ship_id,
+        ),
+    )?;
+    Ok(ChangeSet {
+        cargoes: cargoes.map(|v| v.data()).unwrap_or_default(),
+        containers: containers.map(|v| v.data).unwrap_or_default(),
+        compartments: compartments.map(|v| v.data()).unwrap_or_default(),
+        bulkheads: bulkheads.map(|v| v.data).unwrap_or_default(),
+        voyage: voyage.and_then(|v| v.data.into_iter().next()),
+        ..ChangeSet::default()
+    })
+}
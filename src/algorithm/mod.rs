@@ -1,5 +1,10 @@
 pub mod context;
 pub mod entities;
 pub mod initial;
+pub mod loading;
+pub mod parameters;
+pub mod pipeline;
+pub mod stability;
+pub mod strength;
 
 pub mod areas_strength;
\ No newline at end of file
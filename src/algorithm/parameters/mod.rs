@@ -0,0 +1,2 @@
+pub mod parameters_ctx;
+pub mod parameters_step;
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use crate::algorithm::context::ctx_store::StepCtx;
+///
+/// Параметры расчета, задаются в конфигурации
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ParametersCtx {
+    /// Идентификаторы проверяемых критериев, None - все критерии
+    #[serde(default)]
+    pub criteria: Option<Vec<i32>>,
+    /// Учет обледенения по типу обледенения рейса в парусности
+    /// и крене от ветра, false - без обледенения
+    #[serde(default)]
+    pub icing: bool,
+    /// Количество шпаций для разбиения корпуса, см. Bounds::from_n,
+    /// None - разбиение из базы данных
+    #[serde(default)]
+    pub frames: Option<usize>,
+}
+//
+//
+impl ParametersCtx {
+    ///
+    /// Returns true if the criterion with `id` is enabled
+    pub fn is_enabled(&self, id: i32) -> bool {
+        self.criteria.as_ref().is_none_or(|criteria| criteria.contains(&id))
+    }
+}
+//
+//
+impl StepCtx for ParametersCtx {
+    const STEP: &'static str = "Parameters";
+}
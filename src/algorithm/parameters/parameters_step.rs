@@ -0,0 +1,48 @@
+use futures::future::BoxFuture;
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::ContextWrite, ctx_store::CtxType},
+        pipeline::step::Step,
+    },
+    kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult},
+};
+use super::parameters_ctx::ParametersCtx;
+///
+/// Writes the calculation parameters from the configuration into the context
+pub struct Parameters {
+    dbg: DbgId,
+    value: ParametersCtx,
+}
+//
+//
+impl Parameters {
+    ///
+    /// New instance
+    /// - 'value' - the calculation parameters
+    pub fn new(parent: impl Into<String>, value: ParametersCtx) -> Self {
+        let dbg = DbgId::with_parent(&DbgId(parent.into()), "Parameters");
+        Self { dbg, value }
+    }
+}
+//
+//
+impl Eval<Context, EvalResult> for Parameters {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            ctx.write(self.value.clone())
+        })
+    }
+}
+//
+//
+impl Step for Parameters {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        vec![]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<ParametersCtx>()]
+    }
+}
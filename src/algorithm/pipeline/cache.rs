@@ -0,0 +1,240 @@
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use futures::future::BoxFuture;
+use indexmap::IndexMap;
+use sal_sync::services::entity::error::str_err::StrErr;
+use serde::{de::DeserializeOwned, Serialize};
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::{ContextRead, ContextReadRef, ContextWrite}, ctx_result::CtxResult, ctx_store::{CtxType, StepCtx}},
+        initial::initial_ctx::InitialCtx,
+    },
+    kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, types::eval_result::EvalResult},
+};
+use super::step::Step;
+///
+/// FNV-1a hasher, the hash is stable between the runs and builds,
+/// so it can be used as the key of the persisted results
+#[derive(Debug, Clone)]
+pub struct StableHasher(u64);
+//
+//
+impl StableHasher {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    ///
+    /// New instance
+    pub fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+}
+//
+//
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+//
+//
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+///
+/// Step, which result can be memoized
+/// - The result is the single context type [Memo::Output]
+/// - The result depends only on the ship id, the ship data revision
+///   and the inputs written by [Memo::hash_inputs]
+/// - Without the ship data revision the result is not memoized,
+///   so the edits of the ship data are never served stale
+pub trait Memo: Step {
+    type Output: StepCtx + Clone + Serialize + DeserializeOwned;
+    ///
+    /// Writes the inputs of the step into the `state`, ship id and revision are written by the [Cached]
+    fn hash_inputs(&self, ctx: &Context, state: &mut StableHasher) -> Result<(), EvalError>;
+}
+///
+/// # Storage of the memoized step results
+/// - Results are stored in memory, shared between the clones,
+///   the least recently used result is evicted above the `capacity`
+/// - If `dir` is specified, results are persisted as `<dir>/<step>.<hash>.json`
+#[derive(Debug, Clone)]
+pub struct StepCache {
+    dir: Option<PathBuf>,
+    capacity: usize,
+    values: Arc<Mutex<IndexMap<String, String>>>,
+}
+//
+//
+impl StepCache {
+    ///
+    /// Default number of the results stored in memory
+    pub const CAPACITY: usize = 64;
+    ///
+    /// New instance
+    /// - 'dir' - directory to persist the results, for example `src/assets/cache`
+    pub fn new(dir: Option<impl AsRef<Path>>) -> Self {
+        Self {
+            dir: dir.map(|dir| dir.as_ref().to_path_buf()),
+            capacity: Self::CAPACITY,
+            values: Arc::new(Mutex::new(IndexMap::new())),
+        }
+    }
+    ///
+    /// Sets the number of the results stored in memory
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+    ///
+    /// Returns the key of the result
+    fn key(step: &str, hash: u64) -> String {
+        let step: String = step.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect();
+        format!("{}.{:016x}", step, hash)
+    }
+    ///
+    /// Returns the stored result, from memory or from the disk
+    pub fn get<T: DeserializeOwned>(&self, step: &str, hash: u64) -> Result<Option<T>, StrErr> {
+        let key = Self::key(step, hash);
+        let json = match self.values.lock() {
+            // the used result is moved to the end of the eviction order
+            Ok(mut values) => values.shift_remove(&key).inspect(|json| {
+                values.insert(key.clone(), json.clone());
+            }),
+            Err(err) => return Err(StrErr(format!("StepCache.get | Lock error: {err}"))),
+        };
+        let json = match (json, &self.dir) {
+            (Some(json), _) => json,
+            (None, Some(dir)) => match fs::read_to_string(dir.join(format!("{key}.json"))) {
+                Ok(json) => json,
+                Err(_) => return Ok(None),
+            },
+            (None, None) => return Ok(None),
+        };
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|err| StrErr(format!("StepCache.get | Parse '{key}' error: {err}")))
+    }
+    ///
+    /// Stores the result in memory and on the disk
+    pub fn put<T: Serialize>(&self, step: &str, hash: u64, value: &T) -> Result<(), StrErr> {
+        let key = Self::key(step, hash);
+        let json = serde_json::to_string(value)
+            .map_err(|err| StrErr(format!("StepCache.put | Serialize '{key}' error: {err}")))?;
+        if let Some(dir) = &self.dir {
+            fs::create_dir_all(dir)
+                .and_then(|_| fs::write(dir.join(format!("{key}.json")), &json))
+                .map_err(|err| StrErr(format!("StepCache.put | Write '{key}' error: {err}")))?;
+        }
+        let mut values = self.values
+            .lock()
+            .map_err(|err| StrErr(format!("StepCache.put | Lock error: {err}")))?;
+        values.shift_remove(&key);
+        values.insert(key, json);
+        while values.len() > self.capacity {
+            values.shift_remove_index(0);
+        }
+        Ok(())
+    }
+}
+///
+/// # Memoizing wrapper of the [Memo] step
+/// - The wrapped step is skipped, if the result for the same inputs is stored in the [StepCache]
+/// - The wrapped step is evaluated without the cache, if the ship data revision is unknown
+/// - The cache is bypassed, if the database replies are recorded or replayed,
+///   so the bundle has the replies of all the queries and is evaluated on its own data
+pub struct Cached<S: Memo> {
+    step: S,
+    cache: StepCache,
+}
+//
+//
+impl<S: Memo> Cached<S> {
+    ///
+    /// New instance
+    /// - 'step' - the step to be memoized
+    /// - 'cache' - storage of the results
+    pub fn new(step: S, cache: StepCache) -> Self {
+        Self { step, cache }
+    }
+    ///
+    /// Returns hash of the step inputs, None if the ship data revision is unknown
+    fn hash(&self, ctx: &Context) -> Result<Option<u64>, EvalError> {
+        let initial: &InitialCtx = ContextReadRef::read(ctx)?;
+        let Some(revision) = initial.revision else {
+            return Ok(None);
+        };
+        let mut state = StableHasher::new();
+        initial.ship_id.hash(&mut state);
+        revision.hash(&mut state);
+        self.step.hash_inputs(ctx, &mut state)?;
+        Ok(Some(state.finish()))
+    }
+}
+//
+//
+impl<S: Memo> Eval<Context, EvalResult> for Cached<S> {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            if ctx.api_replies().is_active() {
+                return self.step.eval(ctx).await;
+            }
+            let dbg = self.step.dbg().to_string();
+            let step = S::Output::STEP;
+            let hash = match self.hash(&ctx) {
+                Ok(Some(hash)) => hash,
+                Ok(None) => {
+                    log::debug!("{}.eval | No ship data revision, cache skipped", dbg);
+                    return self.step.eval(ctx).await;
+                }
+                Err(err) => return CtxResult::Err(err.context(format!("{}.eval | Cache hash error", dbg))),
+            };
+            match self.cache.get::<S::Output>(step, hash) {
+                Ok(Some(value)) => {
+                    log::debug!("{}.eval | Cache hit: {:016x}", dbg, hash);
+                    return ctx.write(value);
+                }
+                Ok(None) => {}
+                Err(err) => log::warn!("{}.eval | Cache read error: {err}", dbg),
+            }
+            match self.step.eval(ctx).await {
+                CtxResult::Ok(ctx) => {
+                    let value: Result<S::Output, _> = ContextRead::read(&ctx);
+                    match value {
+                        Ok(value) => if let Err(err) = self.cache.put(step, hash, &value) {
+                            log::warn!("{}.eval | Cache write error: {err}", dbg);
+                        }
+                        Err(err) => return CtxResult::Err(err.context(format!("{}.eval | Cache result error", dbg))),
+                    }
+                    CtxResult::Ok(ctx)
+                }
+                result => result,
+            }
+        })
+    }
+}
+//
+//
+impl<S: Memo> Step for Cached<S> {
+    fn dbg(&self) -> &DbgId {
+        self.step.dbg()
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        self.step.reads()
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        self.step.writes()
+    }
+}
@@ -0,0 +1,260 @@
+use std::{any::Any, collections::{HashMap, HashSet}, panic::AssertUnwindSafe, time::Instant};
+use futures::{future::BoxFuture, FutureExt};
+use crate::{
+    algorithm::context::{context::Context, ctx_result::CtxResult, ctx_store::CtxType},
+    infrostructure::api::client::query_stats::QueryStats,
+    kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, types::eval_result::EvalResult},
+};
+use super::{step::Step, trace::{Outcome, Span, Trace}};
+///
+/// # Pipeline of the calculation steps
+/// - Steps are ordered by the declared reads and writes
+/// - Steps of the same wave are independent and evaluated concurrently on the tokio runtime
+/// - Evaluation stops on the first step returned `Err` or `None`
+/// - A panic in the step is returned as `Err`, the step is kept in the pipeline
+/// - Evaluation stops when the [cancel token](Context::cancel_token) of the context is cancelled,
+///   evaluating steps are abandoned
+/// - The [trace](Context::trace) of the evaluated steps is attached to the result
+pub struct Pipeline {
+    dbg: DbgId,
+    steps: Vec<Box<dyn Step>>,
+}
+//
+//
+impl Pipeline {
+    ///
+    /// New empty instance
+    pub fn new(parent: impl Into<String>) -> Self {
+        let dbg = DbgId::with_parent(&DbgId(parent.into()), "Pipeline");
+        Self {
+            dbg,
+            steps: vec![],
+        }
+    }
+    ///
+    /// Adds the step to the pipeline
+    pub fn step(mut self, step: impl Step + 'static) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+    ///
+    /// Returns the waves of the step indexes, the steps of each wave
+    /// depend only on the steps of the previous waves
+    /// - Error if a type is written by several steps
+    /// - Error if a type is read, but neither written by any step nor present in the `ctx`
+    /// - Error if the steps have cyclic dependencies
+    pub fn order(&self, ctx: &Context) -> Result<Vec<Vec<usize>>, EvalError> {
+        let mut writers: HashMap<CtxType, usize> = HashMap::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            for key in step.writes() {
+                if let Some(other) = writers.insert(key, index) {
+                    return Err(EvalError::internal(format!(
+                        "{}.order | '{}' is written by both '{}' and '{}'",
+                        self.dbg, key, self.steps[other].dbg(), step.dbg(),
+                    )));
+                }
+            }
+        }
+        let mut deps: Vec<Vec<usize>> = Vec::with_capacity(self.steps.len());
+        for (index, step) in self.steps.iter().enumerate() {
+            let mut step_deps = vec![];
+            for key in step.reads() {
+                match writers.get(&key) {
+                    Some(&writer) if writer != index => step_deps.push(writer),
+                    Some(_) => {}
+                    None if ctx.contains(&key) => {}
+                    None => return Err(EvalError::internal(format!(
+                        "{}.order | '{}' reads '{}', which is neither written by any step nor present in the context",
+                        self.dbg, step.dbg(), key,
+                    ))),
+                }
+            }
+            deps.push(step_deps);
+        }
+        let mut done = vec![false; self.steps.len()];
+        let mut waves = vec![];
+        while done.iter().any(|done| !done) {
+            let wave: Vec<usize> = (0..self.steps.len())
+                .filter(|&index| !done[index] && deps[index].iter().all(|&dep| done[dep]))
+                .collect();
+            if wave.is_empty() {
+                let names: Vec<String> = (0..self.steps.len())
+                    .filter(|&index| !done[index])
+                    .map(|index| self.steps[index].dbg().to_string())
+                    .collect();
+                return Err(EvalError::internal(format!("{}.order | cyclic dependencies between steps: {:?}", self.dbg, names)));
+            }
+            for &index in &wave {
+                done[index] = true;
+            }
+            waves.push(wave);
+        }
+        Ok(waves)
+    }
+    ///
+    /// Returns the waves of the steps affected by the `changed` context types
+    /// - A step is affected if it reads a changed type or a type written by an affected step
+    pub fn affected(&self, ctx: &Context, changed: &[CtxType]) -> Result<Vec<Vec<usize>>, EvalError> {
+        let mut dirty: HashSet<CtxType> = changed.iter().copied().collect();
+        let mut waves = vec![];
+        for wave in self.order(ctx)? {
+            let wave: Vec<usize> = wave
+                .into_iter()
+                .filter(|&index| self.steps[index].reads().iter().any(|key| dirty.contains(key)))
+                .collect();
+            for &index in &wave {
+                dirty.extend(self.steps[index].writes());
+            }
+            if !wave.is_empty() {
+                waves.push(wave);
+            }
+        }
+        Ok(waves)
+    }
+    ///
+    /// Evaluates only the steps affected by the `changed` context types,
+    /// results of the other steps are kept from the `ctx`
+    pub async fn eval_changes(&mut self, ctx: Context, changed: &[CtxType]) -> EvalResult {
+        match self.affected(&ctx, changed) {
+            Ok(waves) => self.eval_waves(ctx, waves).await,
+            Err(err) => CtxResult::Err(err),
+        }
+    }
+    ///
+    /// Evaluates the `waves` of the steps one by one
+    async fn eval_waves(&mut self, ctx: Context, waves: Vec<Vec<usize>>) -> EvalResult {
+        let names: Vec<String> = self.steps.iter().map(|step| step.dbg().to_string()).collect();
+        let mut slots: Vec<Option<Box<dyn Step>>> = self.steps.drain(..).map(Some).collect();
+        let mut ctx = ctx;
+        let mut trace = Trace::default();
+        let origin = Instant::now();
+        let mut outcome = CtxResult::Ok(());
+        for wave in waves {
+            if ctx.cancel_token().is_cancelled() {
+                outcome = CtxResult::Err(EvalError::cancelled(format!("{}.eval | Cancelled", self.dbg)));
+                break;
+            }
+            log::debug!("{}.eval | wave: {:?}", self.dbg, wave.iter().map(|&index| &names[index]).collect::<Vec<_>>());
+            outcome = eval_wave(&self.dbg, &mut slots, &wave, &mut ctx, origin, &mut trace).await;
+            if !matches!(outcome, CtxResult::Ok(_)) {
+                break;
+            }
+        }
+        trace.push(Span {
+            name: self.dbg.to_string(),
+            lane: 0,
+            start_us: 0,
+            duration_us: origin.elapsed().as_micros() as u64,
+            queries: trace.spans.iter().map(|span| span.queries).sum(),
+            rows: trace.spans.iter().map(|span| span.rows).sum(),
+            outcome: Outcome::of(&outcome),
+        });
+        // a step is lost only if its task is aborted by the runtime
+        self.steps = slots.into_iter().flatten().collect();
+        match outcome {
+            CtxResult::Ok(_) => CtxResult::Ok(ctx.with_trace(trace)),
+            CtxResult::Err(err) => CtxResult::Err(err),
+            CtxResult::None => CtxResult::None,
+        }
+    }
+}
+//
+//
+impl Eval<Context, EvalResult> for Pipeline {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            match self.order(&ctx) {
+                Ok(waves) => self.eval_waves(ctx, waves).await,
+                Err(err) => CtxResult::Err(err),
+            }
+        })
+    }
+}
+//
+//
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("dbg", &self.dbg)
+            .field("steps", &self.steps.iter().map(|step| step.dbg().to_string()).collect::<Vec<_>>())
+            .finish()
+    }
+}
+///
+/// Evaluates the steps of the wave concurrently, merges written types into the `ctx`
+/// - 'slots' - the steps, moved into the evaluating tasks and returned back
+/// - 'origin' - start of the pipeline evaluation, spans of the steps are added to the `trace`
+async fn eval_wave(
+    dbg: &DbgId,
+    slots: &mut [Option<Box<dyn Step>>],
+    wave: &[usize],
+    ctx: &mut Context,
+    origin: Instant,
+    trace: &mut Trace,
+) -> CtxResult<(), EvalError> {
+    let mut handles = vec![];
+    for &index in wave {
+        let Some(mut step) = slots[index].take() else {
+            return CtxResult::Err(EvalError::internal(format!("{}.eval | Step {} is already taken", dbg, index)));
+        };
+        let stats = QueryStats::new();
+        let input = ctx.clone().with_query_stats(stats.clone());
+        let cancel = ctx.cancel_token().clone();
+        handles.push((index, tokio::spawn(async move {
+            let start = origin.elapsed();
+            // the step future is dropped on cancellation or panic, the step itself is kept
+            let eval = AssertUnwindSafe(step.eval(input)).catch_unwind();
+            let result = tokio::select! {
+                result = eval => result.unwrap_or_else(|panic| {
+                    CtxResult::Err(EvalError::internal(format!("Panicked: {}", panic_message(panic.as_ref()))))
+                }),
+                _ = cancel.cancelled() => CtxResult::Err(EvalError::cancelled("Cancelled")),
+            };
+            let span = Span {
+                name: step.dbg().to_string(),
+                lane: index + 1,
+                start_us: start.as_micros() as u64,
+                duration_us: (origin.elapsed() - start).as_micros() as u64,
+                queries: stats.queries(),
+                rows: stats.rows(),
+                outcome: Outcome::of(&result),
+            };
+            (step, result, span)
+        })));
+    }
+    let mut outcome = CtxResult::Ok(());
+    for (index, handle) in handles {
+        match handle.await {
+            Ok((step, result, span)) => {
+                log::debug!(
+                    "{}.eval | '{}' {:?} in {} μs, queries: {}, rows: {}",
+                    dbg, span.name, span.outcome, span.duration_us, span.queries, span.rows,
+                );
+                trace.push(span);
+                match result {
+                    CtxResult::Ok(result) => ctx.merge(&result, &step.writes()),
+                    CtxResult::Err(err) => if !matches!(outcome, CtxResult::Err(_)) {
+                        outcome = CtxResult::Err(err.context(format!("{}.eval | Step '{}' error", dbg, step.dbg())));
+                    }
+                    CtxResult::None => if matches!(outcome, CtxResult::Ok(_)) {
+                        outcome = CtxResult::None;
+                    }
+                }
+                slots[index] = Some(step);
+            }
+            Err(err) => {
+                outcome = CtxResult::Err(EvalError::internal(format!("{}.eval | Step {} task error: {}", dbg, index, err)));
+            }
+        }
+    }
+    outcome
+}
+///
+/// Returns the message of the panic payload
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
@@ -0,0 +1,11 @@
+//! # Pipeline of the calculation steps
+//!
+//! Each step declares the context types it reads and writes,
+//! the [Pipeline](executor::Pipeline) orders the steps and evaluates
+//! independent steps concurrently.
+pub mod cache;
+pub mod executor;
+pub mod pipeline_conf;
+pub mod session;
+pub mod step;
+pub mod trace;
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use crate::{
+    algorithm::{
+        areas_strength::areas_strength::AreasStrength,
+        initial::initial::Initial,
+        loading::{loading_step::Loading, stored_loading::StoredLoading},
+        parameters::{parameters_step::Parameters, parameters_ctx::ParametersCtx},
+        stability::stability_step::Stability,
+        strength::strength_step::Strength,
+    },
+    infrostructure::api::client::api_client::ApiClient,
+    ship_model::ship_model::ShipModel,
+};
+use super::{cache::{Cached, StepCache}, executor::Pipeline, session::EvalSession};
+///
+/// Step of the calculation pipeline in the configuration
+///
+/// ```yaml
+/// - step: initial
+/// - step: parameters
+///   criteria: [1, 2, 5]
+///   frames: 20
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum StepConf {
+    ///
+    /// Ship data from the database, see [Initial]
+    Initial,
+    ///
+    /// Strength areas by frames, see [AreasStrength]
+    AreasStrength,
+    ///
+    /// Masses of the loading, see [Loading]
+    Loading,
+    ///
+    /// Stability of the loading, see [Stability]
+    Stability,
+    ///
+    /// Longitudinal strength of the loading, see [Strength]
+    Strength,
+    ///
+    /// Calculation parameters, see [Parameters],
+    /// the default parameters are used if the step is not configured
+    Parameters(ParametersCtx),
+}
+///
+/// # Calculation pipeline in the configuration
+/// - 'steps' - steps of the calculation, ordered by the pipeline
+/// - 'cache' - directory to persist the memoized step results, see [StepCache]
+/// - 'cache_capacity' - number of the memoized step results kept in memory, [StepCache::CAPACITY] by default
+/// - 'trace' - file to save the trace of the evaluation in the Chrome trace format
+/// - 'bundle' - file to save the bundle of the evaluation to reproduce it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PipelineConf {
+    pub steps: Vec<StepConf>,
+    #[serde(default)]
+    pub cache: Option<PathBuf>,
+    #[serde(default)]
+    pub cache_capacity: Option<usize>,
+    #[serde(default)]
+    pub trace: Option<PathBuf>,
+    #[serde(default)]
+    pub bundle: Option<PathBuf>,
+}
+//
+//
+impl PipelineConf {
+    ///
+    /// Returns the pipeline of the configured steps,
+    /// the default [Parameters] step is added, if not configured
+    /// - 'api_client' - access to the database
+    /// - 'ship_model' - the model of the ship
+    pub async fn pipeline(&self, parent: impl Into<String>, api_client: &ApiClient, ship_model: &ShipModel) -> Pipeline {
+        let parent = parent.into();
+        let cache = StepCache::new(self.cache.as_ref()).with_capacity(self.cache_capacity.unwrap_or(StepCache::CAPACITY));
+        let mut pipeline = Pipeline::new(&parent);
+        if !self.steps.iter().any(|step| matches!(step, StepConf::Parameters(_))) {
+            pipeline = pipeline.step(Parameters::new(&parent, ParametersCtx::default()));
+        }
+        for step in &self.steps {
+            pipeline = match step {
+                StepConf::Initial => pipeline.step(Initial::new(&parent, api_client.clone())),
+                StepConf::AreasStrength => {
+                    let step = AreasStrength::new(&parent, ship_model.link().await);
+                    match self.cache {
+                        Some(_) => pipeline.step(Cached::new(step, cache.clone())),
+                        None => pipeline.step(step),
+                    }
+                }
+                StepConf::Loading => pipeline.step(Loading::new(&parent)),
+                StepConf::Stability => pipeline.step(Stability::new(&parent)),
+                StepConf::Strength => pipeline.step(Strength::new(&parent)),
+                StepConf::Parameters(value) => pipeline.step(Parameters::new(&parent, value.clone())),
+            };
+        }
+        pipeline
+    }
+    ///
+    /// Returns the session evaluating the configured pipeline, the full calculation starts from the stored loading
+    pub async fn session(&self, parent: impl Into<String>, api_client: &ApiClient, ship_model: &ShipModel) -> EvalSession {
+        let parent = parent.into();
+        let mut session = EvalSession::new(&parent, self.pipeline(&parent, api_client, ship_model).await)
+            .with_stored_loading(StoredLoading::new(&parent, api_client.clone()));
+        if let Some(path) = &self.trace {
+            session = session.with_chrome_trace(path);
+        }
+        if let Some(path) = &self.bundle {
+            session = session.with_bundle(path);
+        }
+        session
+    }
+}
+//
+//
+impl Default for PipelineConf {
+    ///
+    /// Ship data, strength areas, loading, stability and strength, results are not persisted
+    fn default() -> Self {
+        Self {
+            steps: vec![StepConf::Initial, StepConf::AreasStrength, StepConf::Loading, StepConf::Stability, StepConf::Strength],
+            cache: None,
+            cache_capacity: None,
+            trace: None,
+            bundle: None,
+        }
+    }
+}
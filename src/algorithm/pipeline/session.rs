@@ -0,0 +1,204 @@
+use std::{collections::HashMap, path::PathBuf, sync::{Mutex, MutexGuard, PoisonError}};
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::ContextReadRef, ctx_bundle::{CtxBundle, CtxRegistry}, ctx_diff::CtxDiff},
+        initial::initial_ctx::InitialCtx,
+        loading::stored_loading::StoredLoading,
+    },
+    infrostructure::{api::client::api_replies::ApiReplies, query::restart_eval::{RestartEvalQuery, RestartEvalReply}},
+    kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, sync::cancel_token::CancelToken},
+};
+use super::executor::Pipeline;
+///
+/// The pipeline and the context of the last successful calculation
+struct SessionState {
+    pipeline: Pipeline,
+    last: Option<Context>,
+}
+///
+/// # Calculation session of the client
+/// - Keeps the context of the last successful calculation
+/// - The full calculation starts from the [stored loading](StoredLoading) if configured, otherwise from the empty loading
+/// - [RestartEvalQuery] with changes for the same ship recalculates only the affected steps
+/// - [RestartEvalQuery] for the ship cancels the running calculation of the same ship,
+///   the cancelled request gets [RestartEvalReply::cancelled]
+/// - Requests are evaluated one by one, the session can be shared between the tasks
+/// - The trace of the evaluation is attached to the reply and optionally saved in the Chrome trace format
+/// - The [bundle](CtxBundle) of the evaluation is optionally saved, and can be [replayed](EvalSession::replay) without the database
+pub struct EvalSession {
+    dbg: DbgId,
+    state: tokio::sync::Mutex<SessionState>,
+    running: Mutex<HashMap<usize, CancelToken>>,
+    chrome_trace: Option<PathBuf>,
+    bundle: Option<PathBuf>,
+    loading: Option<StoredLoading>,
+}
+//
+//
+impl EvalSession {
+    ///
+    /// New instance
+    /// - 'pipeline' - steps of the calculation
+    pub fn new(parent: impl Into<String>, pipeline: Pipeline) -> Self {
+        Self {
+            dbg: DbgId::with_parent(&DbgId(parent.into()), "EvalSession"),
+            state: tokio::sync::Mutex::new(SessionState { pipeline, last: None }),
+            running: Mutex::new(HashMap::new()),
+            chrome_trace: None,
+            bundle: None,
+            loading: None,
+        }
+    }
+    ///
+    /// Returns the session saving the trace of each evaluation in the Chrome trace format into the `path`
+    pub fn with_chrome_trace(self, path: impl Into<PathBuf>) -> Self {
+        Self {
+            chrome_trace: Some(path.into()),
+            ..self
+        }
+    }
+    ///
+    /// Returns the session recording the database replies and saving the [CtxBundle] of each evaluation into the `path`
+    pub fn with_bundle(self, path: impl Into<PathBuf>) -> Self {
+        Self {
+            bundle: Some(path.into()),
+            ..self
+        }
+    }
+    ///
+    /// Returns the session starting each full calculation from the `loading` stored in the database
+    pub fn with_stored_loading(self, loading: StoredLoading) -> Self {
+        Self {
+            loading: Some(loading),
+            ..self
+        }
+    }
+    ///
+    /// Returns the cancellation tokens of the running calculations by ship id
+    fn running(&self) -> MutexGuard<'_, HashMap<usize, CancelToken>> {
+        self.running.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    ///
+    /// Restarts the calculation
+    /// - Full calculation from the stored loading with the changes applied,
+    ///   if there is no previous calculation of the same ship or no changes
+    /// - Otherwise the changes are applied to the previous context and only the affected steps are evaluated
+    pub async fn restart(&self, query: &RestartEvalQuery) -> RestartEvalReply {
+        let cancel = CancelToken::new();
+        if let Some(prev) = self.running().insert(query.ship_id, cancel.clone()) {
+            log::debug!("{}.restart | Cancelling the running calculation of the ship {}", self.dbg, query.ship_id);
+            prev.cancel();
+        }
+        let mut state = self.state.lock().await;
+        let replies = match self.bundle {
+            Some(_) => ApiReplies::record(),
+            None => ApiReplies::new(),
+        };
+        let result = match cancel.is_cancelled() {
+            true => Err(EvalError::cancelled(format!("{}.restart | Cancelled before start", self.dbg))),
+            false => Self::eval(&self.dbg, &mut state, self.loading.as_ref(), query, cancel.clone(), replies).await,
+        };
+        {
+            let mut running = self.running();
+            if running.get(&query.ship_id).is_some_and(|token| token.same(&cancel)) {
+                running.remove(&query.ship_id);
+            }
+        }
+        if cancel.is_cancelled() {
+            log::debug!("{}.restart | Cancelled calculation of the ship {}", self.dbg, query.ship_id);
+            return RestartEvalReply::cancelled();
+        }
+        self.reply(&mut state, result)
+    }
+    ///
+    /// Evaluates the full pipeline from the `bundle`, the database queries are answered by the recorded replies
+    pub async fn replay(&self, bundle: &CtxBundle) -> RestartEvalReply {
+        let mut state = self.state.lock().await;
+        let result = match bundle.context(&CtxRegistry::default()) {
+            Ok(ctx) => state.pipeline.eval(ctx).await
+                .map(|ctx| (ctx, vec![]))
+                .ok_or_else(|| EvalError::internal(format!("{}.replay | Pipeline returned no result", self.dbg))),
+            Err(err) => Err(EvalError::from(err)),
+        };
+        self.reply(&mut state, result)
+    }
+    ///
+    /// Returns the reply to the evaluation `result`, keeps the successfully evaluated context
+    fn reply(&self, state: &mut SessionState, result: Result<(Context, Vec<CtxDiff>), EvalError>) -> RestartEvalReply {
+        let mut reply = RestartEvalReply::new(RestartEvalReply::DONE);
+        match result {
+            Ok((ctx, diff)) => {
+                match ctx.results_json() {
+                    Ok(results) => reply.results = results,
+                    Err(err) => {
+                        reply.answer = RestartEvalReply::ERROR;
+                        reply.error = Some(EvalError::from(err).context(format!("{}.restart | Results error", self.dbg)));
+                    }
+                }
+                reply.diff = diff;
+                reply.trace = ctx.trace().clone();
+                if let Some(path) = &self.chrome_trace {
+                    if let Err(err) = reply.trace.save_chrome(path) {
+                        log::warn!("{}.restart | {}", self.dbg, err);
+                    }
+                }
+                if let Some(path) = &self.bundle {
+                    if let Err(err) = CtxBundle::new(&ctx).and_then(|bundle| bundle.save(path)) {
+                        log::warn!("{}.restart | {}", self.dbg, err);
+                    }
+                }
+                state.last = Some(ctx);
+            }
+            Err(err) => {
+                log::warn!("{}.restart | Error: {}", self.dbg, err);
+                reply.answer = RestartEvalReply::ERROR;
+                reply.error = Some(err);
+            }
+        }
+        reply
+    }
+    ///
+    /// Evaluates the pipeline, returns the new context and its difference from the previous one
+    /// - 'loading' - the stored loading of the full calculation, empty if None
+    /// - 'replies' - records the database replies of the full calculation
+    async fn eval(
+        dbg: &DbgId,
+        state: &mut SessionState,
+        loading: Option<&StoredLoading>,
+        query: &RestartEvalQuery,
+        cancel: CancelToken,
+        replies: ApiReplies,
+    ) -> Result<(Context, Vec<CtxDiff>), EvalError> {
+        let changes = query.changes.clone().unwrap_or_default();
+        let prev = state.last.clone().filter(|ctx| {
+            let initial: Result<&InitialCtx, _> = ContextReadRef::read(ctx);
+            initial.is_ok_and(|initial| initial.ship_id == query.ship_id)
+        });
+        let result = match &prev {
+            Some(prev) if !changes.is_empty() => {
+                let (ctx, changed) = changes.apply(prev.clone().with_cancel(cancel))?;
+                log::debug!("{}.eval | Changed: {:?}", dbg, changed);
+                state.pipeline.eval_changes(ctx, &changed).await
+            }
+            _ => {
+                let ctx = Context::new(InitialCtx::new(query.ship_id))
+                    .with_cancel(cancel)
+                    .with_api_replies(replies);
+                let ctx = match loading {
+                    Some(loading) => {
+                        let ctx = loading.fetch(&ctx).await?.apply_full(ctx)?;
+                        changes.apply(ctx)?.0
+                    }
+                    None => changes.apply_full(ctx)?,
+                };
+                state.pipeline.eval(ctx).await
+            }
+        };
+        let ctx = result.ok_or_else(|| EvalError::internal(format!("{}.eval | Pipeline returned no result", dbg)))?;
+        let diff = match &prev {
+            Some(prev) => CtxDiff::between(prev, &ctx).map_err(|err| EvalError::from(err).context(format!("{}.eval | Diff error", dbg)))?,
+            None => vec![],
+        };
+        Ok((ctx, diff))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{
+    algorithm::context::{context::Context, ctx_store::CtxType},
+    kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult},
+};
+///
+/// # Calculation step of the [Pipeline](super::executor::Pipeline)
+/// - Evaluates on the copy of the [Context], containing all types it reads
+/// - Only the types declared in [Step::writes] are taken from the result
+pub trait Step: Eval<Context, EvalResult> + Send {
+    ///
+    /// Identifier of the step for the logs and errors
+    fn dbg(&self) -> &DbgId;
+    ///
+    /// Context types, which the step reads
+    fn reads(&self) -> Vec<CtxType>;
+    ///
+    /// Context types, which the step writes
+    fn writes(&self) -> Vec<CtxType>;
+}
@@ -0,0 +1,90 @@
+use std::{fs, path::Path};
+use sal_sync::services::entity::error::str_err::StrErr;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use crate::algorithm::context::ctx_result::CtxResult;
+///
+/// Outcome of the step evaluation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum Outcome {
+    Ok,
+    Err,
+    None,
+}
+//
+//
+impl Outcome {
+    ///
+    /// Returns the outcome of the `result`
+    pub fn of<T, E>(result: &CtxResult<T, E>) -> Self {
+        match result {
+            CtxResult::Ok(_) => Outcome::Ok,
+            CtxResult::Err(_) => Outcome::Err,
+            CtxResult::None => Outcome::None,
+        }
+    }
+}
+///
+/// Evaluation of the single step
+/// - 'name' - the step name, from its `DbgId`
+/// - 'lane' - 0 for the whole pipeline, index of the step + 1 for the steps
+/// - 'start_us', 'duration_us' - time since the start of the pipeline and duration, μs
+/// - 'queries', 'rows' - number of the `ApiClient` queries and received rows
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Span {
+    pub name: String,
+    pub lane: usize,
+    pub start_us: u64,
+    pub duration_us: u64,
+    pub queries: usize,
+    pub rows: usize,
+    pub outcome: Outcome,
+}
+///
+/// # Trace of the pipeline evaluation
+/// - Spans of the evaluated steps and of the whole pipeline
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Trace {
+    pub spans: Vec<Span>,
+}
+//
+//
+impl Trace {
+    ///
+    /// Adds the span
+    pub fn push(&mut self, span: Span) {
+        self.spans.push(span);
+    }
+    ///
+    /// Returns the trace in the Chrome trace event format,
+    /// can be opened in `chrome://tracing` or Perfetto
+    pub fn to_chrome(&self) -> serde_json::Value {
+        let events: Vec<serde_json::Value> = self.spans
+            .iter()
+            .map(|span| json!({
+                "name": span.name,
+                "cat": if span.lane == 0 { "pipeline" } else { "step" },
+                "ph": "X",
+                "ts": span.start_us,
+                "dur": span.duration_us,
+                "pid": 1,
+                "tid": span.lane,
+                "args": {
+                    "queries": span.queries,
+                    "rows": span.rows,
+                    "outcome": span.outcome,
+                },
+            }))
+            .collect();
+        json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+    }
+    ///
+    /// Writes the trace in the Chrome trace event format into the file
+    pub fn save_chrome(&self, path: impl AsRef<Path>) -> Result<(), StrErr> {
+        let path = path.as_ref();
+        serde_json::to_string_pretty(&self.to_chrome())
+            .map_err(|err| err.to_string())
+            .and_then(|json| fs::write(path, json).map_err(|err| err.to_string()))
+            .map_err(|err| StrErr(format!("Trace.save_chrome | Write '{}' error: {}", path.display(), err)))
+    }
+}
@@ -0,0 +1,6 @@
+//! # Stability of the ship
+//!
+//! The [step](stability_step::Stability) evaluating the draught, the metacentric height
+//! and the righting lever curve of the loading by the hydrostatic tables on even keel.
+pub mod stability_ctx;
+pub mod stability_step;
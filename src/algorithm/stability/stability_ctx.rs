@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::algorithm::{
+    context::ctx_store::StepCtx,
+    entities::{
+        bulk_heel::BulkHeelCheck, roll_period::RollPeriodData, squat::SquatData, stability::CriterionResult,
+        visibility::VisibilityCheck, wind_heel::WindHeelCheck,
+    },
+};
+///
+/// Остойчивость судна на ровный киль
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StabilityCtx {
+    /// Водоизмещение, т
+    pub displacement: f64,
+    /// Объемное водоизмещение, м^3
+    pub volume: f64,
+    /// Средняя осадка, м
+    pub draught: f64,
+    /// Метацентрическая высота с поправкой на свободные поверхности, м
+    pub gm: f64,
+    /// Диаграмма статической остойчивости: (крен, градус; плечо, м)
+    pub gz: Vec<(f64, f64)>,
+    /// Крен от смещения навалочных грузов
+    pub bulk_heel: Option<BulkHeelCheck>,
+    /// Крен от постоянного ветра района плавания,
+    /// None - парусность корпуса или район плавания неизвестны
+    pub wind_heel: Option<WindHeelCheck>,
+    /// Период собственных бортовых колебаний по метацентрической высоте,
+    /// None - метацентрическая высота не положительна
+    pub roll_period: Option<RollPeriodData>,
+    /// Метацентрическая высота по периоду качки, замеренному экипажем
+    pub roll_test: Option<RollPeriodData>,
+    /// Проседание и запас воды под килем на мелководье при эксплуатационной скорости,
+    /// None - глубина воды или размерения судна неизвестны
+    pub squat: Option<SquatData>,
+    /// Зона затенения впереди форштевня при обзоре с ходового мостика,
+    /// None - положение глаза наблюдателя или носовой оконечности неизвестно
+    pub visibility: Option<VisibilityCheck>,
+    /// Критерии остойчивости второго поколения, первый уровень уязвимости
+    pub criteria: Vec<CriterionResult>,
+}
+//
+//
+impl StepCtx for StabilityCtx {
+    const STEP: &'static str = "Stability";
+    const RESULT: bool = true;
+}
@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use futures::future::BoxFuture;
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::{ContextReadRef, ContextWrite}, ctx_result::CtxResult, ctx_store::CtxType},
+        entities::{
+            bulk_heel::BulkHeel, icing_stab::{IcingStab, IcingStabType}, roll_period::RollPeriod, squat::{Squat, SquatData},
+            second_generation::{DeadShip, ParametricRoll, PureLoss},
+            math::Position,
+            stability::{CriterionResult, HydrostaticTables, VerticalAreaArray},
+            visibility::{Visibility, VisibilityCheck},
+            wind_heel::WindHeel,
+            Voyage, KNOT,
+        },
+        initial::initial_ctx::InitialCtx,
+        loading::loading_ctx::{LoadingCtx, VoyageCtx},
+        parameters::parameters_ctx::ParametersCtx,
+        pipeline::step::Step,
+    },
+    kernel::{
+        dbgid::dbgid::DbgId, error::eval_error::{EvalError, EvalErrorKind},
+        eval::Eval, types::eval_result::EvalResult,
+    },
+};
+use super::stability_ctx::StabilityCtx;
+///
+/// # Stability of the loaded ship on even keel
+/// - The draught and the metacentric height by the hydrostatic tables,
+///   corrected by the free surface moments of the tanks
+/// - The righting lever curve by the pantocarens
+/// - The heel from the shift of the bulk cargoes, checked against the allowed angle
+/// - The steady heel under the wind of the navigation area, checked against the allowed angle,
+///   the windage of the hull is increased by the icing of the voyage, if enabled by the parameters,
+///   and the windage of the deck cargoes
+/// - The natural roll period by the metacentric height and the metacentric height
+///   by the roll period observed by the crew
+/// - The blind sector ahead of the bow from the bridge over the deck cargoes, checked against the allowed length
+/// - The squat and the under keel clearance at the operational speed in the shallow water of the voyage
+/// - The level 1 vulnerability to the pure loss of stability, the parametric roll
+///   and the dead ship stability, reported as the separate criteria, if enabled in the [ParametersCtx]
+pub struct Stability {
+    dbg: DbgId,
+}
+//
+//
+impl Stability {
+    ///
+    /// New instance
+    pub fn new(parent: impl Into<String>) -> Self {
+        let dbg = DbgId::with_parent(&DbgId(parent.into()), "Stability");
+        Self { dbg }
+    }
+}
+//
+//
+impl Eval<Context, EvalResult> for Stability {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            match stability(&ctx) {
+                Ok(value) => {
+                    if let Some(check) = value.bulk_heel.as_ref().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Bulk cargo shift heel exceeded: {:?}", self.dbg, check);
+                    }
+                    if let Some(check) = value.wind_heel.as_ref().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Wind heel exceeded: {:?}", self.dbg, check);
+                    }
+                    if let Some(check) = value.visibility.as_ref().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Blind sector exceeded: {:?}", self.dbg, check);
+                    }
+                    if let Some(check) = value.squat.as_ref().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Under keel clearance insufficient: {:?}", self.dbg, check);
+                    }
+                    for result in value.criteria.iter().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Criterion failed: {}", self.dbg, result);
+                    }
+                    if let Some(test) = &value.roll_test {
+                        log::debug!("{}.eval | Roll period test: {}, computed gm: {}", self.dbg, test, value.gm);
+                    }
+                    ctx.write(value)
+                }
+                Err(err) => CtxResult::Err(err.context(format!("{}.eval | Error", self.dbg))),
+            }
+        })
+    }
+}
+//
+//
+impl Step for Stability {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        vec![
+            CtxType::of::<InitialCtx>(),
+            CtxType::of::<LoadingCtx>(),
+            CtxType::of::<VoyageCtx>(),
+            CtxType::of::<ParametersCtx>(),
+        ]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<StabilityCtx>()]
+    }
+}
+///
+/// Returns the stability of the loading stored in the `ctx`
+fn stability(ctx: &Context) -> Result<StabilityCtx, EvalError> {
+    let initial: &InitialCtx = ContextReadRef::read(ctx)?;
+    let loading: &LoadingCtx = ContextReadRef::read(ctx)?;
+    let voyage: &VoyageCtx = ContextReadRef::read(ctx)?;
+    let parameters: &ParametersCtx = ContextReadRef::read(ctx)?;
+    let Some(tables) = &initial.hydrostatics else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "mean_draught".to_owned(), ship_id: initial.ship_id },
+            "stability | No hydrostatic tables".to_owned(),
+        ));
+    };
+    if loading.mass <= 0. {
+        return Err(EvalError::new(
+            EvalErrorKind::InvalidData { field: "mass".to_owned(), value: loading.mass.to_string() },
+            "stability | Displacement of the loading <= 0".to_owned(),
+        ));
+    }
+    let volume = loading.mass / voyage.voyage.density;
+    let draught = tables.draught(volume)?;
+    // аппликата центра тяжести с поправкой на свободные поверхности
+    let z_g = loading.shift_z + loading.surface_moment_x / loading.mass;
+    let gm = tables.km(volume)? - z_g;
+    let gz = tables.gz(draught, z_g, loading.shift_y)?;
+    let entry_angle = tables.entry_angle(draught)?;
+    let bulk_heel = match loading.bulk_moment {
+        moment if moment > 0. => Some(BulkHeel::new(loading.mass, moment)?.check(&gz, entry_angle)?),
+        _ => None,
+    };
+    let wind = wind(initial, loading, &voyage.voyage, parameters.icing, draught)?;
+    let wind_heel = match (&wind, &initial.ship) {
+        (Some(wind), Some(ship)) => {
+            let area = ship.navigation_area()?;
+            Some(wind.check(area.p_v, area.m, &gz, entry_angle)?)
+        }
+        _ => None,
+    };
+    let roll = roll_period(initial, tables, draught)?;
+    let roll_test = match (&roll, voyage.voyage.roll_period) {
+        (Some(roll), Some(period)) => Some(roll.h(period)?),
+        _ => None,
+    };
+    let roll_period = match &roll {
+        Some(roll) if gm > 0. => Some(roll.period(gm)?),
+        _ => None,
+    };
+    let visibility = visibility(initial, loading, draught)?;
+    let squat = squat(initial, &voyage.voyage, volume, draught)?;
+    let dead_ship = (wind.as_ref(), roll_period.as_ref().map(|v| v.period));
+    let mut criteria = second_generation(initial, tables, &voyage.voyage, (volume, draught), (z_g, gm), &gz, dead_ship)?;
+    criteria.retain(|result| parameters.is_enabled(result.id));
+    Ok(StabilityCtx {
+        displacement: loading.mass,
+        volume,
+        draught,
+        gm,
+        gz,
+        bulk_heel,
+        wind_heel,
+        roll_period,
+        roll_test,
+        visibility,
+        squat,
+        criteria,
+    })
+}
+///
+/// Returns the ship parameter by the `key`
+fn parameter(initial: &InitialCtx, key: &str) -> Option<f64> {
+    initial.ship_parameters.as_ref().and_then(|parameters| parameters.get(key).copied())
+}
+///
+/// Returns the heeling lever of the wind by the windage of the hull and the deck cargoes,
+/// None if the windage of the hull is unknown
+/// - 'with_icing' - the icing of the voyage is taken into account
+/// - 'draught' - the mean draught of the loading
+fn wind(
+    initial: &InitialCtx,
+    loading: &LoadingCtx,
+    voyage: &Voyage,
+    with_icing: bool,
+    draught: f64,
+) -> Result<Option<WindHeel>, EvalError> {
+    let Some(windage) = &initial.windage else {
+        return Ok(None);
+    };
+    let area_v = VerticalAreaArray { data: windage.clone(), error: HashMap::new() };
+    let deck = (loading.windage_area, loading.windage_moment_z);
+    Ok(Some(WindHeel::from_windage(draught, loading.mass, &area_v, &icing(initial, voyage, with_icing)?, deck)?))
+}
+///
+/// Returns the icing of the voyage by the icing coefficients of the ship,
+/// without the coefficients the windage is not increased, if there is no icing
+/// - 'enabled' - the icing is taken into account by the parameters of the calculation,
+///   otherwise the icing type of the voyage is ignored
+fn icing(initial: &InitialCtx, voyage: &Voyage, enabled: bool) -> Result<IcingStab, EvalError> {
+    let icing_type = match enabled {
+        true => IcingStabType::from_str(&voyage.icing_type)?,
+        false => IcingStabType::None,
+    };
+    let keys = [
+        "icing_m_timber", "icing_m_v_full", "icing_m_v_half", "icing_m_h_full", "icing_m_h_half",
+        "icing_coef_v_area_full", "icing_coef_v_area_half", "icing_coef_v_area_zero",
+        "icing_coef_v_moment_full", "icing_coef_v_moment_half", "icing_coef_v_moment_zero",
+    ];
+    let values: Option<Vec<f64>> = keys.iter()
+        .map(|key| initial.icing.as_ref().and_then(|icing| icing.get(*key).copied()))
+        .collect();
+    let v = match values {
+        Some(values) => values,
+        None if icing_type == IcingStabType::None => vec![0.; keys.len()],
+        None => return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "icing".to_owned(), ship_id: initial.ship_id },
+            format!("icing | No icing coefficients: {:?}", keys),
+        )),
+    };
+    Ok(IcingStab::new(icing_type, v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7], v[8], v[9], v[10]))
+}
+///
+/// Returns the blind sector ahead of the bow over the deck cargoes on even keel,
+/// None if the 'LBP' or the positions of the eye on the bridge and the top of the bow are unknown
+/// - 'draught' - the mean draught of the loading
+fn visibility(initial: &InitialCtx, loading: &LoadingCtx, draught: f64) -> Result<Option<VisibilityCheck>, EvalError> {
+    let keys = ["LBP", "X bridge eye from middle", "Z bridge eye from BL", "X bow from middle", "Z bow from BL"];
+    let Some(v) = keys.iter().map(|key| parameter(initial, key)).collect::<Option<Vec<f64>>>() else {
+        return Ok(None);
+    };
+    let visibility = Visibility::new(v[0], Position::new(v[1], 0., v[2]), Position::new(v[3], 0., v[4]), draught, draught)?;
+    Ok(Some(visibility.check_by_tops(&loading.deck_tops)?))
+}
+///
+/// Returns the squat and the under keel clearance at the operational speed,
+/// None if the water depth of the voyage or the ship parameters 'LBP' and 'MouldedBreadth' are unknown
+/// - 'volume', 'draught' - the displacement volume and the mean draught of the loading
+fn squat(initial: &InitialCtx, voyage: &Voyage, volume: f64, draught: f64) -> Result<Option<SquatData>, EvalError> {
+    let (Some(depth), Some(length), Some(breadth)) = (voyage.water_depth, parameter(initial, "LBP"), parameter(initial, "MouldedBreadth")) else {
+        return Ok(None);
+    };
+    let c_b = volume / (length * breadth * draught);
+    let squat = Squat::new(length, breadth, draught, c_b, depth, voyage.channel_width)?;
+    Ok(Some(squat.data(voyage.operational_speed * KNOT, voyage.ukc_required)?))
+}
+///
+/// Returns the natural roll period of the ship by the breadth and the inertia coefficient,
+/// None if the breadth is unknown
+/// - 'tables' - the inertia coefficient by the draught, if no table, the coefficient by the hull
+///   with the length by the waterline table or 'LBP'
+fn roll_period(initial: &InitialCtx, tables: &HydrostaticTables, draught: f64) -> Result<Option<RollPeriod>, EvalError> {
+    let Some(width) = parameter(initial, "MouldedBreadth") else {
+        return Ok(None);
+    };
+    if let Some(c) = tables.inertia_coefficient(draught)? {
+        return Ok(Some(RollPeriod::new(width, c)?));
+    }
+    let Some(length) = tables.waterline_length(draught)?.or_else(|| parameter(initial, "LBP")) else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "waterline_length".to_owned(), ship_id: initial.ship_id },
+            "roll_period | No inertia coefficient and no length of the ship".to_owned(),
+        ));
+    };
+    Ok(Some(RollPeriod::from_hull(width, draught, length)?))
+}
+///
+/// Returns the level 1 vulnerability checks of the second generation criteria,
+/// empty if the ship parameters 'LBP' and 'Summer draught' are unknown
+/// - 'voyage' - the operational speed in knots to apply the pure loss of stability
+/// - 'volume', 'draught' - the displacement volume and the mean draught of the loading
+/// - 'z_g' - the center of gravity corrected by the free surfaces
+/// - 'gm' - the parametric roll is checked if the 'MouldedBreadth', 'Moulded depth'
+///   and 'Midship section coefficient' are known and `gm` > 0
+/// - 'gz' - the righting lever curve
+/// - 'wind', 'period' - the heeling lever of the wind and the natural roll period,
+///   the dead ship stability is checked if both are known and the roll amplitude tables are present
+fn second_generation(
+    initial: &InitialCtx,
+    tables: &HydrostaticTables,
+    voyage: &Voyage,
+    (volume, draught): (f64, f64),
+    (z_g, gm): (f64, f64),
+    gz: &[(f64, f64)],
+    (wind, period): (Option<&WindHeel>, Option<f64>),
+) -> Result<Vec<CriterionResult>, EvalError> {
+    let (Some(length), Some(draught_full)) = (parameter(initial, "LBP"), parameter(initial, "Summer draught")) else {
+        return Ok(vec![]);
+    };
+    let (rad_trans, volume_curve) = tables.waterplane()?;
+    let mut results = vec![];
+    let pure_loss = PureLoss::new(length, draught, draught_full, tables.kb(volume)?, &rad_trans, &volume_curve)?;
+    if pure_loss.is_applicable(voyage.operational_speed * KNOT) {
+        results.push(pure_loss.check(z_g)?);
+    }
+    let hull = (parameter(initial, "MouldedBreadth"), parameter(initial, "Moulded depth"), parameter(initial, "Midship section coefficient"));
+    if let (Some(breadth), Some(moulded_depth), Some(c_m)) = hull {
+        if gm > 0. {
+            let keel_area = parameter(initial, "Keel area").unwrap_or(0.);
+            let roll = ParametricRoll::new(length, breadth, draught, draught_full, moulded_depth, c_m, keel_area, &rad_trans, &volume_curve)?;
+            results.push(roll.check(gm)?);
+        }
+    }
+    if let (Some(wind), Some(period), Some(breadth)) = (wind, period, hull.0) {
+        if let Some(amplitude) = roll_amplitude(initial, tables, (length, breadth), (volume, draught), z_g, period)? {
+            let flooding_angle = tables.flooding_angle(draught)?.unwrap_or(f64::INFINITY);
+            results.push(DeadShip::new(gz, wind, amplitude, flooding_angle)?.check()?);
+        }
+    }
+    Ok(results)
+}
+///
+/// Returns the roll amplitude by the multipliers X1, X2 and the coefficient k,
+/// None if the multipliers tables are absent
+/// - 'length', 'breadth' - the 'LBP' and the 'MouldedBreadth' of the ship
+/// - 'volume', 'draught' - the displacement volume and the mean draught of the loading
+/// - 'z_g' - the center of gravity corrected by the free surfaces
+/// - 'period' - the natural roll period
+/// - The coefficient k is 1 without the 'Keel area', otherwise the table is required
+fn roll_amplitude(
+    initial: &InitialCtx,
+    tables: &HydrostaticTables,
+    (length, breadth): (f64, f64),
+    (volume, draught): (f64, f64),
+    z_g: f64,
+    period: f64,
+) -> Result<Option<f64>, EvalError> {
+    let c_b = volume / (length * breadth * draught);
+    let (Some(x1), Some(x2)) = (tables.multipler_x1(breadth / draught)?, tables.multipler_x2(c_b)?) else {
+        return Ok(None);
+    };
+    let k = match parameter(initial, "Keel area") {
+        Some(keel_area) if keel_area > 0. => tables.coefficient_k(keel_area * 100. / (length * breadth))?.ok_or_else(|| EvalError::new(
+            EvalErrorKind::DataMissing { table: "coefficient_k".to_owned(), ship_id: initial.ship_id },
+            "roll_amplitude | No coefficient k for the keel area".to_owned(),
+        ))?,
+        _ => 1.,
+    };
+    Ok(Some(DeadShip::amplitude(k, x1, x2, z_g, draught, period)?))
+}
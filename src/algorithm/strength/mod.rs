@@ -0,0 +1,6 @@
+//! # Longitudinal strength of the hull
+//!
+//! The [step](strength_step::Strength) evaluating the still water bending moments and shear forces
+//! of the loading by the frames and the stresses in the sections of the hull girder.
+pub mod strength_ctx;
+pub mod strength_step;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use crate::algorithm::{context::ctx_store::StepCtx, entities::girder::{GirderCheck, StressData, TorsionCheck}};
+///
+/// Общая прочность корпуса
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct StrengthCtx {
+    /// Нагрузки на тихой воде в конце шпаций:
+    /// (отстояние сечения, м; изгибающий момент, т*м; перерезывающая сила, т)
+    pub still_water: Vec<(f64, f64, f64)>,
+    /// Напряжения в сечениях по шпангоутам, если известны моменты сопротивления
+    pub stress: Vec<StressData>,
+    /// Суммарные нагрузки на тихой воде и на волнении в конце шпаций,
+    /// проверенные по допускаемым для района плавания, если они заданы
+    pub girder: Vec<GirderCheck>,
+    /// Крутящие моменты в конце шпаций, проверенные по допускаемым, если они заданы
+    pub torsion: Vec<TorsionCheck>,
+}
+//
+//
+impl StepCtx for StrengthCtx {
+    const STEP: &'static str = "Strength";
+    const RESULT: bool = true;
+}
@@ -0,0 +1,236 @@
+use std::rc::Rc;
+use futures::future::BoxFuture;
+use crate::{
+    algorithm::{
+        context::{context::Context, context_access::{ContextReadRef, ContextWrite}, ctx_result::CtxResult, ctx_store::CtxType},
+        entities::{
+            girder::{GirderCheck, Stress, StressData, Torsion, TorsionCheck, WaveLoad},
+            load::{ILoadMass, LoadMass, LoadingType},
+            math::{Bound, Bounds, Curve, Curve2D, ICurve, ICurve2D, Position},
+            strength::{GirderLimitData, SectionModulusData},
+        },
+        initial::initial_ctx::InitialCtx,
+        loading::loading_ctx::{LoadingCtx, VoyageCtx},
+        pipeline::step::Step,
+        stability::stability_ctx::StabilityCtx,
+    },
+    kernel::{
+        dbgid::dbgid::DbgId, error::{error::Error, eval_error::{EvalError, EvalErrorKind}},
+        eval::Eval, types::eval_result::EvalResult,
+    },
+};
+use super::strength_ctx::StrengthCtx;
+///
+/// # Longitudinal strength of the loaded ship on even keel
+/// - The buoyancy of the frames by the Bonjean scale at the mean draught,
+///   balanced to the mass and the longitudinal center of the loading
+///   by the buoyancy changing linearly along the length, as on the trim
+/// - The still water bending moments and shear forces at the ends of the frames
+/// - The total still water and wave loads against the limits of the navigation area,
+///   if the limits are known
+/// - The torsional moments from the transverse moments of the masses by the frames
+///   against the allowable moments, if the limits are known
+/// - The normal and shear stresses against the allowable stresses of the steel grade,
+///   if the section moduli are known, in the sections at the ends of the frames from the database
+pub struct Strength {
+    dbg: DbgId,
+}
+//
+//
+impl Strength {
+    ///
+    /// New instance
+    pub fn new(parent: impl Into<String>) -> Self {
+        let dbg = DbgId::with_parent(&DbgId(parent.into()), "Strength");
+        Self { dbg }
+    }
+}
+//
+//
+impl Eval<Context, EvalResult> for Strength {
+    fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+        Box::pin(async move {
+            match strength(&ctx) {
+                Ok(value) => {
+                    for stress in value.stress.iter().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Allowable stress exceeded: {:?}", self.dbg, stress);
+                    }
+                    for check in value.girder.iter().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Allowable load exceeded: {:?}", self.dbg, check);
+                    }
+                    for check in value.torsion.iter().filter(|v| !v.is_ok()) {
+                        log::warn!("{}.eval | Allowable torsional moment exceeded: {:?}", self.dbg, check);
+                    }
+                    ctx.write(value)
+                }
+                Err(err) => CtxResult::Err(err.context(format!("{}.eval | Error", self.dbg))),
+            }
+        })
+    }
+}
+//
+//
+impl Step for Strength {
+    fn dbg(&self) -> &DbgId {
+        &self.dbg
+    }
+    fn reads(&self) -> Vec<CtxType> {
+        vec![
+            CtxType::of::<InitialCtx>(),
+            CtxType::of::<LoadingCtx>(),
+            CtxType::of::<StabilityCtx>(),
+            CtxType::of::<VoyageCtx>(),
+        ]
+    }
+    fn writes(&self) -> Vec<CtxType> {
+        vec![CtxType::of::<StrengthCtx>()]
+    }
+}
+///
+/// Returns the strength of the loading stored in the `ctx`
+fn strength(ctx: &Context) -> Result<StrengthCtx, EvalError> {
+    let initial: &InitialCtx = ContextReadRef::read(ctx)?;
+    let loading: &LoadingCtx = ContextReadRef::read(ctx)?;
+    let stability: &StabilityCtx = ContextReadRef::read(ctx)?;
+    let voyage: &VoyageCtx = ContextReadRef::read(ctx)?;
+    let Some(bonjean) = &initial.bonjean else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "bonjean_frame".to_owned(), ship_id: initial.ship_id },
+            "strength | No Bonjean scale".to_owned(),
+        ));
+    };
+    let still_water = still_water(bonjean, loading, stability.draught, voyage.voyage.density)?;
+    let stress = match &initial.section_moduli {
+        Some(sections) => stress(initial, &loading.frames, &still_water, sections)?,
+        None => vec![],
+    };
+    let girder = match &initial.girder_limits {
+        Some(limits) => girder(initial, &still_water, limits)?,
+        None => vec![],
+    };
+    let torsion = match &initial.torsion_limits {
+        Some(limits) => torsion(loading, limits)?,
+        None => vec![],
+    };
+    Ok(StrengthCtx { still_water, stress, girder, torsion })
+}
+///
+/// Returns the still water loads at the ends of the frames:
+/// (section, bending moment, positive on hogging, shear force)
+/// - 'bonjean' - the immersed areas of the frames by the draught
+/// - 'loading' - the masses by the frames
+/// - 'draught' - the mean draught of the loading
+/// - 'density' - the density of the water
+fn still_water(bonjean: &[(f64, Vec<(f64, f64)>)], loading: &LoadingCtx, draught: f64, density: f64) -> Result<Vec<(f64, f64, f64)>, EvalError> {
+    let areas = Curve2D::from_values_linear(bonjean)?;
+    let buoyancy = loading.frames.iter()
+        .map(|&(start, end)| Ok(areas.value((start + end) / 2., draught)?.value * (end - start) * density))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let total: f64 = buoyancy.iter().sum();
+    if total <= 0. {
+        return Err(EvalError::new(
+            EvalErrorKind::InvalidData { field: "bonjean".to_owned(), value: total.to_string() },
+            format!("still_water | Buoyancy of the frames <= 0 at the draught {draught}"),
+        ));
+    }
+    // выравнивание сил поддержания по массе судна на ровный киль
+    let scale = loading.frame_mass.iter().sum::<f64>() / total;
+    let mut loads: Vec<(f64, f64, f64)> = loading.frames.iter()
+        .zip(loading.frame_mass.iter().zip(buoyancy))
+        .map(|(&(start, end), (mass, buoyancy))| ((start + end) / 2., end - start, mass - buoyancy * scale))
+        .collect();
+    // невязка продольного момента уравновешивается линейной
+    // по длине добавкой сил поддержания с нулевой суммой
+    let length: f64 = loads.iter().map(|(_, length, _)| length).sum();
+    let center = loads.iter().map(|(center, length, _)| center * length).sum::<f64>() / length;
+    let inertia: f64 = loads.iter().map(|(x, length, _)| length * (x - center).powi(2)).sum();
+    if inertia > 0. {
+        let k = loads.iter().map(|(x, _, load)| load * x).sum::<f64>() / inertia;
+        for (x, length, load) in loads.iter_mut() {
+            *load -= k * *length * (*x - center);
+        }
+    }
+    Ok(loading.frames.iter().enumerate()
+        .map(|(i, &(_, end))| {
+            let shear = loads[..=i].iter().map(|(_, _, load)| load).sum();
+            let bending = loads[..=i].iter().map(|(x, _, load)| load * (end - x)).sum();
+            (end, bending, shear)
+        })
+        .collect())
+}
+///
+/// Returns the stresses in the sections at the ends of the frames from the database,
+/// the still water loads are interpolated at the sections,
+/// so the loads can be evaluated by the frames of another split
+/// - 'frames' - the frames of the still water loads
+/// - 'still_water' - the loads at the ends of the `frames`, see [still_water]
+/// - 'sections' - the section moduli by the index of the frame from the database
+fn stress(
+    initial: &InitialCtx,
+    frames: &[(f64, f64)],
+    still_water: &[(f64, f64, f64)],
+    sections: &[SectionModulusData],
+) -> Result<Vec<StressData>, EvalError> {
+    let bounds = initial.bounds.as_deref().unwrap_or_default();
+    let start = frames.first().map_or(0., |&(start, _)| start);
+    let points: Vec<(f64, f64, f64)> = std::iter::once((start, 0., 0.)).chain(still_water.iter().copied()).collect();
+    let bending = Curve::new_linear(&points.iter().map(|&(x, bending, _)| (x, bending)).collect::<Vec<_>>())?;
+    let shear = Curve::new_linear(&points.iter().map(|&(x, _, shear)| (x, shear)).collect::<Vec<_>>())?;
+    let loads = sections.iter()
+        .map(|section| {
+            let Some(&(_, x)) = usize::try_from(section.index).ok().and_then(|index| bounds.get(index)) else {
+                return Err(EvalError::new(
+                    EvalErrorKind::InvalidData { field: "section_modulus.index".to_owned(), value: section.index.to_string() },
+                    format!("stress | No frame {} of the section among {} frames", section.index, bounds.len()),
+                ));
+            };
+            Ok((bending.value(x)?, shear.value(x)?))
+        })
+        .collect::<Result<Vec<_>, EvalError>>()?;
+    Ok(Stress::new(sections.to_vec())?.table(&loads)?)
+}
+///
+/// Returns the total still water and wave loads at the ends of the frames,
+/// checked against the `limits` of the navigation area of the ship
+/// - The wave loads by the 'LBP', the 'MouldedBreadth' and the block coefficient at the 'Summer draught'
+/// - 'still_water' - the loads at the ends of the frames, see [still_water]
+fn girder(initial: &InitialCtx, still_water: &[(f64, f64, f64)], limits: &[GirderLimitData]) -> Result<Vec<GirderCheck>, EvalError> {
+    let Some(ship) = &initial.ship else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "ship".to_owned(), ship_id: initial.ship_id },
+            "girder | No navigation area of the ship".to_owned(),
+        ));
+    };
+    let keys = ["LBP", "MouldedBreadth", "Summer draught"];
+    let values: Option<Vec<f64>> = keys.iter()
+        .map(|key| initial.ship_parameters.as_ref().and_then(|parameters| parameters.get(*key).copied()))
+        .collect();
+    let (Some(values), Some(tables)) = (values, &initial.hydrostatics) else {
+        return Err(EvalError::new(
+            EvalErrorKind::DataMissing { table: "ship_parameters".to_owned(), ship_id: initial.ship_id },
+            format!("girder | No dimensions of the ship for the wave loads: {:?}", keys),
+        ));
+    };
+    let (length, breadth, draught) = (values[0], values[1], values[2]);
+    let (_, volume) = tables.waterplane()?;
+    let c_b = Curve::new_linear(&volume)?.value(draught)? / (length * breadth * draught);
+    let area = ship.navigation_area()?.area;
+    let wave = WaveLoad::from_area(length, breadth, c_b, area, initial.wave_reductions.as_deref().unwrap_or_default())?;
+    Ok(wave.check(still_water, area, limits)?)
+}
+///
+/// Returns the torsional moments at the ends of the frames checked against the `limits`,
+/// the mass of each frame is placed at the transverse center of the frame
+/// - 'limits' - the allowable moments: (section, moment)
+fn torsion(loading: &LoadingCtx, limits: &[(f64, f64)]) -> Result<Vec<TorsionCheck>, EvalError> {
+    let loads = loading.frames.iter()
+        .zip(loading.frame_mass.iter().zip(&loading.frame_moment_y))
+        .filter(|(_, (&mass, _))| mass > 0.)
+        .map(|(&(start, end), (&mass, &moment))| {
+            let shift = Position::new((start + end) / 2., moment / mass, 0.);
+            let load: Rc<dyn ILoadMass> = Rc::new(LoadMass::new(mass, Bound::new(start, end)?, Some(shift), LoadingType::Cargo)?);
+            Ok(load)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(Torsion::new(Bounds::from_frames(&loading.frames)?, loads).check(limits)?)
+}
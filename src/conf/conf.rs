@@ -2,11 +2,22 @@ use std::{fs::OpenOptions, path::Path};
 
 use serde::{Deserialize, Serialize};
 
+use crate::algorithm::pipeline::pipeline_conf::PipelineConf;
+
 use super::api_conf::ApiConf;
 
+///
+/// Application configuration
+/// - 'api' - the database connection
+/// - 'ship_id' - the ship to be calculated
+/// - 'pipeline' - steps of the calculation
 #[derive(Serialize, Deserialize)]
 pub struct Conf {
     pub api: ApiConf,
+    #[serde(default)]
+    pub ship_id: usize,
+    #[serde(default)]
+    pub pipeline: PipelineConf,
 }
 //
 //
@@ -16,9 +27,6 @@ impl Conf {
             .read(true)
             .open(path)
             .unwrap();
-        let api = serde_yaml::from_reader(file).unwrap();
-        Self {
-            api, 
-        }
+        serde_yaml::from_reader(file).unwrap()
     }
 }
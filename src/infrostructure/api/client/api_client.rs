@@ -1,6 +1,8 @@
 use api_tools::client::{api_query::{ApiQuery, ApiQueryKind, ApiQuerySql}, api_request::ApiRequest};
 
-use crate::kernel::error::error::Error;
+use serde::{de::IgnoredAny, Deserialize};
+use crate::kernel::{error::error::Error, sync::cancel_token::CancelToken};
+use super::{api_replies::ApiReplies, query_stats::QueryStats};
 
 ///
 /// Provides access to the API Server
@@ -9,6 +11,16 @@ pub struct ApiClient {
     database: String,
     host: String,
     port: String,
+    cancel: Option<CancelToken>,
+    stats: Option<QueryStats>,
+    replies: ApiReplies,
+}
+///
+/// Reply of the API Server, only the rows are counted
+#[derive(Deserialize)]
+struct Rows {
+    #[serde(default)]
+    data: Vec<IgnoredAny>,
 }
 //
 impl ApiClient {
@@ -17,10 +29,46 @@ impl ApiClient {
             database,
             host,
             port,
+            cancel: None,
+            stats: None,
+            replies: ApiReplies::new(),
+        }
+    }
+    ///
+    /// Returns the copy of the client, which fetches are abandoned after the `cancel` token is cancelled
+    pub fn with_cancel(&self, cancel: CancelToken) -> Self {
+        Self {
+            cancel: Some(cancel),
+            ..self.clone()
+        }
+    }
+    ///
+    /// Returns the copy of the client, which queries and received rows are counted in the `stats`
+    pub fn with_stats(&self, stats: QueryStats) -> Self {
+        Self {
+            stats: Some(stats),
+            ..self.clone()
+        }
+    }
+    ///
+    /// Returns the copy of the client, which records or replays the `replies`
+    pub fn with_replies(&self, replies: ApiReplies) -> Self {
+        Self {
+            replies,
+            ..self.clone()
         }
     }
     //
     pub fn fetch(&self, sql: &str) -> Result<Vec<u8>, Error> {
+        if self.cancel.as_ref().is_some_and(|cancel| cancel.is_cancelled()) {
+            return Err(Error::Cancelled("ApiServer fetch cancelled".to_owned()));
+        }
+        if self.replies.is_replay() {
+            return match self.replies.get(sql) {
+                Some(reply) => Ok(reply.into_bytes()),
+                None => Err(Error::FromString(format!("ApiServer fetch error: no recorded reply to '{sql}'"))),
+            };
+        }
         let mut request = ApiRequest::new(
             &api_tools::debug::dbg_id::DbgId("parent".to_owned()),
             self.host.clone() + ":" + &self.port,
@@ -32,8 +80,13 @@ impl ApiClient {
             true,
             false,
         );
-        request
+        let reply = request
             .fetch(true)
-            .map_err(|e| Error::FromString(format!("ApiServer fetch error: {e}")))
+            .map_err(|e| Error::ApiRequest(format!("ApiServer fetch error: {e}")))?;
+        self.replies.put(sql, &reply);
+        if let Some(stats) = &self.stats {
+            stats.add(serde_json::from_slice::<Rows>(&reply).map_or(0, |rows| rows.data.len()));
+        }
+        Ok(reply)
     }
 }
\ No newline at end of file
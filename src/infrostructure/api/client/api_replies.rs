@@ -0,0 +1,69 @@
+use std::{collections::BTreeMap, sync::{Arc, Mutex, PoisonError}};
+///
+/// Mode of the [ApiReplies]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Mode {
+    #[default]
+    Off,
+    Record,
+    Replay,
+}
+///
+/// # Raw replies of the API Server by the sql query
+/// - `record` - replies received by the [ApiClient](super::api_client::ApiClient) are stored
+/// - `replay` - the client returns the stored replies without the API Server
+/// - Clones share the same replies
+#[derive(Debug, Clone, Default)]
+pub struct ApiReplies {
+    mode: Mode,
+    replies: Arc<Mutex<BTreeMap<String, String>>>,
+}
+//
+//
+impl ApiReplies {
+    ///
+    /// Neither records nor replays the replies
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///
+    /// Records the received replies
+    pub fn record() -> Self {
+        Self { mode: Mode::Record, ..Self::default() }
+    }
+    ///
+    /// Replays the `replies` instead of the API Server
+    pub fn replay(replies: BTreeMap<String, String>) -> Self {
+        Self { mode: Mode::Replay, replies: Arc::new(Mutex::new(replies)) }
+    }
+    ///
+    /// Returns true if the replies are replayed
+    pub fn is_replay(&self) -> bool {
+        self.mode == Mode::Replay
+    }
+    ///
+    /// Returns true if the replies are recorded or replayed
+    pub fn is_active(&self) -> bool {
+        self.mode != Mode::Off
+    }
+    ///
+    /// Returns the stored reply to the `sql`
+    pub fn get(&self, sql: &str) -> Option<String> {
+        self.replies.lock().unwrap_or_else(PoisonError::into_inner).get(sql).cloned()
+    }
+    ///
+    /// Stores the `reply` to the `sql`, if the replies are recorded
+    pub fn put(&self, sql: &str, reply: &[u8]) {
+        if self.mode == Mode::Record {
+            self.replies
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(sql.to_owned(), String::from_utf8_lossy(reply).into_owned());
+        }
+    }
+    ///
+    /// Returns all stored replies
+    pub fn replies(&self) -> BTreeMap<String, String> {
+        self.replies.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
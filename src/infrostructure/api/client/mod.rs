@@ -1 +1,3 @@
-pub mod api_client;
\ No newline at end of file
+pub mod api_client;
+pub mod api_replies;
+pub mod query_stats;
@@ -0,0 +1,34 @@
+use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+///
+/// Counters of the [ApiClient](super::api_client::ApiClient) queries and received rows
+/// - Clones share the same counters
+#[derive(Debug, Clone, Default)]
+pub struct QueryStats {
+    queries: Arc<AtomicUsize>,
+    rows: Arc<AtomicUsize>,
+}
+//
+//
+impl QueryStats {
+    ///
+    /// New instance with zero counters
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///
+    /// Counts the query and the received `rows`
+    pub fn add(&self, rows: usize) {
+        self.queries.fetch_add(1, Ordering::SeqCst);
+        self.rows.fetch_add(rows, Ordering::SeqCst);
+    }
+    ///
+    /// Returns the number of the queries
+    pub fn queries(&self) -> usize {
+        self.queries.load(Ordering::SeqCst)
+    }
+    ///
+    /// Returns the number of the received rows
+    pub fn rows(&self) -> usize {
+        self.rows.load(Ordering::SeqCst)
+    }
+}
@@ -1,24 +1,68 @@
+use std::collections::BTreeMap;
 use serde::{Serialize, Deserialize};
+use crate::{
+    algorithm::{context::ctx_diff::CtxDiff, loading::change_set::ChangeSet, pipeline::trace::Trace},
+    kernel::error::eval_error::EvalError,
+};
 ///
 /// Client request | Restart of calculation
+/// - If `changes` are specified for the same ship, only the affected steps are recalculated
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RestartEvalQuery {
     pub ship_id: usize,
+    #[serde(default)]
+    pub changes: Option<ChangeSet>,
 }
 ///
 /// Reply to [RestartEvalQuery]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RestartEvalReply {
-    pub answer: u8
+    /// Status of the calculation: [DONE](Self::DONE), [ERROR](Self::ERROR) or [CANCELLED](Self::CANCELLED)
+    pub answer: u8,
+    /// Results of the calculation, by context type
+    #[serde(default)]
+    pub results: BTreeMap<String, serde_json::Value>,
+    /// Results changed since the previous calculation
+    #[serde(default)]
+    pub diff: Vec<CtxDiff>,
+    /// Error of the calculation with the stable code
+    #[serde(default)]
+    pub error: Option<EvalError>,
+    /// The calculation is cancelled by the next request for the same ship
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Timing of the evaluated steps
+    #[serde(default)]
+    pub trace: Trace,
 }
 //
 //
 impl RestartEvalReply {
+    /// The calculation is done
+    pub const DONE: u8 = 0;
+    /// The calculation failed, the `error` is set
+    pub const ERROR: u8 = 1;
+    /// The calculation is cancelled by the next request
+    pub const CANCELLED: u8 = 2;
     ///
     /// New instance [InitialReply]
     pub fn new(choosen: u8) -> Self {
         Self {
             answer: choosen,
+            results: BTreeMap::new(),
+            diff: vec![],
+            error: None,
+            cancelled: false,
+            trace: Trace::default(),
+        }
+    }
+    ///
+    /// Reply to the cancelled request
+    pub fn cancelled() -> Self {
+        Self {
+            cancelled: true,
+            error: Some(EvalError::cancelled("Cancelled by the next request")),
+            ..Self::new(Self::CANCELLED)
         }
     }
 }
@@ -18,6 +18,10 @@ pub enum Error {
     Parameter(String),
     #[error("ApiRequest: {0}")]
     ApiRequest(String),
+    #[error("Database: {0}")]
+    Database(String),
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
     #[error("Calculate: {0}")]
     Calculate(String),
     #[error(transparent)]
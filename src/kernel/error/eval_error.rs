@@ -0,0 +1,193 @@
+use sal_sync::services::entity::error::str_err::StrErr;
+use serde::{Deserialize, Serialize, Serializer};
+use super::error::Error;
+///
+/// Kind of the [EvalError], serialized as the stable error `code`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "code", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EvalErrorKind {
+    ///
+    /// Required data of the ship is absent in the database
+    DataMissing { table: String, ship_id: usize },
+    ///
+    /// Data has the wrong format or value
+    InvalidData { field: String, value: String },
+    ///
+    /// Operation has not completed in time
+    Timeout,
+    ///
+    /// Database or service is not available
+    Communication,
+    ///
+    /// Evaluation is cancelled by the next request
+    Cancelled,
+    ///
+    /// Error in the calculation itself
+    Internal,
+}
+//
+//
+impl EvalErrorKind {
+    ///
+    /// Returns the stable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            EvalErrorKind::DataMissing { .. } => "DATA_MISSING",
+            EvalErrorKind::InvalidData { .. } => "INVALID_DATA",
+            EvalErrorKind::Timeout => "TIMEOUT",
+            EvalErrorKind::Communication => "COMMUNICATION",
+            EvalErrorKind::Cancelled => "CANCELLED",
+            EvalErrorKind::Internal => "INTERNAL",
+        }
+    }
+}
+///
+/// # Error of the calculation
+/// - 'kind' - what is wrong, defines the stable error `code`
+/// - 'message' - where and why it happened
+/// - 'cause' - the error of the underlying layer
+///
+/// Serialized with the `retryable` flag, see [EvalError::is_retryable]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EvalError {
+    #[serde(flatten)]
+    pub kind: EvalErrorKind,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cause: Option<Box<EvalError>>,
+}
+//
+//
+impl EvalError {
+    ///
+    /// New instance without the cause
+    pub fn new(kind: EvalErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            cause: None,
+        }
+    }
+    ///
+    /// Returns the [EvalErrorKind::Internal] error
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(EvalErrorKind::Internal, message)
+    }
+    ///
+    /// Returns the [EvalErrorKind::Cancelled] error
+    pub fn cancelled(message: impl Into<String>) -> Self {
+        Self::new(EvalErrorKind::Cancelled, message)
+    }
+    ///
+    /// Returns the error of the query of the `table` of the ship `ship_id`
+    /// - the error reported by the database itself (no table, SQL error) means the data is missing
+    /// - the reply not parsed means the data is invalid
+    pub fn query(err: Error, table: &str, ship_id: usize) -> Self {
+        match err {
+            Error::Database(value) => Self::new(
+                EvalErrorKind::DataMissing { table: table.to_owned(), ship_id },
+                format!("'{table}' error: {value}"),
+            ),
+            Error::FromUtf8Error(_) | Error::ParseIntError(_) | Error::ParseFloatError(_) | Error::Serde(_) => Self::new(
+                EvalErrorKind::InvalidData { field: table.to_owned(), value: err.to_string() },
+                format!("'{table}' parse error"),
+            ),
+            err => Self::from(err).context(format!("'{table}' error")),
+        }
+    }
+    ///
+    /// Returns the error of the upper layer with the same kind, caused by this error
+    pub fn context(self, message: impl Into<String>) -> Self {
+        Self {
+            kind: self.kind.clone(),
+            message: message.into(),
+            cause: Some(Box::new(self)),
+        }
+    }
+    ///
+    /// Returns the stable error code
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+    ///
+    /// Returns true if the same request may succeed later
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.kind, EvalErrorKind::Timeout | EvalErrorKind::Communication | EvalErrorKind::Cancelled)
+    }
+    ///
+    /// Returns the chain of the errors, starting from this one
+    pub fn chain(&self) -> impl Iterator<Item = &EvalError> {
+        std::iter::successors(Some(self), |err| err.cause.as_deref())
+    }
+}
+//
+//
+impl Serialize for EvalError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EvalErrorReply {
+            kind: &self.kind,
+            message: &self.message,
+            retryable: self.is_retryable(),
+            cause: &self.cause,
+        }
+        .serialize(serializer)
+    }
+}
+///
+/// Serialized form of the [EvalError]
+#[derive(Serialize)]
+struct EvalErrorReply<'a> {
+    #[serde(flatten)]
+    kind: &'a EvalErrorKind,
+    message: &'a str,
+    retryable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cause: &'a Option<Box<EvalError>>,
+}
+//
+//
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message)?;
+        for cause in self.chain().skip(1) {
+            write!(f, "\n\tcaused by: {}", cause.message)?;
+        }
+        Ok(())
+    }
+}
+//
+//
+impl std::error::Error for EvalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.cause.as_deref().map(|cause| cause as &(dyn std::error::Error + 'static))
+    }
+}
+//
+//
+impl From<StrErr> for EvalError {
+    fn from(err: StrErr) -> Self {
+        Self::internal(err.to_string())
+    }
+}
+//
+//
+///
+/// The table and the ship of the database error are unknown here,
+/// the queries are mapped with them by [EvalError::query]
+impl From<Error> for EvalError {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        let invalid = |field: &str, value: String| EvalErrorKind::InvalidData { field: field.to_owned(), value };
+        match err {
+            Error::ApiRequest(_) => Self::new(EvalErrorKind::Communication, message),
+            Error::Database(_) => Self::new(EvalErrorKind::DataMissing { table: String::new(), ship_id: 0 }, message),
+            Error::Cancelled(_) => Self::cancelled(message),
+            Error::FromUtf8Error(err) => Self::new(invalid("utf8", String::from_utf8_lossy(err.as_bytes()).into_owned()), message),
+            Error::ParseIntError(err) => Self::new(invalid("int", err.to_string()), message),
+            Error::ParseFloatError(err) => Self::new(invalid("float", err.to_string()), message),
+            Error::Serde(err) => Self::new(invalid("json", err.to_string()), message),
+            Error::Parameter(value) => Self::new(invalid("parameter", value), message),
+            _ => Self::internal(message),
+        }
+    }
+}
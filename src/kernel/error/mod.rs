@@ -1 +1,2 @@
 pub mod error;
+pub mod eval_error;
@@ -0,0 +1,47 @@
+use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+use tokio::sync::Notify;
+///
+/// # Cooperative cancellation signal
+/// - Clones share the same state, cancelling any clone cancels all of them
+/// - The cancelled token can't be reset, new token is created for the next evaluation
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+//
+//
+impl CancelToken {
+    ///
+    /// New not cancelled instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+    ///
+    /// Cancels the token, wakes all awaiting [CancelToken::cancelled]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+    ///
+    /// Returns true if the token is cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+    ///
+    /// Completes when the token is cancelled
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+    ///
+    /// Returns true if both tokens share the same state
+    pub fn same(&self, other: &CancelToken) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cancel_token;
 pub mod link;
 pub mod recv_timeout;
 pub mod switch;
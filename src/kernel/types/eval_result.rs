@@ -1,7 +1,5 @@
-use sal_sync::services::entity::error::str_err::StrErr;
-
-use crate::algorithm::context::{context::Context, ctx_result::CtxResult};
+use crate::{algorithm::context::{context::Context, ctx_result::CtxResult}, kernel::error::eval_error::EvalError};
 ///
 /// Result returned from Calculation steps
 // pub type EvalResult = (Switch, CtxResult<Context, StrErr>);
-pub type EvalResult = CtxResult<Context, StrErr>;
\ No newline at end of file
+pub type EvalResult = CtxResult<Context, EvalError>;
@@ -6,18 +6,14 @@ mod conf;
 mod ship_model;
 #[cfg(test)]
 mod tests;
-use algorithm::{
-    areas_strength::areas_strength::AreasStrength, context::context::Context, initial::{initial::Initial, initial_ctx::InitialCtx}
-};
+use algorithm::context::ctx_bundle::CtxBundle;
 //
 use api_tools::debug::dbg_id::DbgId;
 use app::app::App;
 use conf::conf::Conf;
 use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
 use infrostructure::{api::client::api_client::ApiClient, query::restart_eval::RestartEvalQuery};
-use kernel::{
-    eval::Eval, run::Run,
-};
+use kernel::run::Run;
 use ship_model::ship_model::ShipModel;
 ///
 /// Application entry point
@@ -34,7 +30,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     let conf = "./config.yaml";
     let conf = Conf::new(&dbg, conf);
-    let ship_id = 0;
+    let ship_id = conf.ship_id;
     let ship_model = ShipModel::new(
         &dbg,
         ship_id,
@@ -42,21 +38,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     let ship_model_handle = ship_model.run().await.unwrap();
     log::debug!("main | Calculations...");
-    let _result = AreasStrength::new(
+    let session = conf.pipeline.session(
         &dbg,
-        ship_model.link().await,
-        Initial::new(
-            &dbg,
-            ApiClient::new(conf.api.database.clone(), conf.api.host.clone(), conf.api.port.clone()),
-            Context::new(
-                InitialCtx::new(
-                    ship_id,
-                ),
-            ),
-        ),
-    )
-    .eval(())
-    .await;
+        &ApiClient::new(conf.api.database.clone(), conf.api.host.clone(), conf.api.port.clone()),
+        &ship_model,
+    ).await;
+    // the calculation is reproduced from the bundle, if its path is passed as the argument
+    let _reply = match std::env::args().nth(1) {
+        Some(path) => match CtxBundle::load(&path) {
+            Ok(bundle) => Some(session.replay(&bundle).await),
+            Err(err) => {
+                log::error!("main | Error: {}", err);
+                None
+            }
+        },
+        None => Some(session.restart(&RestartEvalQuery { ship_id, changes: None }).await),
+    };
     ship_model.exit();
     ship_model_handle.await.unwrap();
     Ok(())
@@ -1,6 +1,10 @@
 use std::{fmt::Debug, sync::{atomic::{AtomicBool, Ordering}, mpsc::{Receiver, Sender}, Arc}, time::Duration};
-use sal_sync::services::entity::{error::str_err::StrErr, name::Name, point::point_tx_id::PointTxId};
-use crate::algorithm::entities::{area::HAreaStrength, strength::VerticalArea};
+use sal_sync::services::entity::{name::Name, point::point_tx_id::PointTxId};
+use crate::{
+    algorithm::entities::{area::HAreaStrength, strength::VerticalArea},
+    infrostructure::api::client::api_replies::ApiReplies,
+    kernel::error::eval_error::{EvalError, EvalErrorKind},
+};
 
 use super::{query::Query, reply::Reply};
 ///
@@ -65,28 +69,28 @@ impl ModelLink {
     // }
     ///
     /// - Returns strength areas by ship frames
-    pub async fn areas(&self) -> Result<(Vec<VerticalArea>, Vec<HAreaStrength>), StrErr> {
+    /// - 'replies' - the database replies of the calculation, the model records or replays its queries
+    pub async fn areas(&self, replies: ApiReplies) -> Result<(Vec<VerticalArea>, Vec<HAreaStrength>), EvalError> {
         let timeout = Duration::from_secs(300);
-        match self.send.send(Query::AreasStrength) {
+        match self.send.send(Query::AreasStrength(replies)) {
             Ok(_) => {
-                log::debug!("{}.areas | Sent request: {:#?}", self.name, Query::AreasStrength);
+                log::debug!("{}.areas | Sent request: AreasStrength", self.name);
                 tokio::task::block_in_place(move|| {
                     match &self.recv {
                         Some(recv) => match recv.recv_timeout(timeout) {
                             Ok(reply) => {
                                 log::debug!("{}.req | Received reply: {:#?}", self.name, reply);
                                 match reply {
-                                    Reply::AreasStrength(items) => items,
-                                    _ => panic!("{}.areas | Wrong reply: {:#?}", self.name, reply),
+                                    Reply::AreasStrength(items) => items.map_err(EvalError::from),
                                 }
                             }
-                            _ => Err(StrErr(format!("{}.req | Request timeout ({:?})", self.name, timeout))),
+                            _ => Err(EvalError::new(EvalErrorKind::Timeout, format!("{}.req | Request timeout ({:?})", self.name, timeout))),
                         }
-                        None => todo!(),
+                        None => Err(EvalError::internal(format!("{}.areas | Link has no receiver", self.name))),
                     }
                 })
             },
-            Err(err) => Err(StrErr(format!("{}.req | Send request error: {:#?}", self.name, err))),
+            Err(err) => Err(EvalError::new(EvalErrorKind::Communication, format!("{}.req | Send request error: {:#?}", self.name, err))),
         }
     }
 }
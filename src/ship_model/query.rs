@@ -1,10 +1,9 @@
-use sal_sync::services::entity::error::str_err::StrErr;
-
-use crate::algorithm::entities::{area::HAreaStrength, strength};
+use crate::infrostructure::api::client::api_replies::ApiReplies;
 
 ///
 /// Queries to the `ShipModel`
+/// - 'ApiReplies' - the database replies of the calculation, recorded or replayed
 #[derive(Debug)]
 pub enum Query {
-    AreasStrength,
+    AreasStrength(ApiReplies),
 }
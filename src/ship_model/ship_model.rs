@@ -93,8 +93,8 @@ impl ShipModel {
                         Ok(query) => {
                             log::trace!("{}.run | Received query: {:?}", dbg, query);
                             match query {
-                                Query::AreasStrength => {
-                                    let result = areas_strength(&api_client, ship_id);
+                                Query::AreasStrength(replies) => {
+                                    let result = areas_strength(&api_client.with_replies(replies), ship_id);
                                     if let Err(err) = send.send(Reply::AreasStrength(result)) {
                                         log::warn!("{}.run | Send error: {:?}", dbg, err);
                                     }
@@ -0,0 +1,67 @@
+#[cfg(test)]
+
+mod bulk_heel {
+    use std::{rc::Rc, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        bulk_heel::BulkHeel,
+        load::{Bulk, BulkShift, IBulk},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'IBulk::heel_moment', 'BulkHeel::angle' and 'BulkHeel::check'
+    #[test]
+    fn bulk_heel() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "bulk_heel";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let grain = Bulk::new(2., 2000.).unwrap();
+        let repose = Bulk::new_shift(1., 1000., 800., BulkShift::Repose(15.)).unwrap();
+        let fixed = Bulk::new_shift(1., 0., 500., BulkShift::Fixed(0.2)).unwrap();
+        let test_data = [
+            // step, bulk, roll, target moment
+            (1, &grain, 30., 1000.),
+            (2, &repose, 30., 1000.),
+            (3, &repose, 20., 1000. * 5f64.to_radians().tan() / 15f64.to_radians().tan()),
+            (4, &repose, 10., 0.),
+            (5, &fixed, 30., 100.),
+        ];
+        for (step, bulk, roll, target) in test_data {
+            let result = bulk.heel_moment(roll);
+            assert!((result - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        assert!(Bulk::new_shift(1., 0., -1., BulkShift::Fixed(0.2)).is_err());
+        let bulks: Vec<Rc<dyn IBulk>> = vec![Rc::new(repose), Rc::new(fixed)];
+        let heel = BulkHeel::new(10000., BulkHeel::moment(&bulks, 30.)).unwrap();
+        assert!((heel.lever() - 0.11).abs() < 1e-9);
+        assert!((heel.lever_at(40.) - 0.088).abs() < 1e-9);
+        // GZ = 0.02θ, 0.02θ = 0.11(1 - 0.005θ)
+        let gz = [(0., 0.), (60., 1.2)];
+        let target = 0.11 / 0.02055;
+        let result = heel.angle(&gz).unwrap().unwrap();
+        assert!((result - target).abs() < 1e-6, "\nresult: {:?}\ntarget: {:?}", result, target);
+        assert!(heel.check(&gz, Some(20.)).unwrap().is_ok());
+        assert!(!heel.check(&gz, Some(5.)).unwrap().is_ok());
+        assert!(heel.angle(&[(0., 0.), (60., 0.01)]).unwrap().is_none());
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,164 @@
+#[cfg(test)]
+
+mod bundle {
+    use std::{collections::BTreeMap, sync::Once, time::Duration};
+    use futures::future::BoxFuture;
+    use serde::{Deserialize, Serialize};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{
+                context::Context, context_access::{ContextRead, ContextWrite}, ctx_bundle::{CtxBundle, CtxRegistry},
+                ctx_result::CtxResult, ctx_store::{CtxType, StepCtx},
+            },
+            areas_strength::{areas_strength::AreasStrength, areas_strength_ctx::AreasStrengthCtx},
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_ctx::CargoesCtx},
+            pipeline::{cache::{Cached, StepCache}, executor::Pipeline, step::Step},
+        },
+        infrostructure::api::client::{api_client::ApiClient, api_replies::ApiReplies},
+        kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, types::eval_result::EvalResult},
+        ship_model::ship_model::ShipModel,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Test context of the step 'Rows'
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    struct RowsCtx(Vec<f64>);
+    impl StepCtx for RowsCtx {
+        const STEP: &'static str = "Rows";
+    }
+    ///
+    /// Fetches the rows from the database
+    struct Rows {
+        dbg: DbgId,
+        api_client: ApiClient,
+    }
+    impl Eval<Context, EvalResult> for Rows {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                let initial: InitialCtx = match ContextRead::read(&ctx) {
+                    Ok(initial) => initial,
+                    Err(err) => return CtxResult::Err(err),
+                };
+                let reply = self.api_client
+                    .with_replies(ctx.api_replies().clone())
+                    .fetch(&format!("SELECT value FROM rows WHERE ship_id={};", initial.ship_id));
+                match reply.map(|reply| serde_json::from_slice::<Vec<f64>>(&reply)) {
+                    Ok(Ok(rows)) => ctx.write(RowsCtx(rows)),
+                    Ok(Err(err)) => CtxResult::Err(EvalError::internal(err.to_string())),
+                    Err(err) => CtxResult::Err(EvalError::from(err).context(format!("{}.eval | Fetch error", self.dbg))),
+                }
+            })
+        }
+    }
+    impl Step for Rows {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<InitialCtx>()]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<RowsCtx>()]
+        }
+    }
+    ///
+    /// Testing 'CtxBundle' save, load and replay without the database
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replay() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "bundle";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let dir = std::env::temp_dir().join(format!("cdt-math-bundle-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let changes: ChangeSet = serde_json::from_str(
+            r#"{"cargoes":[{"name":"a","mass":10.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":1.0}]}"#,
+        ).unwrap();
+        let (ctx, _) = changes.apply(Context::new(InitialCtx::new(7))).unwrap();
+        let ctx = ctx.write(RowsCtx(vec![])).unwrap();
+        let mut bundle = CtxBundle::new(&ctx).unwrap();
+        bundle.replies = BTreeMap::from([
+            ("SELECT value FROM rows WHERE ship_id=7;".to_owned(), "[1.5, 2.5]".to_owned()),
+        ]);
+        for file in ["bundle.json", "bundle.yaml"] {
+            let path = dir.join(file);
+            bundle.save(&path).unwrap();
+            let result = CtxBundle::load(&path).unwrap();
+            assert!(result == bundle, "\nfile: {}\nresult: {:?}\ntarget: {:?}", file, result, bundle);
+        }
+        // the context types of the test are unknown to the default registry
+        assert!(bundle.context(&CtxRegistry::default()).is_err());
+        let registry = CtxRegistry::default().with::<RowsCtx>();
+        let ctx = bundle.context(&registry).unwrap();
+        let result: CargoesCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.cargoes.len() == 1 && result.cargoes[0].mass == Some(10.), "\nresult: {:?}", result);
+        // the database is not available, the replies are replayed
+        let api_client = ApiClient::new("none".to_owned(), "0.0.0.0".to_owned(), "0".to_owned());
+        let mut pipeline = Pipeline::new(dbg).step(Rows { dbg: DbgId("Rows".into()), api_client: api_client.clone() });
+        let result = pipeline.eval(ctx).await.unwrap();
+        let result: RowsCtx = ContextRead::read(&result).unwrap();
+        assert!(result == RowsCtx(vec![1.5, 2.5]), "\nresult: {:?}\ntarget: {:?}", result, vec![1.5, 2.5]);
+        // the query, which reply is not recorded, is the error
+        let result = api_client.with_replies(ApiReplies::replay(BTreeMap::new())).fetch("SELECT 1;");
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: error", result);
+        std::fs::remove_dir_all(&dir).unwrap();
+        test_duration.exit();
+    }
+    ///
+    /// Testing the replay of the ship model queries, the cache is bypassed
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replay_ship_model() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "bundle";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        // the database is not available, the replies are replayed
+        let api_client = ApiClient::new("none".to_owned(), "0.0.0.0".to_owned(), "0".to_owned());
+        let ship_model = ShipModel::new(dbg, 7, api_client);
+        let handle = ship_model.run().await.unwrap();
+        let replies = BTreeMap::from([
+            (
+                "SELECT name, value, bound_x1, bound_x2 FROM horizontal_area_strength WHERE ship_id=7;".to_owned(),
+                r#"{"data":[{"name":"deck","value":12.0,"bound_x1":0.0,"bound_x2":4.0}],"error":{}}"#.to_owned(),
+            ),
+            (
+                "SELECT name, value, bound_x1, bound_x2 FROM vertical_area_strength WHERE ship_id=7;".to_owned(),
+                r#"{"data":[{"name":"hull","value":30.0,"bound_x1":0.0,"bound_x2":4.0}],"error":{}}"#.to_owned(),
+            ),
+        ]);
+        let ctx = Context::new(InitialCtx::new(7).with_revision(1)).with_api_replies(ApiReplies::replay(replies));
+        let step = AreasStrength::new(dbg, ship_model.link().await);
+        let mut pipeline = Pipeline::new(dbg).step(Cached::new(step, StepCache::new(None::<&str>)));
+        let result = pipeline.eval(ctx).await.unwrap();
+        let result: AreasStrengthCtx = ContextRead::read(&result).unwrap();
+        let result = (result.areas.0.len(), result.areas.1.len(), result.areas.0[0].value);
+        assert!(result == (1, 1, 30.), "\nresult: {:?}\ntarget: {:?}", result, (1, 1, 30.));
+        ship_model.exit();
+        handle.await.unwrap();
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+
+mod cache {
+    use std::{collections::BTreeMap, sync::{atomic::{AtomicUsize, Ordering}, Arc, Once}, time::Duration};
+    use futures::future::BoxFuture;
+    use serde::{Deserialize, Serialize};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::{ContextRead, ContextWrite}, ctx_store::{CtxType, StepCtx}},
+            initial::initial_ctx::InitialCtx,
+            pipeline::{cache::{Cached, Memo, StableHasher, StepCache}, step::Step},
+        },
+        infrostructure::api::client::api_replies::ApiReplies,
+        kernel::{dbgid::dbgid::DbgId, error::eval_error::EvalError, eval::Eval, types::eval_result::EvalResult},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Test context of the step 'Count'
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct CountCtx(usize);
+    impl StepCtx for CountCtx {
+        const STEP: &'static str = "Count";
+    }
+    ///
+    /// Writes the number of its evaluations
+    struct Count {
+        dbg: DbgId,
+        count: Arc<AtomicUsize>,
+    }
+    impl Eval<Context, EvalResult> for Count {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                let count = self.count.fetch_add(1, Ordering::SeqCst) + 1;
+                ctx.write(CountCtx(count))
+            })
+        }
+    }
+    impl Step for Count {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<CountCtx>()]
+        }
+    }
+    impl Memo for Count {
+        type Output = CountCtx;
+        fn hash_inputs(&self, _: &Context, _: &mut StableHasher) -> Result<(), EvalError> {
+            Ok(())
+        }
+    }
+    ///
+    /// Testing 'Cached::eval' with memory and disk 'StepCache'
+    #[tokio::test(flavor = "multi_thread")]
+    async fn eval() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "cache";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let dir = std::env::temp_dir().join(format!("cdt-math-cache-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let count = Arc::new(AtomicUsize::new(0));
+        let cache = StepCache::new(Some(&dir));
+        let mut step = Cached::new(Count { dbg: DbgId("Count".into()), count: count.clone() }, cache.clone());
+        let test_data = [
+            // step, revision, target value, target evaluations
+            (1, 1, 1, 1),
+            (2, 1, 1, 1),
+            (3, 2, 2, 2),
+            (4, 1, 1, 2),
+        ];
+        for (step_num, revision, target, target_count) in test_data {
+            let ctx = Context::new(InitialCtx::new(3).with_revision(revision));
+            let result: CountCtx = ContextRead::read(&step.eval(ctx).await.unwrap()).unwrap();
+            assert!(result.0 == target, "step {} \nresult: {:?}\ntarget: {:?}", step_num, result.0, target);
+            let result = count.load(Ordering::SeqCst);
+            assert!(result == target_count, "step {} \nresult: {:?}\ntarget: {:?}", step_num, result, target_count);
+        }
+        // new cache instance reads the persisted results
+        let mut step = Cached::new(Count { dbg: DbgId("Count".into()), count: count.clone() }, StepCache::new(Some(&dir)));
+        let ctx = Context::new(InitialCtx::new(3).with_revision(2));
+        let result: CountCtx = ContextRead::read(&step.eval(ctx).await.unwrap()).unwrap();
+        assert!(result.0 == 2 && count.load(Ordering::SeqCst) == 2, "\nresult: {:?}", result);
+        // without the revision the result is not memoized
+        let ctx = Context::new(InitialCtx::new(4));
+        let result: CountCtx = ContextRead::read(&step.eval(ctx.clone()).await.unwrap()).unwrap();
+        assert!(result.0 == 3, "step {} \nresult: {:?}\ntarget: {:?}", 5, result.0, 3);
+        let result: CountCtx = ContextRead::read(&step.eval(ctx).await.unwrap()).unwrap();
+        assert!(result.0 == 4, "step {} \nresult: {:?}\ntarget: {:?}", 6, result.0, 4);
+        let files = std::fs::read_dir(&dir).unwrap().count();
+        assert!(files == 2, "step {} \nresult: {:?}\ntarget: {:?}", 7, files, 2);
+        // replayed evaluation bypasses the cache
+        let ctx = Context::new(InitialCtx::new(3).with_revision(2)).with_api_replies(ApiReplies::replay(BTreeMap::new()));
+        let result: CountCtx = ContextRead::read(&step.eval(ctx).await.unwrap()).unwrap();
+        assert!(result.0 == 5, "step {} \nresult: {:?}\ntarget: {:?}", 8, result.0, 5);
+        // the least recently used result is evicted from memory
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut step = Cached::new(Count { dbg: DbgId("Count".into()), count: count.clone() }, StepCache::new(None::<&str>).with_capacity(2));
+        let test_data = [
+            // step, revision, target value, target evaluations
+            (9, 1, 1, 1),
+            (10, 2, 2, 2),
+            (11, 1, 1, 2),
+            (12, 3, 3, 3),
+            (13, 1, 1, 3),
+            (14, 2, 4, 4),
+        ];
+        for (step_num, revision, target, target_count) in test_data {
+            let ctx = Context::new(InitialCtx::new(3).with_revision(revision));
+            let result: CountCtx = ContextRead::read(&step.eval(ctx).await.unwrap()).unwrap();
+            assert!(result.0 == target, "step {} \nresult: {:?}\ntarget: {:?}", step_num, result.0, target);
+            let result = count.load(Ordering::SeqCst);
+            assert!(result == target_count, "step {} \nresult: {:?}\ntarget: {:?}", step_num, result, target_count);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+
+mod context {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::{
+        areas_strength::areas_strength_ctx::AreasStrengthCtx,
+        context::{context::Context, context_access::{ContextRead, ContextReadRef, ContextWrite}},
+        initial::initial_ctx::InitialCtx,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'ContextRead', 'ContextReadRef' and 'ContextWrite' on the type-keyed store
+    #[test]
+    fn read_write() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "context";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let ctx = Context::new(InitialCtx::new(7));
+        let initial: &InitialCtx = ContextReadRef::read(&ctx).unwrap();
+        assert!(initial.ship_id == 7, "\nresult: {:?}\ntarget: {:?}", initial.ship_id, 7);
+        let result: Result<AreasStrengthCtx, _> = ContextRead::read(&ctx);
+        match result {
+            Ok(value) => panic!("\nresult: {:?}\ntarget: Err", value),
+            Err(err) => assert!(err.message.contains("step 'AreasStrength' has not produced"), "\nerror: {:?}", err),
+        }
+        let ctx = ctx.write(AreasStrengthCtx { areas: (vec![], vec![]) }).unwrap();
+        let result: AreasStrengthCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.areas.0.is_empty() && result.areas.1.is_empty());
+        let ctx = ctx.write(InitialCtx::new(8)).unwrap();
+        let initial: &InitialCtx = ContextReadRef::read(&ctx).unwrap();
+        assert!(initial.ship_id == 8, "\nresult: {:?}\ntarget: {:?}", initial.ship_id, 8);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+
+mod ctx_result {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::context::ctx_result::CtxResult;
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Parses the value with the `?` operator
+    async fn parse(value: &str) -> CtxResult<i32, String> {
+        CtxResult::try_async(async {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            let value: i32 = value.parse().map_err(|err| format!("parse error: {err}"))?;
+            Ok(Some(value * 2))
+        }).await
+    }
+    ///
+    /// Testing 'CtxResult' combinators and conversions
+    #[tokio::test(flavor = "multi_thread")]
+    async fn combinators() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "ctx_result";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let ok: CtxResult<i32, String> = CtxResult::Ok(2);
+        let err: CtxResult<i32, String> = CtxResult::Err("e".to_owned());
+        let none: CtxResult<i32, String> = CtxResult::None;
+        assert!(ok.is_ok() && err.is_err() && none.is_none());
+        assert!(ok.clone().map(|v| v + 1) == CtxResult::Ok(3));
+        assert!(err.clone().map(|v| v + 1) == CtxResult::Err("e".to_owned()));
+        assert!(none.clone().map(|v| v + 1) == CtxResult::None);
+        assert!(err.clone().map_err(|e| e.len()) == CtxResult::Err(1));
+        assert!(ok.clone().map_err(|e| e.len()) == CtxResult::Ok(2));
+        assert!(ok.clone().and_then(|v| CtxResult::Ok(v * 10)) == CtxResult::Ok(20));
+        assert!(ok.clone().and_then(|_| CtxResult::<i32, String>::None) == CtxResult::None);
+        assert!(err.clone().and_then(|v| CtxResult::Ok(v * 10)) == CtxResult::Err("e".to_owned()));
+        assert!(ok.clone().ok_or("none".to_owned()) == Ok(2));
+        assert!(none.clone().ok_or("none".to_owned()) == Err("none".to_owned()));
+        assert!(err.clone().ok_or_else(|| "none".to_owned()) == Err("e".to_owned()));
+        assert!(ok.clone().ok() == Some(2) && err.clone().ok().is_none());
+        let test_data = [
+            (Ok(Some(1)), CtxResult::Ok(1)),
+            (Ok(None), CtxResult::None),
+            (Err("e".to_owned()), CtxResult::Err("e".to_owned())),
+        ];
+        for (step, (value, target)) in test_data.into_iter().enumerate() {
+            let result: CtxResult<i32, String> = value.clone().into();
+            assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+            let result: Result<Option<i32>, String> = target.into();
+            assert!(result == value, "step {} \nresult: {:?}\ntarget: {:?}", step, result, value);
+        }
+        let test_data = [
+            ("21", CtxResult::Ok(42)),
+            ("", CtxResult::None),
+            ("x", CtxResult::Err("parse error: invalid digit found in string".to_owned())),
+        ];
+        for (step, (value, target)) in test_data.into_iter().enumerate() {
+            let result = parse(value).await;
+            assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+
+mod deck_load {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        load::{DeckLoad, DeckLoadCheck},
+        loads::{CompartmentData, DeckZoneData, LoadCargo},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Deck zone or tank top zone 10 m wide
+    fn zone(name: &str, is_tank_top: bool, bound_x: (f64, f64), bound_y: (f64, f64), max_load: f64) -> DeckZoneData {
+        DeckZoneData {
+            name: name.to_owned(),
+            is_tank_top,
+            bound_x1: bound_x.0,
+            bound_x2: bound_x.1,
+            bound_y1: bound_y.0,
+            bound_y2: bound_y.1,
+            max_load,
+        }
+    }
+    ///
+    /// Result of the check for the cargo on the zone
+    fn check(cargo: &str, zone: &str, load: f64, limit: f64) -> DeckLoadCheck {
+        DeckLoadCheck { cargo: cargo.to_owned(), zone: zone.to_owned(), load, limit }
+    }
+    ///
+    /// Testing 'DeckLoad' of the deck cargoes, the cargo in the hold and the bulk cargo in the hold
+    #[test]
+    fn check_loads() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "deck_load";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let zones = vec![
+            zone("deck 1", false, (0., 10.), (-5., 5.), 2.),
+            zone("deck 2", false, (10., 20.), (-5., 5.), 1.),
+            zone("tank top port", true, (0., 20.), (-4., 0.), 5.),
+            zone("tank top starboard", true, (0., 20.), (0., 4.), 5.),
+        ];
+        let cargoes: Vec<LoadCargo> = serde_json::from_str(r#"[
+            {"name":"over","mass":150.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+             "bound_x1":5.0,"bound_x2":15.0,"bound_y1":-2.0,"bound_y2":3.0,"bound_z1":10.0,"bound_z2":12.0},
+            {"name":"under","mass":20.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+             "bound_x1":0.0,"bound_x2":5.0,"bound_y1":0.0,"bound_y2":2.0,"bound_z1":10.0,"bound_z2":12.0},
+            {"name":"in hold","mass":60.0,"general_category":"cargo","timber":false,"is_on_deck":false,
+             "bound_x1":0.0,"bound_x2":10.0,"bound_y1":0.0,"bound_y2":2.0,"bound_z1":1.0,"bound_z2":3.0,"horizontal_area":15.0},
+            {"name":"no bounds","mass":100.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+             "bound_x1":0.0,"bound_x2":10.0},
+            {"name":"ballast","mass":100.0,"general_category":"ballast","timber":false,"is_on_deck":true,
+             "bound_x1":0.0,"bound_x2":10.0,"bound_y1":0.0,"bound_y2":1.0,"bound_z1":10.0,"bound_z2":12.0}
+        ]"#).unwrap();
+        let compartments: Vec<CompartmentData> = serde_json::from_str(r#"[
+            {"space_id":1,"name":"bulk","mass":480.0,"bound_x1":10.0,"bound_x2":20.0,"general_category":"cargo","matter_type":"bulk"},
+            {"space_id":2,"name":"tank","mass":100.0,"bound_x1":0.0,"bound_x2":10.0,"general_category":"ballast","matter_type":"liquid"}
+        ]"#).unwrap();
+        let result = DeckLoad::new(&zones).check(&cargoes, &compartments).unwrap();
+        let target = vec![
+            check("over", "deck 1", 3., 2.),
+            check("over", "deck 2", 3., 1.),
+            check("under", "deck 1", 2., 2.),
+            check("in hold", "tank top starboard", 4., 5.),
+            check("bulk", "tank top port", 6., 5.),
+            check("bulk", "tank top starboard", 6., 5.),
+        ];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        let result: Vec<bool> = result.iter().map(|v| v.is_ok()).collect();
+        let target = vec![false, false, true, true, false, false];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        // the cargo without the area
+        let cargoes: Vec<LoadCargo> = serde_json::from_str(r#"[
+            {"name":"no area","mass":10.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+             "bound_x1":0.0,"bound_x2":10.0,"bound_y1":0.0,"bound_y2":2.0,"horizontal_area":0.0,"vertical_area":1.0}
+        ]"#).unwrap();
+        let result = DeckLoad::new(&zones).check(&cargoes, &[]);
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: error", result);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+
+mod hold {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        load::{Hold, MovableBulkhead},
+        loads::{Bulkhead, BulkheadPlaceData, CargoGeneralCategory, HoldData},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'Hold::parts' with 'MovableBulkhead::move_to'
+    #[test]
+    fn parts() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "hold";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        // box hold 30 m long with 100 m^3 per metre
+        let hold = Hold::new(
+            HoldData { id: 1, name: "hold_1".to_owned(), bound_x1: 0., bound_x2: 30. },
+            &[(0., 0.), (30., 3000.)],
+        ).unwrap();
+        let places: Vec<_> = [(1, 10.), (2, 20.)]
+            .into_iter()
+            .map(|(position, x)| BulkheadPlaceData { bulkhead_id: 7, hold_id: 1, position, x })
+            .collect();
+        let bulkhead = Bulkhead {
+            id: Some(7),
+            position: Some(1),
+            name: "grain_bulkhead".to_owned(),
+            mass: Some(5.),
+            general_category: CargoGeneralCategory::Bulkhead,
+            bound_x1: 9.8,
+            bound_x2: 10.2,
+            mass_shift_x: Some(10.),
+            mass_shift_y: Some(0.),
+            mass_shift_z: Some(4.),
+        };
+        let mut bulkheads = vec![MovableBulkhead::new(bulkhead, &places).unwrap()];
+        let result: Vec<_> = hold.parts(&bulkheads).unwrap().iter().map(|v| (v.volume, v.shift_x)).collect();
+        let target = vec![(1000., 5.), (2000., 20.)];
+        assert!(result.iter().zip(&target).all(|(r, t)| (r.0 - t.0).abs() < 1e-6 && (r.1 - t.1).abs() < 1e-4), "step {} \nresult: {:?}\ntarget: {:?}", 1, result, target);
+        assert!(bulkheads[0].move_to(3).is_err());
+        bulkheads[0].move_to(2).unwrap();
+        let result: Vec<_> = hold.parts(&bulkheads).unwrap().iter().map(|v| (v.volume, v.shift_x)).collect();
+        let target = vec![(2000., 10.), (1000., 25.)];
+        assert!(result.iter().zip(&target).all(|(r, t)| (r.0 - t.0).abs() < 1e-6 && (r.1 - t.1).abs() < 1e-4), "step {} \nresult: {:?}\ntarget: {:?}", 2, result, target);
+        let cargo = bulkheads[0].cargo();
+        let result = (cargo.bound_x1, cargo.bound_x2, cargo.mass_shift_x, cargo.mass);
+        let target = (19.8, 20.2, Some(20.), Some(5.));
+        assert!((result.0 - target.0).abs() < 1e-6 && (result.1 - target.1).abs() < 1e-6 && result.2 == target.2 && result.3 == target.3, "step {} \nresult: {:?}\ntarget: {:?}", 3, result, target);
+        // hold narrowing aft: 1 m^3 per metre on [0, 10], 3 m^3 per metre on [10, 20],
+        // centroid (50 + 450) / 40 = 12.5 differs from the volume median 13.33
+        let hold = Hold::new(
+            HoldData { id: 2, name: "hold_2".to_owned(), bound_x1: 0., bound_x2: 20. },
+            &[(0., 0.), (10., 10.), (20., 40.)],
+        ).unwrap();
+        let result: Vec<_> = hold.parts(&[]).unwrap().iter().map(|v| (v.volume, v.shift_x)).collect();
+        let target = vec![(40., 12.5)];
+        assert!(result.iter().zip(&target).all(|(r, t)| (r.0 - t.0).abs() < 1e-6 && (r.1 - t.1).abs() < 1e-6), "step {} \nresult: {:?}\ntarget: {:?}", 4, result, target);
+        test_duration.exit();
+    }
+}
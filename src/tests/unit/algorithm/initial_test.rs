@@ -0,0 +1,100 @@
+#[cfg(test)]
+
+mod initial {
+    use std::{collections::BTreeMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            initial::{initial::Initial, initial_ctx::InitialCtx},
+        },
+        infrostructure::api::client::{api_client::ApiClient, api_replies::ApiReplies},
+        kernel::{error::eval_error::EvalErrorKind, eval::Eval},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Recorded replies of the database for the ship 7
+    /// - 'frames' - reply to the query of the frames
+    fn replies(frames: &str) -> BTreeMap<String, String> {
+        let empty = r#"{"data":[],"error":{}}"#;
+        let absent = |table: &str| format!(r#"{{"data":[],"error":{{"message":"relation \"{table}\" does not exist"}}}}"#);
+        BTreeMap::from([
+            ("SELECT index, start_x, end_x FROM computed_frame_space WHERE ship_id=7;".to_owned(), frames.to_owned()),
+            ("SELECT revision FROM ship WHERE id=7;".to_owned(), r#"{"data":[{"revision":5}],"error":{}}"#.to_owned()),
+            ("SELECT name, ship_type, navigation_area, p_v, m, freeboard_type FROM ship WHERE id=7;".to_owned(), empty.to_owned()),
+            ("SELECT key, value FROM ship_parameters WHERE ship_id=7;".to_owned(), absent("ship_parameters")),
+            ("SELECT mass, bound_x1, bound_x2, loading_type FROM load_constant WHERE ship_id=7;".to_owned(), empty.to_owned()),
+            ("SELECT space_id, sounding, volume, shift_x, shift_y, shift_z, m_f_s_y, m_f_s_x FROM tank_sounding WHERE ship_id=7;".to_owned(), absent("tank_sounding")),
+            ("SELECT frame_x, value FROM torsion_limit WHERE ship_id=7;".to_owned(), r#"{"data":[{"frame_x":0.0,"value":100.0}],"error":{}}"#.to_owned()),
+            ("SELECT area, frame_x, bending_max, bending_min, shear_max, shear_min FROM girder_limit WHERE ship_id=7;".to_owned(), empty.to_owned()),
+            ("SELECT area, value FROM wave_load_reduction WHERE ship_id=7;".to_owned(), absent("wave_load_reduction")),
+            ("SELECT index, w_deck, w_bottom, shear_area, steel_grade FROM section_modulus WHERE ship_id=7;".to_owned(), empty.to_owned()),
+            ("SELECT name, is_tank_top, bound_x1, bound_x2, bound_y1, bound_y2, max_load FROM deck_zone WHERE ship_id=7;".to_owned(), absent("deck_zone")),
+            ("SELECT id, name, bound_x1, bound_x2 FROM hold WHERE ship_id=7;".to_owned(), empty.to_owned()),
+            ("SELECT bay, row, is_on_deck, max_mass FROM container_stack_limit WHERE ship_id=7;".to_owned(), absent("container_stack_limit")),
+            ("SELECT trim, volume, value FROM mean_draught WHERE ship_id=7;".to_owned(), absent("mean_draught")),
+            ("SELECT frame_index, pos_x FROM bonjean_frame WHERE ship_id=7;".to_owned(), empty.to_owned()),
+            ("SELECT draught, area, moment_x, moment_z FROM vertical_area_stability WHERE ship_id=7;".to_owned(), absent("vertical_area_stability")),
+            ("SELECT key, value FROM icing WHERE ship_id=7;".to_owned(), empty.to_owned()),
+        ])
+    }
+    ///
+    /// Testing 'Initial' with the optional tables absent or empty
+    #[tokio::test(flavor = "multi_thread")]
+    async fn optional_tables() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "initial";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let api_client = ApiClient::new("none".to_owned(), "0.0.0.0".to_owned(), "0".to_owned());
+        let frames = r#"{"data":[{"index":1,"start_x":1.0,"end_x":2.0},{"index":0,"start_x":0.0,"end_x":1.0}],"error":{}}"#;
+        let ctx = Context::new(InitialCtx::new(7)).with_api_replies(ApiReplies::replay(replies(frames)));
+        let ctx = Initial::new(dbg, api_client.clone()).eval(ctx).await.unwrap();
+        let result: InitialCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.bounds == Some(vec![(0., 1.), (1., 2.)]), "step {} \nresult: {:?}", 1, result.bounds);
+        assert!(result.torsion_limits == Some(vec![(0., 100.)]), "step {} \nresult: {:?}", 2, result.torsion_limits);
+        assert!(result.revision == Some(5), "step {} \nresult: {:?}", 2, result.revision);
+        let result = [
+            result.ship.is_none(),
+            result.ship_parameters.is_none(),
+            result.load_constants.is_none(),
+            result.sounding_tables.is_none(),
+            result.girder_limits.is_none(),
+            result.wave_reductions.is_none(),
+            result.section_moduli.is_none(),
+            result.deck_zones.is_none(),
+            result.hold_tables.is_none(),
+            result.container_stack_limits.is_none(),
+            result.hydrostatics.is_none(),
+            result.bonjean.is_none(),
+            result.windage.is_none(),
+            result.icing.is_none(),
+        ];
+        assert!(result.iter().all(|v| *v), "step {} \nresult: {:?}", 3, result);
+        // the frames are required
+        let frames = r#"{"data":[],"error":{"message":"relation \"computed_frame_space\" does not exist"}}"#;
+        let ctx = Context::new(InitialCtx::new(7)).with_api_replies(ApiReplies::replay(replies(frames)));
+        let result = Result::<Option<Context>, _>::from(Initial::new(dbg, api_client).eval(ctx).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "computed_frame_space".to_owned(), ship_id: 7 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 4, result, target);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,416 @@
+#[cfg(test)]
+
+mod loading {
+    use std::{collections::HashMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            entities::{
+                load::{DeckLoadCheck, StowageViolation, VcfTable, VolumeCorrection},
+                loads::{BulkheadPlaceData, ContainerStackLimit, DeckZoneData, HoldData, HoldTables, LoadConstantData, LoadConstantType, SoundingData, SoundingTables},
+                Ship, Voyage,
+            },
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_ctx::LoadingCtx, loading_step::Loading},
+            parameters::{parameters_ctx::ParametersCtx, parameters_step::Parameters},
+            pipeline::executor::Pipeline,
+        },
+        kernel::{error::eval_error::EvalErrorKind, eval::Eval},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Two cargoes and the ballast tank
+    const CHANGES: &str = r#"{
+        "cargoes": [
+            {"name":"a","mass":100.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":10.0,
+             "mass_shift_x":5.0,"mass_shift_y":0.0,"mass_shift_z":2.0},
+            {"name":"b","mass":50.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":5.0,"bound_x2":15.0,
+             "mass_shift_x":10.0,"mass_shift_y":1.0,"mass_shift_z":4.0}
+        ],
+        "compartments": [
+            {"space_id":1,"name":"tank","mass":20.0,"density":1.0,"volume":20.0,"bound_x1":10.0,"bound_x2":20.0,
+             "mass_shift_x":15.0,"mass_shift_y":-1.0,"mass_shift_z":1.0,"m_f_s_x":30.0,"m_f_s_y":200.0,
+             "general_category":"ballast","matter_type":"liquid"}
+        ]
+    }"#;
+    ///
+    /// Testing 'Loading' step and its evaluation on the changed loading
+    #[tokio::test(flavor = "multi_thread")]
+    async fn eval() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let initial = InitialCtx { bounds: Some(vec![(0., 10.), (10., 20.)]), ..InitialCtx::new(1) };
+        let changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = LoadingCtx {
+            mass: 170.,
+            shift_x: 1300. / 170.,
+            shift_y: 30. / 170.,
+            shift_z: 420. / 170.,
+            surface_moment_x: 30.,
+            surface_moment_y: 200.,
+            bulk_moment: 0.,
+            windage_area: 0.,
+            windage_moment_z: 0.,
+            deck_tops: vec![],
+            frames: vec![(0., 10.), (10., 20.)],
+            frame_mass: vec![125., 45.],
+            frame_moment_y: vec![25., 5.],
+            deck_loads: vec![],
+            stowage: vec![],
+        };
+        assert!(
+            (result.mass - target.mass).abs() < 1e-6
+                && (result.shift_x - target.shift_x).abs() < 1e-6
+                && (result.shift_y - target.shift_y).abs() < 1e-6
+                && (result.shift_z - target.shift_z).abs() < 1e-6
+                && result.surface_moment_x == target.surface_moment_x
+                && result.surface_moment_y == target.surface_moment_y,
+            "\nresult: {:?}\ntarget: {:?}", result, target,
+        );
+        assert!(
+            result.frame_mass.iter().zip(&target.frame_mass).all(|(result, target)| (result - target).abs() < 1e-6),
+            "\nresult: {:?}\ntarget: {:?}", result.frame_mass, target.frame_mass,
+        );
+        assert!(
+            result.frame_moment_y.iter().zip(&target.frame_moment_y).all(|(result, target)| (result - target).abs() < 1e-6),
+            "\nresult: {:?}\ntarget: {:?}", result.frame_moment_y, target.frame_moment_y,
+        );
+        // removed cargo recalculates the loading
+        let changes = ChangeSet { removed_cargoes: vec!["b".to_owned()], ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = (120., vec![100., 20.]);
+        assert!(
+            (result.mass - target.0).abs() < 1e-6 && result.frame_mass == target.1,
+            "\nresult: {:?}\ntarget: {:?}", (result.mass, &result.frame_mass), target,
+        );
+        test_duration.exit();
+    }
+    ///
+    /// Oil tanker with the constant load and the sounded cargo tank 10 x 4 x 2 m
+    fn tanker() -> InitialCtx {
+        let table = [0., 1., 2.].into_iter().map(|sounding| SoundingData {
+            space_id: 2,
+            sounding,
+            volume: 40. * sounding,
+            shift_x: 15.,
+            shift_y: 0.,
+            shift_z: sounding / 2.,
+            m_f_s_y: 4. * 1000. / 12.,
+            m_f_s_x: 10. * 64. / 12.,
+        }).collect();
+        InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            ship: Some(Ship {
+                name: "tanker".to_owned(),
+                ship_type: "oil tanker".to_owned(),
+                navigation_area: "R2".to_owned(),
+                p_v: 0.,
+                m: 0.,
+                freeboard_type: "B".to_owned(),
+            }),
+            ship_parameters: Some(HashMap::from([
+                ("LCG from middle".to_owned(), 5.),
+                ("TCG from CL".to_owned(), 0.),
+                ("VCG from BL".to_owned(), 3.),
+            ])),
+            load_constants: Some(vec![LoadConstantData {
+                mass: 100.,
+                bound_x1: 0.,
+                bound_x2: 10.,
+                loading_type: LoadConstantType::Hull,
+            }]),
+            sounding_tables: Some(SoundingTables {
+                table: HashMap::from([(2, table)]),
+                trim_correction: HashMap::from([(2, vec![(0., vec![(0., 0.), (2., 0.)]), (1., vec![(0., 0.1), (2., 0.1)])])]),
+                heel_correction: HashMap::new(),
+            }),
+            ..InitialCtx::new(1)
+        }
+    }
+    ///
+    /// Testing 'Loading' with the tank mass by the sounding, corrected by the trim and the temperature
+    #[tokio::test(flavor = "multi_thread")]
+    async fn soundings() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading_soundings";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut changes: ChangeSet = serde_json::from_str(r#"{
+            "compartments": [
+                {"space_id":2,"name":"cargo tank","bound_x1":10.0,"bound_x2":20.0,"sounding":1.0,"temperature":25.0,"density_15":0.85,
+                 "general_category":"cargo","matter_type":"liquid"}
+            ]
+        }"#).unwrap();
+        changes.voyage = Some(Voyage { sounding_trim: Some(1.), ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(tanker())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        // sounding 1.0 m + 0.1 m correction on the trim 1.0 m
+        let density = VolumeCorrection::new(VcfTable::Products, 0.85).unwrap().density(25.);
+        let tank = 44. * density;
+        let target = (100. + tank, (500. + 15. * tank) / (100. + tank), (300. + 0.55 * tank) / (100. + tank), 10. * 64. / 12. * density);
+        let result_values = (result.mass, result.shift_x, result.shift_z, result.surface_moment_x);
+        assert!(
+            (result_values.0 - target.0).abs() < 1e-6
+                && (result_values.1 - target.1).abs() < 1e-6
+                && (result_values.2 - target.2).abs() < 1e-6
+                && (result_values.3 - target.3).abs() < 1e-6,
+            "\nresult: {:?}\ntarget: {:?}", result_values, target,
+        );
+        assert!(
+            (result.frame_mass[0] - 100.).abs() < 1e-6 && (result.frame_mass[1] - tank).abs() < 1e-6,
+            "\nresult: {:?}\ntarget: {:?}", result.frame_mass, [100., tank],
+        );
+        // the voyage without the trim at the soundings
+        let changes = ChangeSet { voyage: Some(Voyage::default()), ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = 100. + 40. * density;
+        assert!((result.mass - target).abs() < 1e-6, "\nresult: {:?}\ntarget: {:?}", result.mass, target);
+        // the center of the constant load is required
+        let initial = InitialCtx { ship_parameters: None, ..tanker() };
+        let ctx = ChangeSet::default().apply_full(Context::new(initial)).unwrap();
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx).await);
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: error", result.map(|_| ()));
+        test_duration.exit();
+    }
+    ///
+    /// Hold 0...20 m of 200 m^3, divided by the movable bulkhead in the place 1 (x = 5 m) or 2 (x = 10 m)
+    const HOLD_CHANGES: &str = r#"{
+        "compartments": [
+            {"space_id":3,"name":"hold part 1","mass":60.0,"bound_x1":0.0,"bound_x2":20.0,"hold_id":1,"part_index":0,
+             "mass_shift_x":0.0,"mass_shift_y":0.0,"mass_shift_z":1.0,"general_category":"cargo","matter_type":"solid"},
+            {"space_id":4,"name":"hold part 2","mass":40.0,"bound_x1":0.0,"bound_x2":20.0,"hold_id":1,"part_index":1,
+             "mass_shift_x":0.0,"mass_shift_y":0.0,"mass_shift_z":1.0,"general_category":"cargo","matter_type":"solid"}
+        ],
+        "bulkheads": [
+            {"id":1,"position":2,"name":"bulkhead","mass":10.0,"general_category":"bulkhead","bound_x1":0.0,"bound_x2":1.0,
+             "mass_shift_x":0.5,"mass_shift_y":0.0,"mass_shift_z":1.0}
+        ]
+    }"#;
+    ///
+    /// Testing 'Loading' with the hold parts bounded by the movable bulkhead
+    #[tokio::test(flavor = "multi_thread")]
+    async fn holds() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading_holds";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let initial = InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            hold_tables: Some(HoldTables {
+                holds: vec![HoldData { id: 1, name: "hold".to_owned(), bound_x1: 0., bound_x2: 20. }],
+                capacity: HashMap::from([(1, vec![(0., 0.), (20., 200.)])]),
+                places: [(1, 5.), (2, 10.)].into_iter()
+                    .map(|(position, x)| BulkheadPlaceData { bulkhead_id: 1, hold_id: 1, position, x })
+                    .collect(),
+            }),
+            ..InitialCtx::new(1)
+        };
+        let mut changes: ChangeSet = serde_json::from_str(HOLD_CHANGES).unwrap();
+        let ctx = changes.apply_full(Context::new(initial.clone())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        // parts 0...10 and 10...20 m, the bulkhead 9.5...10.5 m
+        let target = (110., (60. * 5. + 40. * 15. + 10. * 10.) / 110., vec![65., 45.]);
+        let result_values = (result.mass, result.shift_x, result.frame_mass.clone());
+        assert!(
+            (result_values.0 - target.0).abs() < 1e-6
+                && (result_values.1 - target.1).abs() < 1e-6
+                && result_values.2.iter().zip(&target.2).all(|(result, target)| (result - target).abs() < 1e-6),
+            "\nresult: {:?}\ntarget: {:?}", result_values, target,
+        );
+        // the bulkhead is moved to the place 1, parts 0...5 and 5...20 m
+        changes.compartments.clear();
+        changes.bulkheads[0].position = Some(1);
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = (110., (60. * 2.5 + 40. * 12.5 + 10. * 5.) / 110., vec![60. + 40. / 3. + 10., 80. / 3.]);
+        let result_values = (result.mass, result.shift_x, result.frame_mass.clone());
+        assert!(
+            (result_values.0 - target.0).abs() < 1e-6
+                && (result_values.1 - target.1).abs() < 1e-6
+                && result_values.2.iter().zip(&target.2).all(|(result, target)| (result - target).abs() < 1e-6),
+            "\nresult: {:?}\ntarget: {:?}", result_values, target,
+        );
+        // the place of the bulkhead is unknown
+        changes.bulkheads[0].position = Some(3);
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let result = Result::<Option<Context>, _>::from(pipeline.eval_changes(ctx, &changed).await);
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: error", result.map(|_| ()));
+        // the hold parts without the holds
+        let ctx = serde_json::from_str::<ChangeSet>(HOLD_CHANGES).unwrap()
+            .apply_full(Context::new(InitialCtx { hold_tables: None, ..initial })).unwrap();
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx).await);
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: error", result.map(|_| ()));
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Loading' with the deck cargo checked by the allowed load of the deck zone
+    #[tokio::test(flavor = "multi_thread")]
+    async fn deck_loads() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading_deck_loads";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let initial = InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            deck_zones: Some(vec![DeckZoneData {
+                name: "deck".to_owned(),
+                is_tank_top: false,
+                bound_x1: 0.,
+                bound_x2: 20.,
+                bound_y1: -5.,
+                bound_y2: 5.,
+                max_load: 2.,
+            }]),
+            ..InitialCtx::new(1)
+        };
+        let changes: ChangeSet = serde_json::from_str(r#"{
+            "cargoes": [
+                {"name":"deck cargo","mass":50.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+                 "bound_x1":0.0,"bound_x2":10.0,"bound_y1":0.0,"bound_y2":2.0,"bound_z1":10.0,"bound_z2":12.0}
+            ]
+        }"#).unwrap();
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = vec![DeckLoadCheck { cargo: "deck cargo".to_owned(), zone: "deck".to_owned(), load: 2.5, limit: 2. }];
+        assert!(result.deck_loads == target, "\nresult: {:?}\ntarget: {:?}", result.deck_loads, target);
+        assert!(!result.deck_loads[0].is_ok(), "\nresult: {:?}\ntarget: over the limit", result.deck_loads[0]);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Loading' with the containers in the hold: 20' on top of 40' in the overloaded stack
+    #[tokio::test(flavor = "multi_thread")]
+    async fn containers() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading_containers";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let initial = InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            container_stack_limits: Some(vec![ContainerStackLimit { bay: 2, row: 1, is_on_deck: false, max_mass: 20. }]),
+            ..InitialCtx::new(1)
+        };
+        let changes: ChangeSet = serde_json::from_str(r#"{
+            "containers": [
+                {"id":1,"mass":15.0,"general_category":"cargo","bound_x1":0.0,"bound_x2":12.0,"bound_y1":0.0,"bound_y2":2.5,
+                 "bound_z1":0.0,"bound_z2":2.5,"mass_shift_x":6.0,"mass_shift_y":1.25,"mass_shift_z":1.25,"is_on_deck":false,
+                 "bay":2,"row":1,"tier":2,"iso_code":"42G1"},
+                {"id":2,"mass":10.0,"general_category":"cargo","bound_x1":0.0,"bound_x2":6.0,"bound_y1":0.0,"bound_y2":2.5,
+                 "bound_z1":2.5,"bound_z2":5.0,"mass_shift_x":3.0,"mass_shift_y":1.25,"mass_shift_z":3.75,"is_on_deck":false,
+                 "bay":1,"row":1,"tier":4,"iso_code":"22G1"}
+            ]
+        }"#).unwrap();
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = vec![
+            StowageViolation::Mixing { lower: 1, upper: 2 },
+            StowageViolation::StackMass { bay: 2, row: 1, is_on_deck: false, mass: 25., limit: 20. },
+        ];
+        assert!(result.stowage == target, "\nresult: {:?}\ntarget: {:?}", result.stowage, target);
+        let target = (25., vec![15. * 10. / 12. + 10., 15. * 2. / 12.]);
+        assert!(
+            (result.mass - target.0).abs() < 1e-6
+                && result.frame_mass.iter().zip(&target.1).all(|(result, target)| (result - target).abs() < 1e-6),
+            "\nresult: {:?}\ntarget: {:?}", (result.mass, &result.frame_mass), target,
+        );
+        // the removed container
+        let changes = ChangeSet { removed_containers: vec![2], ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        assert!(
+            result.stowage.is_empty() && (result.mass - 15.).abs() < 1e-6,
+            "\nresult: {:?}\ntarget: {:?}", (result.mass, &result.stowage), (15., "no violations"),
+        );
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Loading' with the masses distributed by the configured number of frames
+    #[tokio::test(flavor = "multi_thread")]
+    async fn frames() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "loading_frames";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let initial = InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            ship_parameters: Some(HashMap::from([("L.O.A".to_owned(), 20.), ("X midship from Fr0".to_owned(), 0.)])),
+            ..InitialCtx::new(1)
+        };
+        let changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        let parameters = ParametersCtx { frames: Some(4), ..ParametersCtx::default() };
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, parameters)).step(Loading::new(dbg));
+        let ctx = pipeline.eval(changes.apply_full(Context::new(initial.clone())).unwrap()).await.unwrap();
+        let result: LoadingCtx = ContextRead::read(&ctx).unwrap();
+        let target = vec![(0., 5.), (5., 10.), (10., 15.), (15., 20.)];
+        assert!(result.frames == target, "step {} \nresult: {:?}\ntarget: {:?}", 1, result.frames, target);
+        let target = vec![50., 75., 35., 10.];
+        assert!(
+            result.frame_mass.len() == target.len() && result.frame_mass.iter().zip(&target).all(|(result, target)| (result - target).abs() < 1e-6),
+            "step {} \nresult: {:?}\ntarget: {:?}", 2, result.frame_mass, target,
+        );
+        // the length of the ship is required
+        let initial = InitialCtx { ship_parameters: None, ..initial };
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(changes.apply_full(Context::new(initial)).unwrap()).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "ship_parameters".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 3, result, target);
+        test_duration.exit();
+    }
+}
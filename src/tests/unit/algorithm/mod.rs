@@ -0,0 +1,28 @@
+mod bulk_heel_test;
+mod bundle_test;
+mod cache_test;
+mod context_test;
+mod ctx_result_test;
+mod deck_load_test;
+mod hold_test;
+mod initial_test;
+mod loading_test;
+mod parametric_roll_test;
+mod pipeline_conf_test;
+mod pipeline_test;
+mod roll_period_test;
+mod root_test;
+mod second_generation_test;
+mod session_test;
+mod squat_test;
+mod stability_test;
+mod stored_loading_test;
+mod stowage_test;
+mod strength_test;
+mod stress_test;
+mod tank_calibration_test;
+mod torsion_test;
+mod visibility_test;
+mod volume_correction_test;
+mod wave_load_test;
+mod wind_heel_test;
@@ -0,0 +1,75 @@
+#[cfg(test)]
+
+mod parametric_roll {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::second_generation::{Criterion, ParametricRoll};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'ParametricRoll::delta_gm', 'ParametricRoll::limit' and 'ParametricRoll::check'
+    #[test]
+    fn parametric_roll() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "parametric_roll";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        // r(d) = d, V(d) = 1000d, I(d) = 1000d^2
+        let rad_trans = [(0., 0.), (10., 10.)];
+        let volume = [(0., 0.), (10., 10000.)];
+        let roll = ParametricRoll::new(100., 20., 5., 8., 10., 0.9, 0., &rad_trans, &volume).unwrap();
+        // ΔD_H = min(10 - 5, 100*0.0167/2) = 0.835, ΔD_L = min(5 - 0.25*8, 100*0.0167/2) = 0.835
+        let target = 1000. * (5.835f64.powi(2) - 4.165f64.powi(2)) / (2. * 5000.);
+        let result = roll.delta_gm().unwrap();
+        assert!((result - target).abs() < 1.0e-6, "\nresult: {:?}\ntarget: {:?}", result, target);
+        assert!((roll.limit() - 0.17).abs() < 1.0e-9);
+        let check = roll.check(2.).unwrap();
+        assert_eq!((check.id, check.relation.as_str()), (Criterion::ParametricRoll.id(), "<"));
+        assert!(!check.is_ok(), "\ncheck: {:?}", check);
+        assert!(roll.check(0.).is_err());
+        // ΔD_H ограничено высотой борта: min(5.5 - 5, 0.835) = 0.5
+        let roll = ParametricRoll::new(100., 20., 5., 8., 5.5, 0.9, 0., &rad_trans, &volume).unwrap();
+        let target = 1000. * (5.5f64.powi(2) - 4.165f64.powi(2)) / (2. * 5000.);
+        let result = roll.delta_gm().unwrap();
+        assert!((result - target).abs() < 1.0e-6, "\nresult: {:?}\ntarget: {:?}", result, target);
+        // 100*A_k/(LB) = 1, R_PR = 0.17 + 0.425 = 0.595
+        let roll = ParametricRoll::new(100., 20., 5., 8., 10., 0.98, 20., &rad_trans, &volume).unwrap();
+        assert!((roll.limit() - 0.595).abs() < 1.0e-9);
+        assert!(roll.check(10.).unwrap().is_ok());
+        // C_m <= 0.94: R_PR = 0.17 + 0.2125 * 1 = 0.3825
+        let roll = ParametricRoll::new(100., 20., 5., 8., 10., 0.9, 20., &rad_trans, &volume).unwrap();
+        assert!((roll.limit() - 0.3825).abs() < 1.0e-9, "\nlimit: {:?}", roll.limit());
+        // ΔGM1 = 1.67, ΔGM1/GM = 1.67 / 5 = 0.334 < 0.3825
+        assert!(roll.check(5.).unwrap().is_ok());
+        // ΔGM1/GM = 1.67 / 4 = 0.4175 >= 0.3825
+        assert!(!roll.check(4.).unwrap().is_ok());
+        // формула непрерывна при C_m = 0.94
+        let below = ParametricRoll::new(100., 20., 5., 8., 10., 0.94, 20., &rad_trans, &volume).unwrap();
+        let above = ParametricRoll::new(100., 20., 5., 8., 10., 0.94 + 1.0e-9, 20., &rad_trans, &volume).unwrap();
+        assert!((below.limit() - above.limit()).abs() < 1.0e-6);
+        // осадка менее четверти осадки по грузовую марку
+        let roll = ParametricRoll::new(100., 20., 1.5, 8., 10., 0.9, 0., &rad_trans, &volume).unwrap();
+        assert!(roll.delta_gm().is_err());
+        // палуба входит в воду на тихой воде
+        let roll = ParametricRoll::new(100., 20., 5., 8., 5., 0.9, 0., &rad_trans, &volume).unwrap();
+        assert!(roll.delta_gm().is_err());
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+
+mod pipeline_conf {
+    use std::{path::PathBuf, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            initial::initial_ctx::InitialCtx,
+            parameters::{parameters_step::Parameters, parameters_ctx::ParametersCtx},
+            pipeline::{executor::Pipeline, pipeline_conf::{PipelineConf, StepConf}},
+        },
+        kernel::eval::Eval,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'PipelineConf' parsed from yaml
+    #[test]
+    fn parse() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "pipeline_conf_parse";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let test_data = [
+            (
+                r#"
+                steps:
+                    - step: initial
+                    - step: areas_strength
+                    - step: loading
+                    - step: stability
+                    - step: strength
+                "#,
+                PipelineConf::default(),
+            ),
+            (
+                r#"
+                cache: ./cache
+                cache_capacity: 16
+                trace: ./trace.json
+                steps:
+                    - step: initial
+                    - step: parameters
+                      criteria: [1, 5]
+                      icing: false
+                      frames: 20
+                "#,
+                PipelineConf {
+                    steps: vec![
+                        StepConf::Initial,
+                        StepConf::Parameters(ParametersCtx { criteria: Some(vec![1, 5]), icing: false, frames: Some(20) }),
+                    ],
+                    cache: Some(PathBuf::from("./cache")),
+                    cache_capacity: Some(16),
+                    trace: Some(PathBuf::from("./trace.json")),
+                    bundle: None,
+                },
+            ),
+            (
+                r#"
+                steps:
+                    - step: parameters
+                "#,
+                PipelineConf {
+                    steps: vec![StepConf::Parameters(ParametersCtx::default())],
+                    cache: None,
+                    cache_capacity: None,
+                    trace: None,
+                    bundle: None,
+                },
+            ),
+        ];
+        for (step, (yaml, target)) in test_data.into_iter().enumerate() {
+            let result: PipelineConf = serde_yaml::from_str(yaml).unwrap();
+            assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        let result: Result<PipelineConf, _> = serde_yaml::from_str("steps:\n    - step: unknown\n");
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: Err", result);
+        // the icing is taken into account
+        let result: PipelineConf = serde_yaml::from_str("steps:\n    - step: parameters\n      icing: true\n").unwrap();
+        let target = vec![StepConf::Parameters(ParametersCtx { icing: true, ..ParametersCtx::default() })];
+        assert!(result.steps == target, "\nresult: {:?}\ntarget: {:?}", result.steps, target);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Parameters' step writes the configured parameters
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parameters() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "pipeline_conf_parameters";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let target = ParametersCtx { criteria: Some(vec![2, 3]), icing: false, frames: None };
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, target.clone()));
+        let ctx = pipeline.eval(Context::new(InitialCtx::default())).await.unwrap();
+        let result: ParametersCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        let result = (result.is_enabled(2), result.is_enabled(4));
+        assert!(result == (true, false), "\nresult: {:?}\ntarget: {:?}", result, (true, false));
+        assert!(ParametersCtx::default().is_enabled(4));
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(test)]
+
+mod pipeline {
+    use std::{sync::Once, time::{Duration, Instant}};
+    use futures::future::BoxFuture;
+    use serde::Serialize;
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::{ContextRead, ContextWrite}, ctx_result::CtxResult, ctx_store::{CtxType, StepCtx}},
+            initial::initial_ctx::InitialCtx,
+            pipeline::{executor::Pipeline, step::Step, trace::Outcome},
+        },
+        kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Test context of the step 'A'
+    #[derive(Debug, Clone, Serialize)]
+    struct ACtx(f64);
+    impl StepCtx for ACtx {
+        const STEP: &'static str = "A";
+    }
+    ///
+    /// Test context of the step 'B'
+    #[derive(Debug, Clone, Serialize)]
+    struct BCtx(f64);
+    impl StepCtx for BCtx {
+        const STEP: &'static str = "B";
+    }
+    ///
+    /// Test context of the step 'Sum'
+    #[derive(Debug, Clone, Serialize)]
+    struct SumCtx(f64);
+    impl StepCtx for SumCtx {
+        const STEP: &'static str = "Sum";
+    }
+    ///
+    /// Writes the value after the delay
+    struct Delay<T> {
+        dbg: DbgId,
+        value: T,
+    }
+    impl<T: StepCtx + Clone> Eval<Context, EvalResult> for Delay<T> {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                ctx.write(self.value.clone())
+            })
+        }
+    }
+    impl<T: StepCtx + Clone> Step for Delay<T> {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<T>()]
+        }
+    }
+    ///
+    /// Writes the sum of 'A' and 'B'
+    struct Sum {
+        dbg: DbgId,
+    }
+    impl Eval<Context, EvalResult> for Sum {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                let a: Result<ACtx, _> = ContextRead::read(&ctx);
+                let b: Result<BCtx, _> = ContextRead::read(&ctx);
+                match (a, b) {
+                    (Ok(a), Ok(b)) => ctx.write(SumCtx(a.0 + b.0)),
+                    (Err(err), _) | (_, Err(err)) => CtxResult::Err(err),
+                }
+            })
+        }
+    }
+    impl Step for Sum {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<ACtx>(), CtxType::of::<BCtx>()]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<SumCtx>()]
+        }
+    }
+    ///
+    /// Panics on the first evaluation, writes 'A' on the next ones
+    struct PanicOnce {
+        dbg: DbgId,
+        panicked: bool,
+    }
+    impl Eval<Context, EvalResult> for PanicOnce {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                if !self.panicked {
+                    self.panicked = true;
+                    panic!("PanicOnce.eval | Test panic");
+                }
+                ctx.write(ACtx(1.5))
+            })
+        }
+    }
+    impl Step for PanicOnce {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<ACtx>()]
+        }
+    }
+    ///
+    /// Testing 'Pipeline::order' and 'Pipeline::eval'
+    #[tokio::test(flavor = "multi_thread")]
+    async fn eval() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "pipeline";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let ctx = Context::new(InitialCtx::default());
+        let mut pipeline = Pipeline::new(dbg)
+            .step(Sum { dbg: DbgId("Sum".into()) })
+            .step(Delay { dbg: DbgId("A".into()), value: ACtx(1.5) })
+            .step(Delay { dbg: DbgId("B".into()), value: BCtx(2.5) });
+        let result = pipeline.order(&ctx).unwrap();
+        let target = vec![vec![1, 2], vec![0]];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        let time = Instant::now();
+        let result = pipeline.eval(ctx.clone()).await.unwrap();
+        let elapsed = time.elapsed();
+        assert!(elapsed < Duration::from_millis(350), "independent steps are not concurrent, elapsed: {:?}", elapsed);
+        let trace = result.trace();
+        assert!(trace.spans.len() == 4, "\nresult: {:?}\ntarget: 3 steps and pipeline", trace.spans);
+        let span = trace.spans.iter().find(|span| span.name == "A").unwrap();
+        assert!(span.duration_us >= 200_000 && span.outcome == Outcome::Ok, "\nresult: {:?}", span);
+        let span = trace.spans.iter().find(|span| span.name == "Sum").unwrap();
+        assert!(span.start_us >= 200_000 && span.lane == 1, "\nresult: {:?}", span);
+        let events = trace.to_chrome()["traceEvents"].as_array().map_or(0, |events| events.len());
+        assert!(events == 4, "\nresult: {:?}\ntarget: {:?}", events, 4);
+        let result: SumCtx = ContextRead::read(&result).unwrap();
+        assert!((result.0 - 4.).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result.0, 4.);
+        // the pipeline can be evaluated again
+        assert!(pipeline.eval(ctx.clone()).await.unwrap().contains(&CtxType::of::<SumCtx>()));
+        // 'B' is not produced by any step
+        let pipeline = Pipeline::new(dbg)
+            .step(Sum { dbg: DbgId("Sum".into()) })
+            .step(Delay { dbg: DbgId("A".into()), value: ACtx(1.5) });
+        assert!(pipeline.order(&ctx).is_err());
+        // 'A' is written by two steps
+        let pipeline = Pipeline::new(dbg)
+            .step(Delay { dbg: DbgId("A1".into()), value: ACtx(1.5) })
+            .step(Delay { dbg: DbgId("A2".into()), value: ACtx(2.5) });
+        assert!(pipeline.order(&ctx).is_err());
+        // the panicked step is returned as the error and kept in the pipeline
+        let mut pipeline = Pipeline::new(dbg)
+            .step(Sum { dbg: DbgId("Sum".into()) })
+            .step(PanicOnce { dbg: DbgId("A".into()), panicked: false })
+            .step(Delay { dbg: DbgId("B".into()), value: BCtx(2.5) });
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx.clone()).await);
+        let result = result.err().map(|err| err.to_string()).unwrap_or_default();
+        assert!(result.contains("Test panic"), "\nresult: {:?}\ntarget: the panic message", result);
+        let result = pipeline.eval(ctx.clone()).await.unwrap();
+        let result: SumCtx = ContextRead::read(&result).unwrap();
+        assert!((result.0 - 4.).abs() < 1e-9, "\nresult: {:?}\ntarget: {:?}", result.0, 4.);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+
+mod roll_period {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::roll_period::RollPeriod;
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'RollPeriod::period' and the inverse 'RollPeriod::h'
+    #[test]
+    fn roll_period() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "roll_period";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        assert!(RollPeriod::new(0., 0.4).is_err());
+        assert!(RollPeriod::new(20., 0.).is_err());
+        assert!(RollPeriod::from_hull(20., 0., 100.).is_err());
+        let c = 0.373 + 0.023 * 20. / 5. - 0.043 * 100. / 100.;
+        let test_data = [
+            // step, roll period, target c
+            (1, RollPeriod::new(20., 0.4).unwrap(), 0.4),
+            (2, RollPeriod::from_hull(20., 5., 100.).unwrap(), c),
+        ];
+        for (step, roll_period, c) in test_data {
+            let result = roll_period.period(1.44).unwrap();
+            let target = 2. * c * 20. / 1.2;
+            assert!(
+                (result.period - target).abs() < 1e-6 && result.h == 1.44 && (result.c - c).abs() < 1e-9 && result.width == 20.,
+                "step {} \nresult: {}\ntarget: {:?}", step, result, target,
+            );
+            // the observed period gives the metacentric height back
+            let result = roll_period.h(target).unwrap();
+            assert!((result.h - 1.44).abs() < 1e-6, "step {} \nresult: {}\ntarget: {:?}", step, result, 1.44);
+            assert!(roll_period.period(0.).is_err() && roll_period.h(0.).is_err(), "step {}", step);
+        }
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+
+mod root {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::math::first_root;
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'first_root'
+    #[test]
+    fn root() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "first_root";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let test_data: [(i32, fn(f64) -> f64, Option<f64>); 4] = [
+            // step, function, target root on 0..60
+            (1, |x| x.to_radians().sin() - 0.5, Some(30.)),
+            // the first of two roots
+            (2, |x| -(x - 10.) * (x - 20.), Some(10.)),
+            (3, |x| x + 1., Some(0.)),
+            (4, |x| -x - 1., None),
+        ];
+        for (step, f, target) in test_data {
+            let result = first_root(|x| Ok(f(x)), 0., 60., 0.1).unwrap();
+            assert!(
+                match (result, target) {
+                    (Some(result), Some(target)) => (result - target).abs() < 1e-6,
+                    (result, target) => result == target,
+                },
+                "step {} \nresult: {:?}\ntarget: {:?}", step, result, target,
+            );
+        }
+        assert!(first_root(|x| Ok(x), -1., 1., 0.).is_err(), "step {} \nzero step must be an error", 5);
+        test_duration.exit();
+    }
+}
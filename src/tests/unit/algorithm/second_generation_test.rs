@@ -0,0 +1,97 @@
+#[cfg(test)]
+
+mod second_generation {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use std::collections::HashMap;
+    use crate::algorithm::entities::{
+        icing_stab::FakeIcingStab,
+        second_generation::{Criterion, DeadShip, PureLoss},
+        stability::{VerticalArea, VerticalAreaArray},
+        wind_heel::WindHeel,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'PureLoss::gm_min', 'PureLoss::is_applicable' and 'PureLoss::check'
+    #[test]
+    fn pure_loss() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "pure_loss";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        // r(d) = d, V(d) = 1000d, I(d) = 1000d^2
+        let rad_trans = [(0., 0.), (10., 10.)];
+        let volume = [(0., 0.), (10., 10000.)];
+        let pure_loss = PureLoss::new(100., 5., 8., 2.6, &rad_trans, &volume).unwrap();
+        // ΔD = min(5 - 0.25*8, 100*0.0334/2) = 1.67
+        let target = 2.6 + 1000. * 3.33f64.powi(2) / 5000. - 4.7;
+        let result = pure_loss.gm_min(4.7).unwrap();
+        assert!((result - target).abs() < 1.0e-6, "\nresult: {:?}\ntarget: {:?}", result, target);
+        let check = pure_loss.check(4.7).unwrap();
+        assert_eq!((check.id, check.relation.as_str()), (Criterion::PureLoss.id(), ">="));
+        assert!(check.is_ok(), "\ncheck: {:?}", check);
+        assert!(!pure_loss.check(4.9).unwrap().is_ok());
+        assert!(pure_loss.is_applicable(7.6));
+        assert!(!pure_loss.is_applicable(7.));
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'DeadShip::steepness', 'DeadShip::areas' and 'DeadShip::check'
+    #[test]
+    fn dead_ship() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "dead_ship";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        assert!((DeadShip::steepness(5.).unwrap() - 0.1).abs() < 1.0e-9);
+        assert!((DeadShip::steepness(10.).unwrap() - 0.0789).abs() < 1.0e-9);
+        assert!((DeadShip::steepness(25.).unwrap() - 0.0343).abs() < 1.0e-9);
+        // windage area 500 m^2 with centre 8 m above the base line
+        let area_v = VerticalAreaArray {
+            data: [2., 6.].into_iter().map(|draught| VerticalArea { draught, area: 500., moment_x: 0., moment_z: 4000. }).collect(),
+            error: HashMap::new(),
+        };
+        let no_icing = FakeIcingStab::new(0., 0., 0., 0., 0., 0., false);
+        let wind = WindHeel::new(4., 5000., &area_v, &no_icing, &[]).unwrap();
+        let gust = 1.5 * wind.lever(504., 0.);
+        // GZ = 0.02θ до 30 градусов, далее линейно убывает до нуля при 60 градусах
+        let gz = [(0., 0.), (30., 0.6), (60., 0.)];
+        let dead_ship = DeadShip::new(&gz, &wind, 15., 60.).unwrap();
+        let (theta_0, theta_c) = (gust / 1.5 / 0.02, gust / 0.02);
+        let start = theta_0 - 15.;
+        let target_a = (gust * (theta_c - start) - 0.01 * (theta_c.powi(2) - start.powi(2))).to_radians();
+        let target_b = (0.01 * (900. - theta_c.powi(2)) + 0.02 * (60. * 20. - (2500. - 900.) / 2.) - gust * (50. - theta_c)).to_radians();
+        let (a, b) = dead_ship.areas().unwrap().unwrap();
+        assert!((a - target_a).abs() < 1.0e-4, "\nresult: {:?}\ntarget: {:?}", a, target_a);
+        assert!((b - target_b).abs() < 1.0e-4, "\nresult: {:?}\ntarget: {:?}", b, target_b);
+        let check = dead_ship.check().unwrap();
+        assert_eq!((check.id, check.relation.as_str()), (Criterion::DeadShip.id(), ">="));
+        assert!(check.is_ok(), "\ncheck: {:?}", check);
+        // диаграмма ниже кренящего плеча
+        let dead_ship = DeadShip::new(&[(0., 0.), (60., 0.001)], &wind, 15., 60.).unwrap();
+        assert!(!dead_ship.check().unwrap().is_ok());
+        test_duration.exit();
+    }
+}
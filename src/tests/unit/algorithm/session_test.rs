@@ -0,0 +1,242 @@
+#[cfg(test)]
+
+mod session {
+    use std::{sync::{atomic::{AtomicUsize, Ordering}, Arc, Once}, time::{Duration, Instant}};
+    use futures::future::BoxFuture;
+    use serde::Serialize;
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::{ContextRead, ContextWrite}, ctx_result::CtxResult, ctx_store::{CtxType, StepCtx}},
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_ctx::CargoesCtx},
+            pipeline::{executor::Pipeline, session::EvalSession, step::Step},
+        },
+        infrostructure::query::restart_eval::{RestartEvalQuery, RestartEvalReply},
+        kernel::{dbgid::dbgid::DbgId, eval::Eval, types::eval_result::EvalResult},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Test context of the step 'Mass'
+    #[derive(Debug, Clone, Serialize)]
+    struct MassCtx(f64);
+    impl StepCtx for MassCtx {
+        const STEP: &'static str = "Mass";
+        const RESULT: bool = true;
+    }
+    ///
+    /// Test context of the step 'Ship'
+    #[derive(Debug, Clone, Serialize)]
+    struct ShipCtx(usize);
+    impl StepCtx for ShipCtx {
+        const STEP: &'static str = "Ship";
+        const RESULT: bool = true;
+    }
+    ///
+    /// Writes the total mass of the cargoes
+    struct Mass {
+        dbg: DbgId,
+        count: Arc<AtomicUsize>,
+    }
+    impl Eval<Context, EvalResult> for Mass {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                self.count.fetch_add(1, Ordering::SeqCst);
+                let cargoes: Result<CargoesCtx, _> = ContextRead::read(&ctx);
+                match cargoes {
+                    Ok(cargoes) => ctx.write(MassCtx(cargoes.cargoes.iter().filter_map(|v| v.mass).sum())),
+                    Err(err) => CtxResult::Err(err),
+                }
+            })
+        }
+    }
+    impl Step for Mass {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<CargoesCtx>()]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<MassCtx>()]
+        }
+    }
+    ///
+    /// Writes the ship id, depends only on the initial context
+    struct Ship {
+        dbg: DbgId,
+        count: Arc<AtomicUsize>,
+    }
+    impl Eval<Context, EvalResult> for Ship {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                self.count.fetch_add(1, Ordering::SeqCst);
+                let initial: Result<InitialCtx, _> = ContextRead::read(&ctx);
+                match initial {
+                    Ok(initial) => ctx.write(ShipCtx(initial.ship_id)),
+                    Err(err) => CtxResult::Err(err),
+                }
+            })
+        }
+    }
+    impl Step for Ship {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<InitialCtx>()]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<ShipCtx>()]
+        }
+    }
+    ///
+    /// Test context of the step 'Slow'
+    #[derive(Debug, Clone, Serialize)]
+    struct SlowCtx;
+    impl StepCtx for SlowCtx {
+        const STEP: &'static str = "Slow";
+        const RESULT: bool = true;
+    }
+    ///
+    /// Writes the value after the delay
+    struct Slow {
+        dbg: DbgId,
+    }
+    impl Eval<Context, EvalResult> for Slow {
+        fn eval(&mut self, ctx: Context) -> BoxFuture<'_, EvalResult> {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                ctx.write(SlowCtx)
+            })
+        }
+    }
+    impl Step for Slow {
+        fn dbg(&self) -> &DbgId {
+            &self.dbg
+        }
+        fn reads(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<InitialCtx>()]
+        }
+        fn writes(&self) -> Vec<CtxType> {
+            vec![CtxType::of::<SlowCtx>()]
+        }
+    }
+    ///
+    /// Returns the change set parsed from the json
+    fn changes(json: &str) -> Option<ChangeSet> {
+        Some(serde_json::from_str(json).unwrap())
+    }
+    ///
+    /// Testing 'EvalSession::restart' with the change sets
+    #[tokio::test(flavor = "multi_thread")]
+    async fn restart() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "session";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let (mass_count, ship_count) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        let pipeline = Pipeline::new(dbg)
+            .step(Mass { dbg: DbgId("Mass".into()), count: mass_count.clone() })
+            .step(Ship { dbg: DbgId("Ship".into()), count: ship_count.clone() });
+        let session = EvalSession::new(dbg, pipeline);
+        let cargo = |name: &str, mass: f64| format!(
+            r#"{{"name":"{name}","mass":{mass},"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":1.0}}"#,
+        );
+        // first calculation is full
+        let reply = session.restart(&RestartEvalQuery {
+            ship_id: 1,
+            changes: changes(&format!(r#"{{"cargoes":[{},{}]}}"#, cargo("a", 10.), cargo("b", 5.))),
+        }).await;
+        assert!(reply.error.is_none(), "\nerror: {:?}", reply.error);
+        assert!(reply.diff.is_empty(), "\nresult: {:?}\ntarget: []", reply.diff);
+        assert!(reply.results["MassCtx"] == serde_json::json!(15.), "\nresult: {:?}\ntarget: 15", reply.results["MassCtx"]);
+        let result = (mass_count.load(Ordering::SeqCst), ship_count.load(Ordering::SeqCst));
+        assert!(result == (1, 1), "\nresult: {:?}\ntarget: {:?}", result, (1, 1));
+        // changed cargo recalculates only the mass
+        let reply = session.restart(&RestartEvalQuery {
+            ship_id: 1,
+            changes: changes(&format!(r#"{{"cargoes":[{}],"removed_cargoes":["b"]}}"#, cargo("a", 12.))),
+        }).await;
+        assert!(reply.error.is_none(), "\nerror: {:?}", reply.error);
+        let result = (mass_count.load(Ordering::SeqCst), ship_count.load(Ordering::SeqCst));
+        assert!(result == (2, 1), "\nresult: {:?}\ntarget: {:?}", result, (2, 1));
+        assert!(reply.results["MassCtx"] == serde_json::json!(12.), "\nresult: {:?}\ntarget: 12", reply.results["MassCtx"]);
+        let diff = reply.diff.iter().find(|diff| diff.path == "MassCtx").unwrap();
+        assert!(diff.before == Some(serde_json::json!(15.)) && diff.after == Some(serde_json::json!(12.)), "\nresult: {:?}", diff);
+        assert!(reply.diff.iter().all(|diff| !diff.path.starts_with("ShipCtx")), "\nresult: {:?}", reply.diff);
+        let result: Vec<&String> = reply.results.keys().collect();
+        assert!(result == ["MassCtx", "ShipCtx"], "\nresult: {:?}\ntarget: only the results", result);
+        // another ship is calculated fully
+        let reply = session.restart(&RestartEvalQuery {
+            ship_id: 2,
+            changes: changes(&format!(r#"{{"cargoes":[{}]}}"#, cargo("c", 1.))),
+        }).await;
+        assert!(reply.error.is_none(), "\nerror: {:?}", reply.error);
+        assert!(reply.diff.is_empty(), "\nresult: {:?}\ntarget: []", reply.diff);
+        let result = (mass_count.load(Ordering::SeqCst), ship_count.load(Ordering::SeqCst));
+        assert!(result == (3, 2), "\nresult: {:?}\ntarget: {:?}", result, (3, 2));
+        // without the changes the loading is empty
+        let reply = session.restart(&RestartEvalQuery { ship_id: 3, changes: None }).await;
+        assert!(reply.error.is_none(), "\nerror: {:?}", reply.error);
+        assert!(reply.results["MassCtx"] == serde_json::json!(0.), "\nresult: {:?}\ntarget: 0", reply.results["MassCtx"]);
+        assert!(reply.answer == RestartEvalReply::DONE, "\nresult: {:?}\ntarget: {:?}", reply.answer, RestartEvalReply::DONE);
+        // the failed calculation returns the error status
+        let reply = session.restart(&RestartEvalQuery {
+            ship_id: 4,
+            changes: changes(r#"{"compartments":[{"name":"tank","bound_x1":0.0,"bound_x2":1.0,"general_category":"ballast","matter_type":"liquid"}]}"#),
+        }).await;
+        assert!(reply.answer == RestartEvalReply::ERROR && reply.error.is_some(), "\nresult: {:?}\ntarget: {:?}", reply, RestartEvalReply::ERROR);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'EvalSession::restart' cancelled by the next request for the same ship
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancel() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "session_cancel";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let pipeline = Pipeline::new(dbg).step(Slow { dbg: DbgId("Slow".into()) });
+        let session = Arc::new(EvalSession::new(dbg, pipeline));
+        let time = Instant::now();
+        let first = tokio::spawn({
+            let session = session.clone();
+            async move { session.restart(&RestartEvalQuery { ship_id: 1, changes: None }).await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let second = session.restart(&RestartEvalQuery { ship_id: 1, changes: None }).await;
+        let first = first.await.unwrap();
+        let elapsed = time.elapsed();
+        assert!(first.cancelled && first.results.is_empty() && first.answer == RestartEvalReply::CANCELLED, "\nresult: {:?}\ntarget: cancelled", first);
+        assert!(!second.cancelled && second.error.is_none() && second.answer == RestartEvalReply::DONE, "\nresult: {:?}\ntarget: done", second);
+        assert!(second.results.contains_key("SlowCtx"), "\nresult: {:?}\ntarget: SlowCtx", second.results);
+        assert!(elapsed < Duration::from_millis(500), "the first calculation is not cancelled, elapsed: {:?}", elapsed);
+        // the pipeline keeps the cancelled step
+        let reply = session.restart(&RestartEvalQuery { ship_id: 1, changes: None }).await;
+        assert!(reply.results.contains_key("SlowCtx"), "\nresult: {:?}\ntarget: SlowCtx", reply.results);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+
+mod squat {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::squat::Squat;
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'Squat::value', 'Squat::ukc' and 'Squat::max_speed'
+    #[test]
+    fn squat() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "squat";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        assert!(Squat::new(100., 15., 5., 0.7, 4., None).is_err());
+        let open = Squat::new(100., 15., 5., 0.7, 7., None).unwrap();
+        let channel = Squat::new(100., 15., 5., 0.7, 7., Some(60.)).unwrap();
+        let knots = 5. / 0.514444444;
+        let f_nh: f64 = 5. / (9.81f64 * 7.).sqrt();
+        let test_data = [
+            // step, squat, target barrass, target icorels
+            (1, &open, 0.7 * knots * knots / 100., 2.4 * 0.7 * 15. * 5. / 100. * f_nh.powi(2) / (1. - f_nh.powi(2)).sqrt()),
+            (2, &channel, 5.74 * (75f64 / 420.).powf(0.76) * 0.7 * knots * knots / 100., 2.4 * 0.7 * 15. * 5. / 100. * f_nh.powi(2) / (1. - f_nh.powi(2)).sqrt()),
+        ];
+        for (step, squat, target_barrass, target_icorels) in test_data {
+            let result = squat.barrass(5.);
+            assert!((result - target_barrass).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target_barrass);
+            let result = squat.icorels(5.).unwrap();
+            assert!((result - target_icorels).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target_icorels);
+            let result = squat.ukc(5.).unwrap();
+            let target = 2. - target_barrass.max(target_icorels);
+            assert!((result - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+            let velocity = squat.max_speed(1.).unwrap();
+            let result = squat.ukc(velocity).unwrap();
+            assert!((result - 1.).abs() < 1e-4, "step {} \nresult: {:?}\ntarget: {:?}", step, result, 1.);
+        }
+        assert!(open.max_speed(2.5).is_err());
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,472 @@
+#[cfg(test)]
+
+mod stability {
+    use std::{collections::HashMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            entities::{
+                icing_stab::{IcingStab, IcingStabType},
+                second_generation::{Criterion, DeadShip, ParametricRoll, PureLoss},
+                stability::{HydrostaticTables, VerticalArea, VerticalAreaArray},
+                math::Position, squat::Squat, visibility::Visibility, wind_heel::WindHeel, Ship, Voyage, KNOT,
+            },
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_step::Loading},
+            parameters::{parameters_ctx::ParametersCtx, parameters_step::Parameters},
+            pipeline::executor::Pipeline,
+            stability::{stability_ctx::StabilityCtx, stability_step::Stability},
+        },
+        kernel::{error::eval_error::EvalErrorKind, eval::Eval},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// The cargo and the bulk cargo in the hold, shifted by 0.5 m
+    const CHANGES: &str = r#"{
+        "cargoes": [
+            {"name":"a","mass":100.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":10.0,
+             "mass_shift_x":5.0,"mass_shift_y":0.0,"mass_shift_z":2.0}
+        ],
+        "compartments": [
+            {"space_id":1,"name":"hold","mass":100.0,"density":1.0,"volume":100.0,"bound_x1":10.0,"bound_x2":20.0,
+             "mass_shift_x":15.0,"mass_shift_y":0.0,"mass_shift_z":1.0,"bulk_shift_y":0.5,
+             "general_category":"cargo","matter_type":"bulk"}
+        ]
+    }"#;
+    ///
+    /// Box hull 20 x 10 m, the righting lever of the form 8.5·sinθ
+    fn ship() -> InitialCtx {
+        let pantocaren: Vec<(f64, f64)> = (0..=6).map(|i| {
+            let angle = 10. * i as f64;
+            (angle, 8.5 * angle.to_radians().sin())
+        }).collect();
+        InitialCtx {
+            bounds: Some(vec![(0., 10.), (10., 20.)]),
+            hydrostatics: Some(HydrostaticTables {
+                mean_draught: vec![(0., 0.), (2000., 10.)],
+                rad_trans: vec![(100., 10.), (300., 6.)],
+                center_z: vec![(0., 0.), (2000., 5.)],
+                pantocaren: vec![(0., pantocaren.clone()), (10., pantocaren)],
+                entry_angle: None,
+                waterline_length: None,
+                inertia_coefficient: None,
+                flooding_angle: None,
+                multipler_x1: None,
+                multipler_x2: None,
+                coefficient_k: None,
+            }),
+            ship_parameters: Some(HashMap::from([("MouldedBreadth".to_owned(), 10.), ("LBP".to_owned(), 20.)])),
+            ..InitialCtx::new(1)
+        }
+    }
+    ///
+    /// Testing 'Stability' step with the heel from the shift of the bulk cargo
+    #[tokio::test(flavor = "multi_thread")]
+    async fn eval() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., roll_period: Some(4.), ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(ship())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        // V = 200 m^3, T = 1 m, KM = 0.5 + 8 m, z_g = 1.5 m
+        let target = (200., 1., 7.);
+        let result_values = (result.volume, result.draught, result.gm);
+        assert!(
+            (result_values.0 - target.0).abs() < 1e-6
+                && (result_values.1 - target.1).abs() < 1e-6
+                && (result_values.2 - target.2).abs() < 1e-6,
+            "step {} \nresult: {:?}\ntarget: {:?}", 1, result_values, target,
+        );
+        let target = 7. * 30f64.to_radians().sin();
+        assert!((result.gz[3].1 - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", 2, result.gz[3], target);
+        // the heeling lever 50 / 200 m against GZ linear within 0..10°
+        let check = result.bulk_heel.unwrap();
+        let target = 0.25 / (0.7 * 10f64.to_radians().sin() + 0.25 * 0.005);
+        assert!(
+            check.angle.is_some_and(|angle| (angle - target).abs() < 1e-6) && check.limit == 12. && check.is_ok(),
+            "step {} \nresult: {:?}\ntarget: {:?}", 3, check, target,
+        );
+        // the inertia coefficient by the hull, B / d = 10, L = 20 m
+        let c = 0.373 + 0.023 * 10. - 0.043 * 0.2;
+        let target = (2. * c * 10. / 7f64.sqrt(), (2. * c * 10. / 4.).powi(2));
+        let result_values = (result.roll_period.as_ref().map(|v| v.period), result.roll_test.as_ref().map(|v| v.h));
+        assert!(
+            result_values.0.is_some_and(|v| (v - target.0).abs() < 1e-6) && result_values.1.is_some_and(|v| (v - target.1).abs() < 1e-6),
+            "step {} \nresult: {:?}\ntarget: {:?}", 4, result_values, target,
+        );
+        // the inertia coefficient by the table
+        let initial = InitialCtx {
+            hydrostatics: ship().hydrostatics.map(|v| HydrostaticTables { inertia_coefficient: Some(vec![(0., 0.4), (10., 0.4)]), ..v }),
+            ..ship()
+        };
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        let target = 2. * 0.4 * 10. / 7f64.sqrt();
+        let result_value = result.roll_period.as_ref().map(|v| v.period);
+        assert!(result_value.is_some_and(|v| (v - target).abs() < 1e-6), "step {} \nresult: {:?}\ntarget: {:?}", 5, result_value, target);
+        // the emptied hold, no heel check
+        let changes: ChangeSet = serde_json::from_str(r#"{
+            "compartments": [
+                {"space_id":1,"name":"hold","mass":0.0,"density":1.0,"volume":0.0,"bound_x1":10.0,"bound_x2":20.0,"bulk_shift_y":0.5,
+                 "general_category":"cargo","matter_type":"bulk"}
+            ]
+        }"#).unwrap();
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.bulk_heel.is_none(), "step {} \nresult: {:?}\ntarget: None", 6, result.bulk_heel);
+        // the hydrostatic tables are required
+        let initial = InitialCtx { hydrostatics: None, ..ship() };
+        let ctx = ChangeSet::default().apply_full(Context::new(initial)).unwrap();
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "mean_draught".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 7, result, target);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Stability' step with the level 1 second generation criteria
+    #[tokio::test(flavor = "multi_thread")]
+    async fn criteria() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability_criteria";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., operational_speed: 10., ..Voyage::default() });
+        let mut initial = ship();
+        initial.ship_parameters.as_mut().unwrap().extend([
+            ("Summer draught".to_owned(), 2.),
+            ("Moulded depth".to_owned(), 3.),
+            ("Midship section coefficient".to_owned(), 1.),
+        ]);
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        // the curves by the draught from the tables by the volume
+        let (rad_trans, volume) = ([(0.5, 10.), (1.5, 6.)], [(0., 0.), (10., 2000.)]);
+        let target = vec![
+            PureLoss::new(20., 1., 2., 0.5, &rad_trans, &volume).unwrap().check(1.5).unwrap(),
+            ParametricRoll::new(20., 10., 1., 2., 3., 1., 0., &rad_trans, &volume).unwrap().check(7.).unwrap(),
+        ];
+        let ids: Vec<i32> = result.criteria.iter().map(|v| v.id).collect();
+        assert!(ids == vec![Criterion::PureLoss.id(), Criterion::ParametricRoll.id()], "step {} \nresult: {:?}", 1, ids);
+        assert!(
+            result.criteria.iter().zip(&target).all(|(result, target)| (result.value - target.value).abs() < 1e-6 && result.limit == target.limit),
+            "step {} \nresult: {:?}\ntarget: {:?}", 2, result.criteria, target,
+        );
+        // the pure loss of stability is not applicable at the low speed
+        let changes = ChangeSet { voyage: Some(Voyage { density: 1., operational_speed: 5., ..Voyage::default() }), ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        let ids: Vec<i32> = result.criteria.iter().map(|v| v.id).collect();
+        assert!(ids == vec![Criterion::ParametricRoll.id()], "step {} \nresult: {:?}", 3, ids);
+        // only the configured criteria are reported
+        let parameters = ParametersCtx { criteria: Some(vec![Criterion::ParametricRoll.id()]), ..ParametersCtx::default() };
+        let mut configured = Pipeline::new(dbg).step(Parameters::new(dbg, parameters)).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = configured.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        let ids: Vec<i32> = result.criteria.iter().map(|v| v.id).collect();
+        assert!(ids == vec![Criterion::ParametricRoll.id()], "step {} \nresult: {:?}", 4, ids);
+        let parameters = ParametersCtx { criteria: Some(vec![]), ..ParametersCtx::default() };
+        let mut configured = Pipeline::new(dbg).step(Parameters::new(dbg, parameters)).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = configured.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.criteria.is_empty(), "step {} \nresult: {:?}", 5, result.criteria);
+        // no summer draught, no criteria
+        let changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        let ctx = changes.apply_full(Context::new(ship())).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.criteria.is_empty(), "step {} \nresult: {:?}", 6, result.criteria);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Stability' step with the steady heel under the wind of the navigation area
+    #[tokio::test(flavor = "multi_thread")]
+    async fn wind_heel() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability_wind_heel";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let windage = vec![
+            VerticalArea { draught: 0., area: 100., moment_x: 0., moment_z: 250. },
+            VerticalArea { draught: 2., area: 80., moment_x: 0., moment_z: 150. },
+        ];
+        let initial = InitialCtx {
+            ship: Some(Ship {
+                name: "box".to_owned(),
+                ship_type: "general dry cargo ship".to_owned(),
+                navigation_area: "R2".to_owned(),
+                p_v: 1000.,
+                m: 500.,
+                freeboard_type: "B".to_owned(),
+            }),
+            windage: Some(windage.clone()),
+            ..ship()
+        };
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(initial.clone())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        // the windage of the hull at T = 1 m, the displacement 200 t, no entry angle
+        let area_v = VerticalAreaArray { data: windage, error: HashMap::new() };
+        let none = IcingStab::new(IcingStabType::None, 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.);
+        let target = WindHeel::from_windage(1., 200., &area_v, &none, (0., 0.)).unwrap().check(1000., 500., &result.gz, None).unwrap();
+        let check = result.wind_heel.clone().unwrap();
+        assert!(
+            check.angle.is_some_and(|angle| target.angle.is_some_and(|target| (angle - target).abs() < 1e-6))
+                && check.limit == 16. && check.is_ok(),
+            "step {} \nresult: {:?}\ntarget: {:?}", 1, check, target,
+        );
+        // the icing of the voyage is ignored, if not enabled by the parameters
+        let changes = ChangeSet { voyage: Some(Voyage { density: 1., icing_type: "full".to_owned(), ..Voyage::default() }), ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.wind_heel.as_ref() == Some(&check), "step {} \nresult: {:?}\ntarget: {:?}", 2, result.wind_heel, check);
+        // the icing of the voyage requires the icing coefficients of the ship
+        let parameters = ParametersCtx { icing: true, ..ParametersCtx::default() };
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, parameters)).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "icing".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 3, result.map(|_| ()), target);
+        // the windage of the hull is increased by the icing
+        let coefficients = [
+            ("icing_m_timber", 0.), ("icing_m_v_full", 0.), ("icing_m_v_half", 0.), ("icing_m_h_full", 0.), ("icing_m_h_half", 0.),
+            ("icing_coef_v_area_full", 0.2), ("icing_coef_v_area_half", 0.1), ("icing_coef_v_area_zero", 0.),
+            ("icing_coef_v_moment_full", 0.3), ("icing_coef_v_moment_half", 0.15), ("icing_coef_v_moment_zero", 0.),
+        ];
+        let initial = InitialCtx {
+            icing: Some(coefficients.iter().map(|(key, value)| (key.to_string(), *value)).collect()),
+            ..initial
+        };
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., icing_type: "full".to_owned(), ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        let full = IcingStab::new(IcingStabType::Full, 0., 0., 0., 0., 0., 0.2, 0.1, 0., 0.3, 0.15, 0.);
+        let target = WindHeel::from_windage(1., 200., &area_v, &full, (0., 0.)).unwrap().check(1000., 500., &result.gz, None).unwrap();
+        let iced = result.wind_heel.clone().unwrap();
+        assert!(
+            iced.angle.is_some_and(|angle| target.angle.is_some_and(|target| (angle - target).abs() < 1e-6)) && iced.angle > check.angle,
+            "step {} \nresult: {:?}\ntarget: {:?}", 4, iced, target,
+        );
+        // no windage of the hull, no check
+        let ctx = changes.apply_full(Context::new(ship())).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.wind_heel.is_none(), "step {} \nresult: {:?}\ntarget: None", 5, result.wind_heel);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Stability' step with the level 1 dead ship stability
+    #[tokio::test(flavor = "multi_thread")]
+    async fn dead_ship() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability_dead_ship";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let windage = vec![
+            VerticalArea { draught: 0., area: 100., moment_x: 0., moment_z: 250. },
+            VerticalArea { draught: 2., area: 80., moment_x: 0., moment_z: 150. },
+        ];
+        let mut initial = InitialCtx {
+            hydrostatics: ship().hydrostatics.map(|v| HydrostaticTables {
+                flooding_angle: Some(vec![(0., 40.), (10., 40.)]),
+                multipler_x1: Some(vec![(5., 0.8), (15., 0.6)]),
+                multipler_x2: Some(vec![(0.5, 0.8), (1., 1.)]),
+                ..v
+            }),
+            windage: Some(windage.clone()),
+            ..ship()
+        };
+        initial.ship_parameters.as_mut().unwrap().insert("Summer draught".to_owned(), 2.);
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(initial.clone())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        // B / d = 10, c_b = 200 / (20 · 10 · 1) = 1, no keels, the roll period by the hull
+        let area_v = VerticalAreaArray { data: windage, error: HashMap::new() };
+        let none = IcingStab::new(IcingStabType::None, 0., 0., 0., 0., 0., 0., 0., 0., 0., 0., 0.);
+        let wind = WindHeel::from_windage(1., 200., &area_v, &none, (0., 0.)).unwrap();
+        let period = result.roll_period.as_ref().unwrap().period;
+        let amplitude = DeadShip::amplitude(1., 0.7, 1., 1.5, 1., period).unwrap();
+        let target = DeadShip::new(&result.gz, &wind, amplitude, 40.).unwrap().check().unwrap();
+        let ids: Vec<i32> = result.criteria.iter().map(|v| v.id).collect();
+        assert!(ids == vec![Criterion::DeadShip.id()], "step {} \nresult: {:?}", 1, ids);
+        assert!(
+            (result.criteria[0].value - target.value).abs() < 1e-6 && result.criteria[0].limit == target.limit,
+            "step {} \nresult: {:?}\ntarget: {:?}", 2, result.criteria[0], target,
+        );
+        // the keel area requires the coefficient k
+        initial.ship_parameters.as_mut().unwrap().insert("Keel area".to_owned(), 4.);
+        let ctx = changes.apply_full(Context::new(initial.clone())).unwrap();
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(ctx).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "coefficient_k".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 3, result.map(|_| ()), target);
+        // no roll amplitude tables, no dead ship check
+        let initial = InitialCtx {
+            hydrostatics: initial.hydrostatics.map(|v| HydrostaticTables { multipler_x1: None, ..v }),
+            ..initial
+        };
+        let ctx = changes.apply_full(Context::new(initial)).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.criteria.is_empty(), "step {} \nresult: {:?}", 4, result.criteria);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Stability' step with the squat and the under keel clearance in the shallow water
+    #[tokio::test(flavor = "multi_thread")]
+    async fn squat() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability_squat";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., operational_speed: 5., water_depth: Some(3.), ukc_required: Some(1.5), ..Voyage::default() });
+        let ctx = changes.apply_full(Context::new(ship())).unwrap();
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        // L = 20 m, B = 10 m, T = 1 m, c_b = 200 / (20 · 10 · 1) = 1
+        let target = Squat::new(20., 10., 1., 1., 3., None).unwrap().data(5. * KNOT, Some(1.5)).unwrap();
+        let squat = result.squat.clone().unwrap();
+        assert!(squat == target && squat.is_ok(), "step {} \nresult: {:?}\ntarget: {:?}", 1, squat, target);
+        assert!((squat.ukc - (2. - squat.squat)).abs() < 1e-9, "step {} \nresult: {:?}\ntarget: {:?}", 2, squat.ukc, 2. - squat.squat);
+        let max_speed = squat.max_speed.unwrap();
+        assert!(max_speed > 5., "step {} \nresult: {:?}\ntarget: > 5", 3, max_speed);
+        // the clearance is not provided even without the speed in the channel
+        let changes = ChangeSet {
+            voyage: Some(Voyage {
+                density: 1., operational_speed: 5., water_depth: Some(3.), channel_width: Some(40.), ukc_required: Some(2.5), ..Voyage::default()
+            }),
+            ..ChangeSet::default()
+        };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        let target = Squat::new(20., 10., 1., 1., 3., Some(40.)).unwrap().data(5. * KNOT, Some(2.5)).unwrap();
+        let squat = result.squat.clone().unwrap();
+        assert!(
+            squat == target && squat.max_speed.is_none() && !squat.is_ok(),
+            "step {} \nresult: {:?}\ntarget: {:?}", 4, squat, target,
+        );
+        // the deep water, no squat
+        let changes = ChangeSet { voyage: Some(Voyage { density: 1., operational_speed: 5., ..Voyage::default() }), ..ChangeSet::default() };
+        let (ctx, changed) = changes.apply(ctx).unwrap();
+        let ctx = pipeline.eval_changes(ctx, &changed).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.squat.is_none(), "step {} \nresult: {:?}\ntarget: None", 5, result.squat);
+        test_duration.exit();
+    }
+    ///
+    /// Testing 'Stability' step with the blind sector ahead of the bow over the deck cargo
+    #[tokio::test(flavor = "multi_thread")]
+    async fn visibility() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stability_visibility";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut initial = ship();
+        initial.ship_parameters.as_mut().unwrap().extend([
+            ("X bridge eye from middle".to_owned(), -5.),
+            ("Z bridge eye from BL".to_owned(), 10.),
+            ("X bow from middle".to_owned(), 10.),
+            ("Z bow from BL".to_owned(), 4.),
+        ]);
+        // the cargo on deck 0..5 m with the top at `z`
+        let changes = |z: f64| -> ChangeSet {
+            let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+            changes.cargoes.push(serde_json::from_str(&format!(
+                r#"{{"name":"deck","mass":20.0,"general_category":"cargo","timber":false,"is_on_deck":true,
+                    "bound_x1":0.0,"bound_x2":5.0,"bound_y1":-2.0,"bound_y2":2.0,"bound_z1":3.0,"bound_z2":{z}}}"#,
+            )).unwrap());
+            changes.voyage = Some(Voyage { density: 1., ..Voyage::default() });
+            changes
+        };
+        let mut pipeline = Pipeline::new(dbg).step(Parameters::new(dbg, ParametersCtx::default())).step(Loading::new(dbg)).step(Stability::new(dbg));
+        let eye = Position::new(-5., 0., 10.);
+        let bow = Position::new(10., 0., 4.);
+        let test_data = [
+            // step, top of the deck cargo, target blind sector, target is ok
+            (1, 8., Some((10. - 0.2 * 5. - 1.1) / 0.2 - 10.), true),
+            (2, 9.5, Some((10. - 0.05 * 5. - 1.1) / 0.05 - 10.), false),
+            (3, 11., None, false),
+        ];
+        for (step, z, target_blind_sector, target_ok) in test_data {
+            let ctx = changes(z).apply_full(Context::new(initial.clone())).unwrap();
+            let ctx = pipeline.eval(ctx).await.unwrap();
+            let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+            // the displacement 220 t, T = 1.1 m on even keel
+            let target = Visibility::new(20., eye, bow, result.draught, result.draught).unwrap().check_by_tops(&[(5., z)]).unwrap();
+            let check = result.visibility.clone().unwrap();
+            assert!(
+                check == target && check.limit == 40. && check.is_ok() == target_ok
+                    && match (check.blind_sector, target_blind_sector) {
+                        (Some(result), Some(target)) => (result - target).abs() < 1e-6,
+                        (result, target) => result == target,
+                    },
+                "step {} \nresult: {:?}\ntarget: {:?}", step, check, target_blind_sector,
+            );
+        }
+        // the position of the bridge is unknown, no check
+        let ctx = changes(8.).apply_full(Context::new(ship())).unwrap();
+        let ctx = pipeline.eval(ctx).await.unwrap();
+        let result: StabilityCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.visibility.is_none(), "step {} \nresult: {:?}\ntarget: None", 4, result.visibility);
+        test_duration.exit();
+    }
+}
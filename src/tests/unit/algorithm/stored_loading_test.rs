@@ -0,0 +1,105 @@
+#[cfg(test)]
+
+mod stored_loading {
+    use std::{collections::BTreeMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_ctx::{CargoesCtx, ContainersCtx, VoyageCtx}, stored_loading::StoredLoading},
+        },
+        infrostructure::api::client::{api_client::ApiClient, api_replies::ApiReplies},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Recorded replies of the database for the ship 7, the compartments and bulkheads are absent
+    fn replies() -> BTreeMap<String, String> {
+        let absent = |table: &str| format!(r#"{{"data":[],"error":{{"message":"relation \"{table}\" does not exist"}}}}"#);
+        BTreeMap::from([
+            (
+                "SELECT name, mass, general_category, timber, is_on_deck, container, bound_x1, bound_x2, bound_y1, bound_y2, bound_z1, bound_z2, \
+                 mass_shift_x, mass_shift_y, mass_shift_z, horizontal_area, vertical_area, vertical_area_shift_x, vertical_area_shift_y, vertical_area_shift_z \
+                 FROM cargo WHERE ship_id=7;".to_owned(),
+                r#"{"data":[
+                    {"name":"a","mass":10.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":1.0},
+                    {"name":"b","mass":5.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":1.0,"bound_x2":2.0}
+                ],"error":{}}"#.to_owned(),
+            ),
+            (
+                "SELECT id, mass, general_category, bound_x1, bound_x2, bound_y1, bound_y2, bound_z1, bound_z2, \
+                 mass_shift_x, mass_shift_y, mass_shift_z, is_on_deck, bay, row, tier, iso_code, vgm FROM container WHERE ship_id=7;".to_owned(),
+                r#"{"data":[{"id":1,"mass":20.0,"general_category":"cargo","bound_x1":0.0,"bound_x2":6.0,"bound_y1":0.0,"bound_y2":2.4,
+                    "bound_z1":0.0,"bound_z2":2.6,"mass_shift_x":3.0,"mass_shift_y":1.2,"mass_shift_z":1.3,"is_on_deck":true}],"error":{}}"#.to_owned(),
+            ),
+            (
+                "SELECT space_id, name, mass, density, volume, bound_x1, bound_x2, mass_shift_x, mass_shift_y, mass_shift_z, m_f_s_y, m_f_s_x, \
+                 grain_moment, angle_of_repose, bulk_shift_y, general_category, matter_type, sounding, ullage, ullage_reference, \
+                 temperature, density_15, vcf_table, vcf_alpha, hold_id, part_index FROM compartment WHERE ship_id=7;".to_owned(),
+                absent("compartment"),
+            ),
+            (
+                "SELECT id, position, name, mass, general_category, bound_x1, bound_x2, mass_shift_x, mass_shift_y, mass_shift_z \
+                 FROM bulkhead WHERE ship_id=7;".to_owned(),
+                absent("bulkhead"),
+            ),
+            (
+                "SELECT density, operational_speed, wetting_timber, icing_type, icing_timber_type, \
+                 sounding_trim, sounding_heel, roll_amplitude, roll_period, water_depth, channel_width, ukc_required FROM voyage WHERE ship_id=7;".to_owned(),
+                r#"{"data":[{"density":1.0,"operational_speed":12.0,"wetting_timber":0.0,"icing_type":"none","icing_timber_type":"full"}],"error":{}}"#.to_owned(),
+            ),
+        ])
+    }
+    ///
+    /// Testing 'StoredLoading::fetch' with the changes of the request applied over
+    #[tokio::test(flavor = "multi_thread")]
+    async fn fetch() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stored_loading";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(2));
+        test_duration.run().unwrap();
+        let api_client = ApiClient::new("none".to_owned(), "0.0.0.0".to_owned(), "0".to_owned());
+        let ctx = Context::new(InitialCtx::new(7)).with_api_replies(ApiReplies::replay(replies()));
+        let stored = StoredLoading::new(dbg, api_client).fetch(&ctx).await.unwrap();
+        let result: Vec<&str> = stored.cargoes.iter().map(|v| v.name.as_str()).collect();
+        assert!(result == ["a", "b"], "step {} \nresult: {:?}\ntarget: {:?}", 1, result, ["a", "b"]);
+        let result = (stored.containers.len(), stored.compartments.len(), stored.bulkheads.len());
+        assert!(result == (1, 0, 0), "step {} \nresult: {:?}\ntarget: {:?}", 2, result, (1, 0, 0));
+        let result = stored.voyage.as_ref().map(|v| v.operational_speed);
+        assert!(result == Some(12.), "step {} \nresult: {:?}\ntarget: {:?}", 3, result, Some(12.));
+        // the changes of the request are applied over the stored loading
+        let changes: ChangeSet = serde_json::from_str(
+            r#"{"cargoes":[{"name":"a","mass":12.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":1.0}],
+                "removed_cargoes":["b"]}"#,
+        ).unwrap();
+        let ctx = stored.apply_full(ctx).unwrap();
+        let (ctx, _) = changes.apply(ctx).unwrap();
+        let result: Vec<(String, Option<f64>)> = ContextRead::<CargoesCtx>::read(&ctx).unwrap().cargoes.into_iter().map(|v| (v.name, v.mass)).collect();
+        let target = vec![("a".to_owned(), Some(12.))];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 4, result, target);
+        let result = (
+            ContextRead::<ContainersCtx>::read(&ctx).unwrap().containers.len(),
+            ContextRead::<VoyageCtx>::read(&ctx).unwrap().voyage.operational_speed,
+        );
+        assert!(result == (1, 12.), "step {} \nresult: {:?}\ntarget: {:?}", 5, result, (1, 12.));
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+
+mod stowage {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        load::{Stowage, StowageViolation},
+        loads::{CargoGeneralCategory, Container, ContainerStackLimit},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Container in the slot bay/row/tier, 2.5 m high, 6 m (20') or 12 m (40') long
+    fn container(id: i32, bay: i32, row: i32, tier: i32, iso_code: &str, mass: f64) -> Container {
+        let x1 = if bay % 4 == 3 { 6. } else { 0. };
+        let x2 = if bay % 2 == 0 { 12. } else { x1 + 6. };
+        let z1 = if tier >= 80 { 10. + (tier - 82) as f64 * 1.25 } else { (tier - 2) as f64 * 1.25 };
+        let y1 = row as f64 * 2.5;
+        Container {
+            id,
+            mass,
+            general_category: CargoGeneralCategory::Cargo,
+            bound_x1: x1,
+            bound_x2: x2,
+            bound_y1: y1,
+            bound_y2: y1 + 2.5,
+            bound_z1: z1,
+            bound_z2: z1 + 2.5,
+            mass_shift_x: (x1 + x2) / 2.,
+            mass_shift_y: y1 + 1.25,
+            mass_shift_z: z1 + 1.25,
+            is_on_deck: tier >= 80,
+            bay: Some(bay),
+            row: Some(row),
+            tier: Some(tier),
+            iso_code: Some(iso_code.to_owned()),
+            vgm: None,
+        }
+    }
+    ///
+    /// Testing 'Stowage' stack mass, mixing rules and windage
+    #[test]
+    fn stowage() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stowage";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let containers = vec![
+            container(1, 1, 1, 82, "22G1", 20.),
+            container(2, 3, 1, 82, "22G1", 10.),
+            container(3, 2, 1, 84, "42G1", 30.),
+            container(4, 1, 1, 86, "22G1", 5.),
+            container(5, 2, 2, 82, "45G1", 25.),
+        ];
+        let stowage = Stowage::new(&containers).unwrap();
+        let test_data = [
+            // step, bay, row, is_on_deck, target mass
+            (1, 1, 1, true, 20. + 15. + 5.),
+            (2, 3, 1, true, 10. + 15.),
+            (3, 2, 1, true, 20. + 10. + 30. + 5.),
+            (4, 2, 2, true, 25.),
+            (5, 2, 1, false, 0.),
+        ];
+        for (step, bay, row, is_on_deck, target) in test_data {
+            let result = stowage.stack_mass(bay, row, is_on_deck);
+            assert!((result - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        let limits = vec![
+            ContainerStackLimit { bay: 1, row: 1, is_on_deck: true, max_mass: 30. },
+            ContainerStackLimit { bay: 2, row: 2, is_on_deck: true, max_mass: 30. },
+        ];
+        let result = stowage.check_stack_mass(&limits);
+        let target = vec![StowageViolation::StackMass { bay: 1, row: 1, is_on_deck: true, mass: 40., limit: 30. }];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 6, result, target);
+        let result = stowage.check_mixing();
+        let target = vec![StowageViolation::Mixing { lower: 3, upper: 4 }];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 7, result, target);
+        // silhouette by x segments: 0..6 - z 10..17.5, 6..12 - z 10..15,
+        // the 40' containers over the 20' ones are not counted twice
+        let windage = Stowage::windage(&containers);
+        let result: f64 = windage.values().map(|(area, _)| area).sum();
+        let target = 6. * 7.5 + 6. * 5.;
+        assert!((result - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", 8, result, target);
+        // container 1 shares 0..6 with 3, 4, 5; container 3 shares 0..6 and 6..12 with 2 and 5
+        let result = (windage[&1].0, windage[&3].0, windage[&3].1.x());
+        let target = (45. / 4., 45. / 4. + 30. / 3., (45. / 4. * 3. + 30. / 3. * 9.) / (45. / 4. + 30. / 3.));
+        assert!((result.0 - target.0).abs() < 1e-6 && (result.1 - target.1).abs() < 1e-6 && (result.2 - target.2).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", 9, result, target);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,237 @@
+#[cfg(test)]
+
+mod strength {
+    use std::{collections::HashMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        algorithm::{
+            context::{context::Context, context_access::ContextRead},
+            entities::{
+                girder::{WaveLoad, G}, stability::{HydrostaticTables, NavigationArea},
+                strength::{GirderLimitData, SectionModulusData}, Ship, Voyage,
+            },
+            initial::initial_ctx::InitialCtx,
+            loading::{change_set::ChangeSet, loading_step::Loading},
+            parameters::{parameters_ctx::ParametersCtx, parameters_step::Parameters},
+            pipeline::executor::Pipeline,
+            stability::stability_step::Stability,
+            strength::{strength_ctx::StrengthCtx, strength_step::Strength},
+        },
+        kernel::{error::eval_error::EvalErrorKind, eval::Eval},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// The heavier cargo in the aft half of the ship
+    const CHANGES: &str = r#"{
+        "cargoes": [
+            {"name":"a","mass":150.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":0.0,"bound_x2":10.0,
+             "mass_shift_x":5.0,"mass_shift_y":0.0,"mass_shift_z":2.0},
+            {"name":"b","mass":50.0,"general_category":"cargo","timber":false,"is_on_deck":false,"bound_x1":10.0,"bound_x2":20.0,
+             "mass_shift_x":15.0,"mass_shift_y":0.0,"mass_shift_z":2.0}
+        ]
+    }"#;
+    ///
+    /// Box hull 20 x 10 m by 4 frames, the immersed area of the frames 10·T
+    fn ship() -> InitialCtx {
+        let pantocaren: Vec<(f64, f64)> = (0..=6).map(|i| {
+            let angle = 10. * i as f64;
+            (angle, 8.5 * angle.to_radians().sin())
+        }).collect();
+        let area = vec![(0., 0.), (10., 100.)];
+        InitialCtx {
+            bounds: Some(vec![(0., 5.), (5., 10.), (10., 15.), (15., 20.)]),
+            hydrostatics: Some(HydrostaticTables {
+                mean_draught: vec![(0., 0.), (2000., 10.)],
+                rad_trans: vec![(100., 10.), (300., 6.)],
+                center_z: vec![(0., 0.), (2000., 5.)],
+                pantocaren: vec![(0., pantocaren.clone()), (10., pantocaren)],
+                entry_angle: None,
+                waterline_length: None,
+                inertia_coefficient: None,
+                flooding_angle: None,
+                multipler_x1: None,
+                multipler_x2: None,
+                coefficient_k: None,
+            }),
+            bonjean: Some(vec![(0., area.clone()), (20., area)]),
+            section_moduli: Some((0..4).map(|index| SectionModulusData {
+                index,
+                w_deck: 1.,
+                w_bottom: 2.,
+                shear_area: 0.5,
+                steel_grade: "A".to_owned(),
+            }).collect()),
+            ..InitialCtx::new(1)
+        }
+    }
+    ///
+    /// Testing 'Strength' step with the still water loads and the stresses
+    #[tokio::test(flavor = "multi_thread")]
+    async fn eval() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "strength";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let mut changes: ChangeSet = serde_json::from_str(CHANGES).unwrap();
+        changes.voyage = Some(Voyage { density: 1., ..Voyage::default() });
+        let mut pipeline = Pipeline::new(dbg)
+            .step(Parameters::new(dbg, ParametersCtx::default()))
+            .step(Loading::new(dbg))
+            .step(Stability::new(dbg))
+            .step(Strength::new(dbg));
+        let ctx = pipeline.eval(changes.apply_full(Context::new(ship())).unwrap()).await.unwrap();
+        let result: StrengthCtx = ContextRead::read(&ctx).unwrap();
+        // the masses 75, 75, 25, 25 t, the buoyancy 50 t by the frame at T = 1 m,
+        // the trim of the buoyancy 30, 10, -10, -30 t
+        let target = [(5., -12.5, -5.), (10., 0., 10.), (15., 12.5, -5.), (20., 0., 0.)];
+        assert!(
+            result.still_water.len() == target.len()
+                && result.still_water.iter().zip(&target).all(|(result, target)| {
+                    (result.0 - target.0).abs() < 1e-6 && (result.1 - target.1).abs() < 1e-6 && (result.2 - target.2).abs() < 1e-6
+                }),
+            "step {} \nresult: {:?}\ntarget: {:?}", 1, result.still_water, target,
+        );
+        let target: Vec<(f64, f64, f64)> = target.iter()
+            .map(|&(_, bending, shear)| (bending * G * 1e-3, -bending * G * 1e-3 / 2., shear * G * 1e-3 / 0.5))
+            .collect();
+        assert!(
+            result.stress.len() == target.len()
+                && result.stress.iter().zip(&target).all(|(result, target)| {
+                    (result.sigma_deck - target.0).abs() < 1e-9
+                        && (result.sigma_bottom - target.1).abs() < 1e-9
+                        && (result.tau - target.2).abs() < 1e-9
+                        && result.is_ok()
+                }),
+            "step {} \nresult: {:?}\ntarget: {:?}", 2, result.stress, target,
+        );
+        // the stresses in the sections at the ends of the frames from the database,
+        // the loads are evaluated by 8 frames and taken at the ends of every second frame
+        let initial = InitialCtx {
+            ship_parameters: Some(HashMap::from([("L.O.A".to_owned(), 20.), ("X midship from Fr0".to_owned(), 0.)])),
+            ..ship()
+        };
+        let mut split = Pipeline::new(dbg)
+            .step(Parameters::new(dbg, ParametersCtx { frames: Some(8), ..ParametersCtx::default() }))
+            .step(Loading::new(dbg))
+            .step(Stability::new(dbg))
+            .step(Strength::new(dbg));
+        let ctx = split.eval(changes.apply_full(Context::new(initial)).unwrap()).await.unwrap();
+        let result: StrengthCtx = ContextRead::read(&ctx).unwrap();
+        let target: Vec<(i32, f64, f64)> = result.still_water.iter().skip(1).step_by(2).enumerate()
+            .map(|(index, &(_, bending, shear))| (index as i32, bending * G * 1e-3, shear * G * 1e-3 / 0.5))
+            .collect();
+        assert!(
+            result.still_water.len() == 8
+                && result.stress.len() == target.len()
+                && result.stress.iter().zip(&target).all(|(result, target)| {
+                    result.index == target.0 && (result.sigma_deck - target.1).abs() < 1e-9 && (result.tau - target.2).abs() < 1e-9
+                }),
+            "step {} \nresult: {:?}\ntarget: {:?}", 3, result.stress, target,
+        );
+        // the section of the frame absent in the database
+        let mut initial = ship();
+        if let Some(sections) = initial.section_moduli.as_mut() {
+            sections[3].index = 4;
+        }
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(changes.apply_full(Context::new(initial)).unwrap()).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::InvalidData { field: "section_modulus.index".to_owned(), value: "4".to_owned() };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 4, result, target);
+        // the total still water and wave loads against the limits of the area R2,
+        // C_b = 200 / (20 · 10 · 1) = 1
+        let limit = |frame_x: f64, bending: f64| GirderLimitData {
+            area: NavigationArea::R2,
+            frame_x,
+            bending_max: bending,
+            bending_min: -bending,
+            shear_max: 1000.,
+            shear_min: -1000.,
+        };
+        let girder = |bending: f64| InitialCtx {
+            ship: Some(Ship {
+                name: "box".to_owned(),
+                ship_type: "general dry cargo ship".to_owned(),
+                navigation_area: "R2".to_owned(),
+                p_v: 0.,
+                m: 0.,
+                freeboard_type: "B".to_owned(),
+            }),
+            ship_parameters: Some(HashMap::from([
+                ("LBP".to_owned(), 20.),
+                ("MouldedBreadth".to_owned(), 10.),
+                ("Summer draught".to_owned(), 1.),
+            ])),
+            girder_limits: Some(vec![limit(0., bending), limit(20., bending)]),
+            wave_reductions: Some(vec![(NavigationArea::R2, 0.5)]),
+            ..ship()
+        };
+        for (step, bending, target_ok) in [(5, 1000., true), (6, 1., false)] {
+            let ctx = pipeline.eval(changes.apply_full(Context::new(girder(bending))).unwrap()).await.unwrap();
+            let result: StrengthCtx = ContextRead::read(&ctx).unwrap();
+            let target = WaveLoad::from_area(20., 10., 1., NavigationArea::R2, &[(NavigationArea::R2, 0.5)]).unwrap()
+                .check(&result.still_water, NavigationArea::R2, &[limit(0., bending), limit(20., bending)])
+                .unwrap();
+            assert!(
+                result.girder.len() == target.len()
+                    && result.girder.iter().zip(&target).all(|(result, target)| {
+                        (result.bending_hog - target.bending_hog).abs() < 1e-6
+                            && (result.bending_sag - target.bending_sag).abs() < 1e-6
+                            && (result.shear_pos - target.shear_pos).abs() < 1e-6
+                            && (result.shear_neg - target.shear_neg).abs() < 1e-6
+                            && result.limit == target.limit
+                    })
+                    && result.girder.iter().all(|v| v.is_ok()) == target_ok,
+                "step {} \nresult: {:?}\ntarget: {:?}", step, result.girder, target,
+            );
+        }
+        // the wave loads require the dimensions of the ship
+        let initial = InitialCtx { ship_parameters: None, ..girder(1000.) };
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(changes.apply_full(Context::new(initial)).unwrap()).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "ship_parameters".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 7, result, target);
+        // the torsional moments of the cargo 'b' at y = 2 m, the residual 100 t·m spread along the length
+        let mut torsion = changes.clone();
+        torsion.cargoes[1].mass_shift_y = Some(2.);
+        let initial = InitialCtx { torsion_limits: Some(vec![(0., 30.), (20., 30.)]), ..ship() };
+        let ctx = pipeline.eval(torsion.apply_full(Context::new(initial)).unwrap()).await.unwrap();
+        let result: Vec<(f64, f64, bool)> = ContextRead::<StrengthCtx>::read(&ctx).unwrap().torsion.iter()
+            .map(|v| (v.x, v.value, v.is_ok()))
+            .collect();
+        let target = [(5., -25., true), (10., -50., false), (15., -25., true), (20., 0., true)];
+        assert!(
+            result.len() == target.len()
+                && result.iter().zip(&target).all(|(result, target)| {
+                    result.0 == target.0 && (result.1 - target.1).abs() < 1e-6 && result.2 == target.2
+                }),
+            "step {} \nresult: {:?}\ntarget: {:?}", 8, result, target,
+        );
+        // no stresses without the section moduli
+        let initial = InitialCtx { section_moduli: None, ..ship() };
+        let ctx = pipeline.eval(changes.apply_full(Context::new(initial)).unwrap()).await.unwrap();
+        let result: StrengthCtx = ContextRead::read(&ctx).unwrap();
+        assert!(result.still_water.len() == 4 && result.stress.is_empty(), "step {} \nresult: {:?}\ntarget: []", 9, result.stress);
+        // the Bonjean scale is required
+        let initial = InitialCtx { bonjean: None, ..ship() };
+        let result = Result::<Option<Context>, _>::from(pipeline.eval(changes.apply_full(Context::new(initial)).unwrap()).await).map_err(|err| err.kind);
+        let target = EvalErrorKind::DataMissing { table: "bonjean_frame".to_owned(), ship_id: 1 };
+        assert!(result.as_ref().err() == Some(&target), "step {} \nresult: {:?}\ntarget: {:?}", 10, result, target);
+        test_duration.exit();
+    }
+}
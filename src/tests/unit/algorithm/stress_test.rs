@@ -0,0 +1,65 @@
+#[cfg(test)]
+
+mod stress {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        girder::{SteelGrade, Stress},
+        strength::SectionModulusData,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'Stress::table'
+    #[test]
+    fn table() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "stress";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let section = |index: i32| SectionModulusData {
+            index,
+            w_deck: 2.,
+            w_bottom: 2.5,
+            shear_area: 0.5,
+            steel_grade: "AH36".to_owned(),
+        };
+        let stress = Stress::new(vec![section(0), section(1)]).unwrap();
+        assert!(Stress::new(vec![SectionModulusData { w_deck: 0., ..section(0) }]).is_err());
+        assert!(stress.table(&[(0., 0.)]).is_err());
+        let result = stress.table(&[(1000., 100.), (50000., 0.)]).unwrap();
+        let sigma_allow = SteelGrade::Ah36.sigma_allow();
+        let test_data = [
+            // step, result, target (sigma_deck, sigma_bottom, tau), target is_ok
+            (1, &result[0], (4.905, -3.924, 1.962), true),
+            (2, &result[1], (245.25, -196.2, 0.), false),
+        ];
+        for (step, result, target, target_ok) in test_data {
+            let value = (result.sigma_deck, result.sigma_bottom, result.tau);
+            assert!(
+                (value.0 - target.0).abs() < 1e-6 && (value.1 - target.1).abs() < 1e-6 && (value.2 - target.2).abs() < 1e-6,
+                "step {} \nresult: {:?}\ntarget: {:?}", step, value, target
+            );
+            assert!(result.sigma_allow == sigma_allow, "step {} \nresult: {:?}\ntarget: {:?}", step, result.sigma_allow, sigma_allow);
+            assert!(result.is_ok() == target_ok, "step {} \nresult: {:?}\ntarget: {:?}", step, result.is_ok(), target_ok);
+        }
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+
+mod tank_calibration {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        load::{ILoad, LoadingType, SoundingReading, Tank, TankCalibration},
+        loads::SoundingData,
+        math::Bound,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Calibration table of the box tank 10 x 4 x 2 m
+    fn table() -> Vec<SoundingData> {
+        [0., 1., 2.].into_iter().map(|sounding| SoundingData {
+            space_id: 1,
+            sounding,
+            volume: 40. * sounding,
+            shift_x: 5.,
+            shift_y: 0.,
+            shift_z: sounding / 2.,
+            m_f_s_y: 4. * 1000. / 12.,
+            m_f_s_x: 10. * 64. / 12.,
+        }).collect()
+    }
+    ///
+    /// Testing 'TankCalibration::sounding' and 'Tank::from_sounding'
+    #[test]
+    fn from_sounding() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "tank_calibration";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let trim_correction = vec![
+            (0., vec![(0., 0.), (2., 0.)]),
+            (1., vec![(0., 0.1), (2., 0.1)]),
+        ];
+        let calibration = TankCalibration::new(&table(), &trim_correction, &[]).unwrap();
+        let test_data = [
+            // step, reading, trim, heel, target volume, target mass
+            (1, SoundingReading::Sounding(1.), 0., 0., 40., 40.),
+            (2, SoundingReading::Sounding(0.5), 0., 0., 20., 20.),
+            (3, SoundingReading::Ullage { ullage: 1.5, reference: 2.5 }, 0., 0., 40., 40.),
+            (4, SoundingReading::Sounding(1.), 0.5, 0., 42., 42.),
+            (5, SoundingReading::Sounding(1.), 1., 3., 44., 44.),
+        ];
+        for (step, reading, trim, heel, target_volume, target_mass) in test_data {
+            let sounding = calibration.sounding(reading, trim, heel).unwrap();
+            let volume = calibration.volume(sounding).unwrap();
+            assert!((volume - target_volume).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, volume, target_volume);
+            let tank = Tank::from_sounding(
+                1.,
+                &calibration,
+                reading,
+                trim,
+                heel,
+                Bound::new(0., 10.).unwrap(),
+                LoadingType::Ballast,
+            ).unwrap();
+            let mass = tank.mass();
+            assert!((mass - target_mass).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, mass, target_mass);
+        }
+        let mut table = table();
+        table[1].sounding = f64::NAN;
+        assert!(TankCalibration::new(&table, &[], &[]).is_err(), "NaN sounding must be an error");
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+
+mod torsion {
+    use std::{collections::HashMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use std::rc::Rc;
+    use crate::algorithm::entities::{
+        girder::Torsion,
+        load::{ILoadMass, LoadMass, LoadingType},
+        math::{Bound, Bounds, Position},
+        strength::{TorsionLimitArray, TorsionLimitData},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'Torsion::values' and 'Torsion::check'
+    #[test]
+    fn torsion() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "torsion";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let load = |mass: f64, x1: f64, x2: f64, y: f64| -> Rc<dyn ILoadMass> {
+            Rc::new(LoadMass::new(
+                mass,
+                Bound::new(x1, x2).unwrap(),
+                Some(Position::new((x1 + x2) / 2., y, 0.)),
+                LoadingType::Cargo,
+            ).unwrap())
+        };
+        let frames = Bounds::from_frames(&[(-10., 0.), (0., 10.)]).unwrap();
+        // balanced: 10 t at y = 2 m aft, 10 t at y = -2 m forward
+        let torsion = Torsion::new(frames.clone(), vec![load(10., -10., 0., 2.), load(10., 0., 10., -2.)]);
+        let result = torsion.values().unwrap();
+        let target = vec![(0., 20.), (10., 0.)];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 1, result, target);
+        // unbalanced: residual 20 t*m spread along the length
+        let torsion = Torsion::new(frames, vec![load(10., -10., 0., 2.)]);
+        let result = torsion.values().unwrap();
+        let target = vec![(0., 10.), (10., 0.)];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 2, result, target);
+        let result: Vec<bool> = torsion.check(&[(-10., 5.), (10., 5.)]).unwrap().iter().map(|v| v.is_ok()).collect();
+        let target = vec![false, true];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 3, result, target);
+        // the limits are sorted by the section, NaN section is an error
+        let limits = |frame_x: [f64; 2]| TorsionLimitArray {
+            data: frame_x.iter().map(|&frame_x| TorsionLimitData { frame_x, value: 5. }).collect(),
+            error: HashMap::new(),
+        };
+        let result = limits([10., -10.]).data().unwrap();
+        let target = vec![(-10., 5.), (10., 5.)];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 4, result, target);
+        assert!(limits([10., f64::NAN]).data().is_err(), "step {} \nNaN section must be an error", 5);
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+
+mod visibility {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use std::rc::Rc;
+    use crate::algorithm::entities::{
+        load::{Desk, IDesk},
+        math::{Bound, Position},
+        visibility::Visibility,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'Visibility::blind_sector' and 'Visibility::check'
+    #[test]
+    fn blind_sector() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "visibility";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let desk = |x1: f64, x2: f64, z2: f64| -> Rc<dyn IDesk> {
+            Rc::new(Desk::new(
+                100.,
+                Position::new((x1 + x2) / 2., 0., z2 / 2.),
+                Bound::new(x1, x2).unwrap(),
+                Bound::new(-5., 5.).unwrap(),
+                Bound::new(10., z2).unwrap(),
+                (x2 - x1) * (z2 - 10.),
+                Position::new((x1 + x2) / 2., 0., (z2 + 10.) / 2.),
+                (x2 - x1) * 10.,
+                false,
+                true,
+            ))
+        };
+        let eye = Position::new(-40., 0., 30.);
+        let bow = Position::new(50., 0., 12.);
+        let even_keel = Visibility::new(100., eye, bow, 6., 6.).unwrap();
+        let trim_by_head = Visibility::new(100., eye, bow, 5., 7.).unwrap();
+        let test_data = [
+            // step, visibility, desks, target blind sector
+            (1, &even_keel, vec![], 30.),
+            (2, &even_keel, vec![desk(0., 40., 25.)], 294.),
+            (3, &even_keel, vec![desk(-60., -45., 35.)], 30.),
+            (4, &trim_by_head, vec![], (30. - 8. - 5. - 1.) / (0.2 + 0.02) - 50.),
+            (5, &even_keel, vec![desk(0., 40., 31.)], f64::INFINITY),
+        ];
+        for (step, visibility, desks, target) in test_data {
+            let result = visibility.blind_sector(&desks).unwrap();
+            assert!(result == target || (result - target).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        let result = even_keel.check(&[desk(0., 40., 25.)]).unwrap();
+        assert!(!result.is_ok() && result.limit == 200., "step {} \nresult: {:?}\ntarget: {:?}", 6, result, 200.);
+        test_duration.exit();
+    }
+}
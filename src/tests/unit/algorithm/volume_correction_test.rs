@@ -0,0 +1,54 @@
+#[cfg(test)]
+
+mod volume_correction {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::load::{VcfTable, VolumeCorrection};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'VolumeCorrection::vcf' against ASTM D1250 tables 54A, 54B, 54D
+    #[test]
+    fn vcf() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "volume_correction";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let test_data = [
+            // step, table, density at 15°C, temperature, target VCF
+            (1, VcfTable::Products, 0.850, 15., 1.0),
+            (2, VcfTable::Products, 0.850, 30., 0.9875),
+            (3, VcfTable::Products, 0.850, 0., 1.0125),
+            (4, VcfTable::Crude, 0.870, 40., 0.9800),
+            (5, VcfTable::Lubricants, 0.900, 50., 0.9759),
+            (6, VcfTable::Special(0.001), 1.000, 25., 0.9900),
+        ];
+        for (step, table, density_15, temperature, target) in test_data {
+            let result = VolumeCorrection::new(table, density_15).unwrap().vcf(temperature);
+            assert!((result - target).abs() < 0.0005, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        let correction = VolumeCorrection::new(VcfTable::Products, 0.850).unwrap();
+        let result = correction.mass(1000., 30.);
+        let target = 1000. * correction.vcf(30.) * 0.850;
+        assert!((result - target).abs() < 1e-9, "mass \nresult: {:?}\ntarget: {:?}", result, target);
+        assert!(VolumeCorrection::new(VcfTable::Products, 1.2).is_err(), "density out of range must be rejected");
+        test_duration.exit();
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+
+mod wave_load {
+    use std::{collections::HashMap, sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::algorithm::entities::{
+        girder::WaveLoad,
+        stability::NavigationArea,
+        strength::{GirderLimitArray, GirderLimitData},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'WaveLoad::bending', 'WaveLoad::shear' and 'WaveLoad::check'
+    #[test]
+    fn wave_load() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "wave_load";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        // L = 100 m, C_w = 7.92
+        let hog = 190. * 7.92 * 100. * 100. * 20. * 0.8 * 1.0e-3 / 9.81;
+        let sag = -110. * 7.92 * 100. * 100. * 20. * 1.5 * 1.0e-3 / 9.81;
+        let shear = 30. * 7.92 * 100. * 20. * 1.5 * 1.0e-2 / 9.81;
+        let reductions = [(NavigationArea::R2, 0.5)];
+        let wave = WaveLoad::from_area(100., 20., 0.8, NavigationArea::Unrestricted, &reductions).unwrap();
+        let reduced = WaveLoad::from_area(100., 20., 0.8, NavigationArea::R2, &reductions).unwrap();
+        assert!(WaveLoad::from_area(100., 20., 0.8, NavigationArea::R1, &reductions).is_err());
+        let test_data = [
+            // step, wave, x, target bending, target shear
+            (1, &wave, 0., (hog, sag), (0.7 * shear, -0.7 * shear)),
+            (2, &wave, -50., (0., 0.), (0., 0.)),
+            (3, &wave, 25., (hog * 0.25 / 0.35, sag * 0.25 / 0.35), (shear, -190. * 0.8 / (110. * 1.5) * shear)),
+            (4, &reduced, 0., (hog / 2., sag / 2.), (0.35 * shear, -0.35 * shear)),
+        ];
+        for (step, wave, x, target_bending, target_shear) in test_data {
+            let bending = wave.bending(x).unwrap();
+            assert!((bending.0 - target_bending.0).abs() < 1e-6 && (bending.1 - target_bending.1).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, bending, target_bending);
+            let result = wave.shear(x).unwrap();
+            assert!((result.0 - target_shear.0).abs() < 1e-6 && (result.1 - target_shear.1).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target_shear);
+        }
+        let limit = |area: NavigationArea, frame_x: f64, bending: f64| GirderLimitData {
+            area,
+            frame_x,
+            bending_max: bending,
+            bending_min: -bending,
+            shear_max: 1000.,
+            shear_min: -1000.,
+        };
+        // limits of all areas, only R2 applies
+        let limits = [
+            limit(NavigationArea::R2, -50., 13000.),
+            limit(NavigationArea::R3Rsn, -50., 100000.),
+            limit(NavigationArea::R2, 50., 13000.),
+            limit(NavigationArea::R3Rsn, 50., 100000.),
+        ];
+        let result: Vec<bool> = reduced
+            .check(&[(0., 500., 0.), (0., 1500., 0.)], NavigationArea::R2, &limits)
+            .unwrap()
+            .iter()
+            .map(|v| v.is_ok())
+            .collect();
+        let target = vec![true, false];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 5, result, target);
+        let result: Vec<bool> = reduced
+            .check(&[(0., 500., 0.), (0., 1500., 0.)], NavigationArea::R3Rsn, &limits)
+            .unwrap()
+            .iter()
+            .map(|v| v.is_ok())
+            .collect();
+        let target = vec![true, true];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 6, result, target);
+        assert!(reduced.check(&[(0., 500., 0.)], NavigationArea::R1, &limits).is_err());
+        // the limits are sorted by the section, NaN section is an error
+        let result = GirderLimitArray { data: vec![limits[2].clone(), limits[0].clone()], error: HashMap::new() }.data().unwrap();
+        let target = vec![limits[0].clone(), limits[2].clone()];
+        assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", 7, result, target);
+        let result = GirderLimitArray { data: vec![limits[0].clone(), limit(NavigationArea::R2, f64::NAN, 1.)], error: HashMap::new() }.data();
+        assert!(result.is_err(), "step {} \nresult: {:?}", 8, result);
+        test_duration.exit();
+    }
+}
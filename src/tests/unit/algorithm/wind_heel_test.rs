@@ -0,0 +1,68 @@
+#[cfg(test)]
+
+mod wind_heel {
+    use std::{sync::Once, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use std::collections::HashMap;
+    use crate::algorithm::entities::{
+        icing_stab::FakeIcingStab,
+        stability::{VerticalArea, VerticalAreaArray},
+        wind_heel::WindHeel,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'WindHeel::lever' and 'WindHeel::steady_heel'
+    #[test]
+    fn steady_heel() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "wind_heel";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        // windage area 500 m^2 with centre 8 m above the base line
+        let area_v = VerticalAreaArray {
+            data: [2., 6.].into_iter().map(|draught| VerticalArea { draught, area: 500., moment_x: 0., moment_z: 4000. }).collect(),
+            error: HashMap::new(),
+        };
+        let no_icing = FakeIcingStab::new(0., 0., 0., 0., 0., 0., false);
+        let icing = FakeIcingStab::new(0., 0., 0., 0.1, 0., 0.2, true);
+        let wind = WindHeel::new(4., 5000., &area_v, &no_icing, &[]).unwrap();
+        let iced = WindHeel::new(4., 5000., &area_v, &icing, &[]).unwrap();
+        let test_data = [
+            // step, wind, target area, target lever at p_v = 1000 Pa
+            (1, &wind, 500., 1000. * 500. * 6. / (1000. * 9.81 * 5000.)),
+            (2, &iced, 550., 1000. * 550. * (4800. / 550. - 2.) / (1000. * 9.81 * 5000.)),
+        ];
+        for (step, wind, target_area, target_lever) in test_data {
+            let result = wind.area();
+            assert!((result - target_area).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target_area);
+            let result = wind.lever(1000., 0.);
+            assert!((result - target_lever).abs() < 1e-6, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target_lever);
+        }
+        let gz = [(0., 0.), (30., 0.3), (60., 0.)];
+        let lever = wind.lever(1000., 0.);
+        let result = wind.steady_heel(1000., 0., &gz).unwrap().unwrap();
+        let target = 0.01 * result - lever * result.to_radians().cos().powi(2);
+        assert!(target.abs() < 1e-4, "step {} \nresult: {:?}\ntarget: {:?}", 3, result, target);
+        let result = wind.steady_heel(WindHeel::pressure(200.), 0., &gz).unwrap();
+        assert!(result.is_none(), "step {} \nresult: {:?}\ntarget: {:?}", 4, result, None::<f64>);
+        test_duration.exit();
+    }
+}
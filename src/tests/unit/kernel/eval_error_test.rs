@@ -0,0 +1,85 @@
+#[cfg(test)]
+
+mod eval_error {
+    use std::{sync::Once, time::Duration};
+    use sal_sync::services::entity::error::str_err::StrErr;
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::kernel::error::{error::Error, eval_error::{EvalError, EvalErrorKind}};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing 'EvalError' codes, cause chain and serialization
+    #[test]
+    fn codes() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        log::debug!("");
+        let dbg = "eval_error";
+        log::debug!("\n{}", dbg);
+        let test_duration = TestDuration::new(dbg, Duration::from_secs(1));
+        test_duration.run().unwrap();
+        let test_data = [
+            // error, target code, target retryable
+            (EvalError::new(EvalErrorKind::DataMissing { table: "hold".into(), ship_id: 1 }, "a"), "DATA_MISSING", false),
+            (EvalError::new(EvalErrorKind::InvalidData { field: "mass".into(), value: "-1".into() }, "a"), "INVALID_DATA", false),
+            (EvalError::new(EvalErrorKind::Timeout, "a"), "TIMEOUT", true),
+            (EvalError::from(Error::ApiRequest("a".into())), "COMMUNICATION", true),
+            (EvalError::from(Error::Database("relation does not exist".into())), "DATA_MISSING", false),
+            (EvalError::from(Error::Cancelled("a".into())), "CANCELLED", true),
+            (EvalError::cancelled("a"), "CANCELLED", true),
+            (EvalError::from(StrErr("a".into())), "INTERNAL", false),
+            (EvalError::from(Error::Parameter("a".into())), "INVALID_DATA", false),
+        ];
+        for (step, (err, target_code, target_retryable)) in test_data.into_iter().enumerate() {
+            let err = err.context("outer");
+            let result = (err.code(), err.is_retryable());
+            assert!(result == (target_code, target_retryable), "step {} \nresult: {:?}\ntarget: {:?}", step, result, (target_code, target_retryable));
+            let json = serde_json::to_value(&err).unwrap();
+            assert!(json["code"] == target_code, "step {} \nresult: {}\ntarget: {}", step, json, target_code);
+            assert!(json["retryable"] == target_retryable, "step {} \nresult: {}\ntarget: {}", step, json, target_retryable);
+            let result: EvalError = serde_json::from_value(json).unwrap();
+            assert!(result == err, "step {} \nresult: {:?}\ntarget: {:?}", step, result, err);
+        }
+        // the errors of the queries with the table and the ship
+        let test_data = [
+            // step, error, target kind
+            (1, Error::Database("relation does not exist".into()), EvalErrorKind::DataMissing { table: "hold".into(), ship_id: 3 }),
+            (2, Error::from("a".parse::<f64>().unwrap_err()), EvalErrorKind::InvalidData { field: "hold".into(), value: "ParseFloatError: invalid float literal".into() }),
+            (3, Error::ApiRequest("a".into()), EvalErrorKind::Communication),
+        ];
+        for (step, err, target) in test_data {
+            let result = EvalError::query(err, "hold", 3).kind;
+            assert!(result == target, "step {} \nresult: {:?}\ntarget: {:?}", step, result, target);
+        }
+        // the parse errors with the field and the value
+        let result = EvalError::from(Error::from(serde_json::from_str::<f64>("a").unwrap_err())).kind;
+        assert!(
+            matches!(&result, EvalErrorKind::InvalidData { field, value } if field == "json" && !value.is_empty()),
+            "\nresult: {:?}", result,
+        );
+        let err = EvalError::new(EvalErrorKind::DataMissing { table: "computed_frame_space".into(), ship_id: 3 }, "bounds")
+            .context("Initial.eval")
+            .context("Pipeline.eval");
+        let result: Vec<&str> = err.chain().map(|err| err.message.as_str()).collect();
+        let target = vec!["Pipeline.eval", "Initial.eval", "bounds"];
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        let json = serde_json::to_value(&err).unwrap();
+        let result = (&json["table"], &json["ship_id"], &json["cause"]["cause"]["message"]);
+        assert!(result == (&"computed_frame_space".into(), &3.into(), &"bounds".into()), "\nresult: {}", json);
+        test_duration.exit();
+    }
+}
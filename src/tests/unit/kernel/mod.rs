@@ -1,4 +1,5 @@
+mod eval_error_test;
 mod link_listen_test;
 mod link_test;
 mod request_test;
-mod switch_test;
\ No newline at end of file
+mod switch_test;